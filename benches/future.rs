@@ -46,6 +46,34 @@ fn benchmark_and_then_async(c: &mut Criterion<impl Measurement>) {
     benchmark_group.finish()
 }
 
+// `join`.
+
+fn benchmark_join_with<Fut>(
+    benchmark_group: &mut BenchmarkGroup<impl Measurement>,
+    name: &str,
+    mut f: impl FnMut(Ready<u32>, Ready<u64>) -> Fut,
+) where
+    Fut: Future<Output = (u32, u64)>,
+{
+    benchmark_with(benchmark_group, name, || {
+        f(future::ready(hint::black_box(2)), future::ready(hint::black_box(3)))
+    });
+}
+
+fn benchmark_join(c: &mut Criterion<impl Measurement>) {
+    let mut benchmark_group = c.benchmark_group("future/join");
+
+    benchmark_join_with(&mut benchmark_group, "async block", |fut_1, fut_2| async move {
+        (fut_1.await, fut_2.await)
+    });
+
+    benchmark_join_with(&mut benchmark_group, "futures", futures_util::future::join);
+
+    benchmark_join_with(&mut benchmark_group, "slim-futures", slim_futures::future::join);
+
+    benchmark_group.finish()
+}
+
 // `map`.
 
 fn benchmark_map_with<Fut>(
@@ -179,6 +207,7 @@ fn benchmark_map_ok(c: &mut Criterion<impl Measurement>) {
 criterion::criterion_group!(
     benchmarks,
     benchmark_and_then_async,
+    benchmark_join,
     benchmark_map,
     benchmark_map_async,
     benchmark_map_err,
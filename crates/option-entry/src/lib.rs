@@ -1,5 +1,6 @@
 #![no_std]
 
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::ptr;
 
@@ -52,18 +53,24 @@ impl<'a, T> OptionNoneEntry<'a, T> {
     }
 }
 
+impl<T> Debug for OptionNoneEntry<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionNoneEntry").finish()
+    }
+}
+
 pub struct OptionSomeEntry<'a, T> {
     /// `inner` must be in `Some` state.
     inner: &'a mut Option<T>,
 }
 
 impl<'a, T> OptionSomeEntry<'a, T> {
-    pub fn get_mut(&mut self) -> &mut T {
+    pub const fn get_mut(&mut self) -> &mut T {
         // SAFETY: `OptionSomeEntry` proves that `self.inner` is in `Some` state.
         unsafe { self.inner.as_mut().unwrap_unchecked() }
     }
 
-    pub fn into_mut(self) -> &'a mut T {
+    pub const fn into_mut(self) -> &'a mut T {
         // SAFETY: `OptionSomeEntry` proves that `self.inner` is in `Some` state.
         unsafe { self.inner.as_mut().unwrap_unchecked() }
     }
@@ -78,6 +85,19 @@ impl<'a, T> OptionSomeEntry<'a, T> {
     }
 }
 
+impl<T> Debug for OptionSomeEntry<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // SAFETY: `OptionSomeEntry` proves that `self.inner` is in `Some` state.
+        let value = unsafe { self.inner.as_ref().unwrap_unchecked() };
+
+        f.debug_tuple("OptionSomeEntry").field(value).finish()
+    }
+}
+
+#[derive(Debug)]
 pub enum OptionEntry<'a, T> {
     None(OptionNoneEntry<'a, T>),
     Some(OptionSomeEntry<'a, T>),
@@ -99,6 +119,12 @@ impl<'a, T> OptionNonePinnedEntry<'a, T> {
     }
 }
 
+impl<T> Debug for OptionNonePinnedEntry<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionNonePinnedEntry").finish()
+    }
+}
+
 pub struct OptionSomePinnedEntry<'a, T> {
     /// `inner` must be in `Some` state.
     inner: Pin<&'a mut Option<T>>,
@@ -141,13 +167,26 @@ impl<'a, T> OptionSomePinnedEntry<'a, T> {
     }
 }
 
+impl<T> Debug for OptionSomePinnedEntry<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // SAFETY: `OptionSomePinnedEntry` proves that `self.inner` is in `Some` state.
+        let value = unsafe { self.inner.as_ref().get_ref().as_ref().unwrap_unchecked() };
+
+        f.debug_tuple("OptionSomePinnedEntry").field(value).finish()
+    }
+}
+
+#[derive(Debug)]
 pub enum OptionPinnedEntry<'a, T> {
     None(OptionNonePinnedEntry<'a, T>),
     Some(OptionSomePinnedEntry<'a, T>),
 }
 
 pub trait OptionEntryExt<T>: private::Sealed<T> {
-    fn entry(&mut self) -> OptionEntry<T> {
+    fn entry(&mut self) -> OptionEntry<'_, T> {
         let inner = self.borrow_mut();
 
         match inner {
@@ -156,7 +195,7 @@ pub trait OptionEntryExt<T>: private::Sealed<T> {
         }
     }
 
-    fn pinned_entry(self: Pin<&mut Self>) -> OptionPinnedEntry<T> {
+    fn pinned_entry(self: Pin<&mut Self>) -> OptionPinnedEntry<'_, T> {
         let mut inner = self.borrow_pin_mut();
 
         match inner.as_mut().as_pin_mut() {
@@ -1,8 +1,9 @@
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::{hint, mem};
 
 pin_project_lite::pin_project! {
-    #[derive(Clone, Copy)]
+    #[derive(Clone, Copy, Debug)]
     #[project = InnerThreeStatesPinProject]
     #[project_replace = ThreeStatesPinProjectReplace]
     pub enum ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
@@ -13,7 +14,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn pin_project(self: Pin<&mut Self>) -> ThreeStatesPinProject<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
+    pub fn pin_project(self: Pin<&mut Self>) -> ThreeStatesPinProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
         match *self {
             Self::A { .. } => ThreeStatesPinProject::A(StateAPinProject { inner: self }),
             Self::B { .. } => ThreeStatesPinProject::B(StateBPinProject { inner: self }),
@@ -21,7 +22,7 @@ impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> ThreeStates<APin, AUnpin, BPin, B
         }
     }
 
-    pub fn project_mut(&mut self) -> ThreeStatesProject<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
+    pub const fn project_mut(&mut self) -> ThreeStatesProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
         match *self {
             Self::A { .. } => ThreeStatesProject::A(StateAProject { inner: self }),
             Self::B { .. } => ThreeStatesProject::B(StateBProject { inner: self }),
@@ -30,6 +31,7 @@ impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> ThreeStates<APin, AUnpin, BPin, B
     }
 }
 
+#[derive(Debug)]
 pub struct StatePinProject<'a, A, B> {
     pub pinned: Pin<&'a mut A>,
     pub unpinned: &'a mut B,
@@ -40,7 +42,7 @@ pub struct StateAPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateAPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StatePinProject<APin, AUnpin> {
+    pub fn get_project(&mut self) -> StatePinProject<'_, APin, AUnpin> {
         match self.inner.as_mut().project() {
             InnerThreeStatesPinProject::A { pinned, unpinned } => StatePinProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -84,12 +86,29 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateAPinProject<'a, APin, AU
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateAPinProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    APin: Debug,
+    AUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::A { pinned, unpinned } => f
+                .debug_struct("StateAPinProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
 pub struct StateBPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     inner: Pin<&'a mut ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin>>,
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateBPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StatePinProject<BPin, BUnpin> {
+    pub fn get_project(&mut self) -> StatePinProject<'_, BPin, BUnpin> {
         match self.inner.as_mut().project() {
             InnerThreeStatesPinProject::B { pinned, unpinned } => StatePinProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -133,12 +152,29 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateBPinProject<'a, APin, AU
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateBPinProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    BPin: Debug,
+    BUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::B { pinned, unpinned } => f
+                .debug_struct("StateBPinProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
 pub struct StateCPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     inner: Pin<&'a mut ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin>>,
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateCPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StatePinProject<CPin, CUnpin> {
+    pub fn get_project(&mut self) -> StatePinProject<'_, CPin, CUnpin> {
         match self.inner.as_mut().project() {
             InnerThreeStatesPinProject::C { pinned, unpinned } => StatePinProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -182,12 +218,31 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateCPinProject<'a, APin, AU
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateCPinProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    CPin: Debug,
+    CUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::C { pinned, unpinned } => f
+                .debug_struct("StateCPinProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
+#[derive(Debug)]
 pub enum ThreeStatesPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     A(StateAPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>),
     B(StateBPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>),
     C(StateCPinProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>),
 }
 
+#[derive(Debug)]
 pub struct StateProject<'a, A, B> {
     pub pinned: &'a mut A,
     pub unpinned: &'a mut B,
@@ -198,14 +253,14 @@ pub struct StateAProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateAProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StateProject<APin, AUnpin> {
+    pub const fn get_project(&mut self) -> StateProject<'_, APin, AUnpin> {
         match self.inner {
             ThreeStates::A { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
         }
     }
 
-    pub fn into_project(self) -> StateProject<'a, APin, AUnpin> {
+    pub const fn into_project(self) -> StateProject<'a, APin, AUnpin> {
         match self.inner {
             ThreeStates::A { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -242,19 +297,36 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateAProject<'a, APin, AUnpi
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateAProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    APin: Debug,
+    AUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::A { pinned, unpinned } => f
+                .debug_struct("StateAProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
 pub struct StateBProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     inner: &'a mut ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin>,
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateBProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StateProject<BPin, BUnpin> {
+    pub const fn get_project(&mut self) -> StateProject<'_, BPin, BUnpin> {
         match self.inner {
             ThreeStates::B { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
         }
     }
 
-    pub fn into_project(self) -> StateProject<'a, BPin, BUnpin> {
+    pub const fn into_project(self) -> StateProject<'a, BPin, BUnpin> {
         match self.inner {
             ThreeStates::B { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -291,19 +363,36 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateBProject<'a, APin, AUnpi
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateBProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    BPin: Debug,
+    BUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::B { pinned, unpinned } => f
+                .debug_struct("StateBProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
 pub struct StateCProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     inner: &'a mut ThreeStates<APin, AUnpin, BPin, BUnpin, CPin, CUnpin>,
 }
 
 impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateCProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
-    pub fn get_project(&mut self) -> StateProject<CPin, CUnpin> {
+    pub const fn get_project(&mut self) -> StateProject<'_, CPin, CUnpin> {
         match self.inner {
             ThreeStates::C { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
         }
     }
 
-    pub fn into_project(self) -> StateProject<'a, CPin, CUnpin> {
+    pub const fn into_project(self) -> StateProject<'a, CPin, CUnpin> {
         match self.inner {
             ThreeStates::C { pinned, unpinned } => StateProject { pinned, unpinned },
             _ => unsafe { hint::unreachable_unchecked() },
@@ -340,6 +429,24 @@ impl<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> StateCProject<'a, APin, AUnpi
     }
 }
 
+impl<APin, AUnpin, BPin, BUnpin, CPin, CUnpin> Debug for StateCProject<'_, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>
+where
+    CPin: Debug,
+    CUnpin: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &*self.inner {
+            ThreeStates::C { pinned, unpinned } => f
+                .debug_struct("StateCProject")
+                .field("pinned", pinned)
+                .field("unpinned", unpinned)
+                .finish(),
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
+#[derive(Debug)]
 pub enum ThreeStatesProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin> {
     A(StateAProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>),
     B(StateBProject<'a, APin, AUnpin, BPin, BUnpin, CPin, CUnpin>),
@@ -0,0 +1,154 @@
+use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::task::AbortRegistration;
+use core::fmt::{self, Debug, Formatter};
+use core::ops::Deref;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+pin_project_lite::pin_project! {
+    pub struct Abortable<I, R> {
+        #[pin]
+        iter: I,
+        registration: R,
+    }
+}
+
+impl<I, R> Abortable<I, R> {
+    pub(crate) const fn new(iter: I, registration: R) -> Self {
+        Self { iter, registration }
+    }
+}
+
+impl<I, R> Debug for Abortable<I, R>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Abortable")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, R> AsyncIterator for Abortable<I, R>
+where
+    I: AsyncIterator,
+    R: Deref<Target = AbortRegistration>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if this.registration.is_aborted() {
+            return Poll::Ready(None);
+        }
+
+        if let Poll::Ready(item) = this.iter.poll_next(cx) {
+            return Poll::Ready(item);
+        }
+
+        if this.registration.register(cx) {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.registration.is_aborted() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, R> for Abortable<I, R>
+    where
+        I: AsyncIterator,
+        R: Deref<Target = AbortRegistration>,
+);
+
+impl<I, R> FusedAsyncIterator for Abortable<I, R>
+where
+    I: FusedAsyncIterator,
+    R: Deref<Target = AbortRegistration>,
+{
+    fn is_terminated(&self) -> bool {
+        self.registration.is_aborted() || self.iter.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::task::AbortRegistration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_abortable() {
+        let registration = AbortRegistration::new();
+        let (iter, _) = stream::iter([2, 3, 5]).slim_abortable(&registration);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_abortable_abort() {
+        let registration = AbortRegistration::new();
+        let (mut iter, handle) = stream::iter([2, 3, 5]).slim_abortable(&registration);
+
+        assert_eq!(iter.next().await, Some(2));
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+
+        handle.abort();
+
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_abortable_abort_pending() {
+        let registration = AbortRegistration::new();
+        let (iter, handle) = stream::pending::<u32>().slim_abortable(&registration);
+
+        let abort = async {
+            crate::test_utilities::Yield::new(1).await;
+
+            handle.abort();
+        };
+
+        assert_eq!(
+            futures_util::future::join(iter.collect::<Vec<_>>(), abort).await.0,
+            [0_u32; 0]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn test_abortable_arc() {
+        let (mut iter, handle) = stream::iter([2, 3, 5]).slim_abortable_arc();
+
+        assert_eq!(iter.next().await, Some(2));
+
+        handle.abort();
+
+        assert_eq!(iter.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_abortable_fused_async_iter() {
+        let registration = AbortRegistration::new();
+        let (iter, handle) = stream::iter([2, 3, 5]).fuse().slim_abortable(&registration);
+
+        assert!(!iter.is_terminated());
+
+        handle.abort();
+
+        assert!(iter.is_terminated());
+    }
+}
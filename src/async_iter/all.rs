@@ -1,5 +1,6 @@
 use crate::async_iter::try_fold::TryFold;
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -60,6 +61,15 @@ where
     }
 }
 
+impl<I, P> Debug for All<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("All").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, P> Future for All<I, P>
 where
     I: AsyncIterator,
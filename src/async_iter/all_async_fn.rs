@@ -0,0 +1,112 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`AllAsync`](super::AllAsync), but takes an async closure whose future may borrow from the closure itself.
+    pub struct AllAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, P, I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: AsyncFnState<'a, P, I::Item, C>,
+    }
+}
+
+impl<'a, I, P, C> AllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            state: AsyncFnState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for AllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AllAsyncFn")
+            .field("iter", &self.iter)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> Future for AllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    type Output = bool;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if task::ready!(state.as_mut().poll(cx)) == Some(false) {
+                return Poll::Ready(false);
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(true),
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_all_async_fn() {
+        let limit = 10;
+
+        let future = stream::iter([2, 3, 5]).slim_all_async_fn(async move |x: u32| {
+            let limit = &limit;
+
+            Yield::new(1).await;
+
+            x < *limit
+        });
+
+        assert!(future.await);
+    }
+
+    #[tokio::test]
+    async fn test_all_async_fn_fail() {
+        let mut calls = 0;
+
+        let future = stream::iter([2, 3, 5]).slim_all_async_fn(async |x: u32| {
+            calls += 1;
+
+            x == 2
+        });
+
+        assert!(!future.await);
+        assert_eq!(calls, 2);
+    }
+}
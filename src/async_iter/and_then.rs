@@ -1,6 +1,7 @@
 use crate::async_iter::map::Map;
 use crate::support::fns::AndThenFn;
-use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FromResidual, FusedAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> AndThen<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Map::new(iter, AndThenFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for AndThen<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AndThen").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for AndThen<I, F>
 where
     I: AsyncIterator,
@@ -53,6 +63,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for AndThen<I, F>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+        F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+        F::Output: FromResidual<<I::Item as Try>::Residual>,
+);
+
 impl<I, F> FusedAsyncIterator for AndThen<I, F>
 where
     I: FusedAsyncIterator,
@@ -65,6 +84,15 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for AndThen<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    I::Item: Try,
+    F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+    F::Output: FromResidual<<I::Item as Try>::Residual>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
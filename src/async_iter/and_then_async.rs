@@ -1,4 +1,5 @@
-use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FromResidual, FusedAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -31,7 +32,7 @@ where
     F: FnMut<(<I::Item as Try>::Output,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: None, f }
     }
 }
@@ -53,6 +54,22 @@ where
     }
 }
 
+impl<I, F> Debug for AndThenAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Try,
+    F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AndThenAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for AndThenAsync<I, F>
 where
     I: AsyncIterator,
@@ -99,6 +116,16 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for AndThenAsync<I, F>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+        F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: FromResidual<<I::Item as Try>::Residual>,
+);
+
 impl<I, F> FusedAsyncIterator for AndThenAsync<I, F>
 where
     I: FusedAsyncIterator,
@@ -115,6 +142,16 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for AndThenAsync<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    I::Item: Try,
+    F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<I::Item as Try>::Residual>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
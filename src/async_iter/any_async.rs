@@ -1,12 +1,14 @@
 use crate::async_iter::try_fold_async::TryFoldAsync;
 use crate::future::Map;
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::fns::CopyFn;
 use fn_traits::FnMut;
+use futures_core::FusedFuture;
 
 #[derive(Clone)]
 struct BreakIfTrue;
@@ -25,14 +27,14 @@ impl FnMut<(bool,)> for BreakIfTrue {
 }
 
 #[derive(Clone)]
-struct AnyAsyncFn<P>
+struct AnyAsyncFoldFn<P>
 where
     P: ?Sized,
 {
     predicate: P,
 }
 
-impl<T, P> FnMut<((), T)> for AnyAsyncFn<P>
+impl<T, P> FnMut<((), T)> for AnyAsyncFoldFn<P>
 where
     P: FnMut<(T,)> + ?Sized,
     P::Output: IntoFuture,
@@ -53,7 +55,7 @@ pin_project_lite::pin_project! {
         P::Output: IntoFuture<Output = bool>,
     {
         #[pin]
-        inner: TryFoldAsync<I, (), CopyFn, AnyAsyncFn<P>>,
+        inner: TryFoldAsync<I, (), CopyFn, AnyAsyncFoldFn<P>>,
     }
 }
 
@@ -65,7 +67,7 @@ where
 {
     pub(crate) fn new(iter: I, predicate: P) -> Self {
         Self {
-            inner: TryFoldAsync::new(iter, (), CopyFn::default(), AnyAsyncFn { predicate }),
+            inner: TryFoldAsync::new(iter, (), CopyFn::default(), AnyAsyncFoldFn { predicate }),
         }
     }
 }
@@ -84,6 +86,18 @@ where
     }
 }
 
+impl<I, P> Debug for AnyAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture<Output = bool>,
+    <P::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AnyAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, P> Future for AnyAsync<I, P>
 where
     I: AsyncIterator,
@@ -97,6 +111,17 @@ where
     }
 }
 
+impl<I, P> FusedFuture for AnyAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,23 +1,33 @@
+use crate::async_iter::abortable::Abortable;
 use crate::async_iter::all::All;
 use crate::async_iter::all_async::AllAsync;
+use crate::async_iter::all_async_fn::AllAsyncFn;
 use crate::async_iter::and_then::AndThen;
 use crate::async_iter::and_then_async::AndThenAsync;
 use crate::async_iter::any::Any;
 use crate::async_iter::any_async::AnyAsync;
+use crate::async_iter::any_async_fn::AnyAsyncFn;
+use crate::async_iter::debounce::Debounce;
 use crate::async_iter::err_into::ErrInto;
 use crate::async_iter::filter::Filter;
 use crate::async_iter::filter_async::FilterAsync;
+use crate::async_iter::filter_async_fn::FilterAsyncFn;
+use crate::async_iter::filter_err::FilterErr;
 use crate::async_iter::filter_map::FilterMap;
 use crate::async_iter::filter_map_async::FilterMapAsync;
+use crate::async_iter::filter_ok::FilterOk;
 use crate::async_iter::find::Find;
 use crate::async_iter::find_async::FindAsync;
+use crate::async_iter::find_async_fn::FindAsyncFn;
 use crate::async_iter::find_map::FindMap;
 use crate::async_iter::find_map_async::FindMapAsync;
 use crate::async_iter::flat_map::FlatMap;
 use crate::async_iter::flat_map_async::FlatMapAsync;
 use crate::async_iter::flatten::Flatten;
+use crate::async_iter::flatten_ok::FlattenOk;
 use crate::async_iter::fold::Fold;
 use crate::async_iter::fold_async::FoldAsync;
+use crate::async_iter::fold_async_fn::FoldAsyncFn;
 use crate::async_iter::for_each::ForEach;
 use crate::async_iter::for_each_async::ForEachAsync;
 use crate::async_iter::fuse::Fuse;
@@ -26,6 +36,7 @@ use crate::async_iter::inspect_err::InspectErr;
 use crate::async_iter::inspect_ok::InspectOk;
 use crate::async_iter::map::Map;
 use crate::async_iter::map_async::MapAsync;
+use crate::async_iter::map_async_fn::MapAsyncFn;
 use crate::async_iter::map_err::MapErr;
 use crate::async_iter::map_err_async::MapErrAsync;
 use crate::async_iter::map_ok::MapOk;
@@ -37,23 +48,90 @@ use crate::async_iter::or_else::OrElse;
 use crate::async_iter::or_else_async::OrElseAsync;
 use crate::async_iter::reduce::Reduce;
 use crate::async_iter::reduce_async::ReduceAsync;
+use crate::async_iter::sample::Sample;
 use crate::async_iter::scan::Scan;
 use crate::async_iter::scan_async::ScanAsync;
 use crate::async_iter::skip_while::SkipWhile;
 use crate::async_iter::skip_while_async::SkipWhileAsync;
+use crate::async_iter::skip_while_async_fn::SkipWhileAsyncFn;
 use crate::async_iter::take_while::TakeWhile;
 use crate::async_iter::take_while_async::TakeWhileAsync;
+use crate::async_iter::take_while_async_fn::TakeWhileAsyncFn;
+use crate::async_iter::throttle::Throttle;
+use crate::async_iter::timeout::Timeout;
+use crate::async_iter::try_all::TryAll;
+use crate::async_iter::try_all_async::TryAllAsync;
+use crate::async_iter::try_all_async_fn::TryAllAsyncFn;
+use crate::async_iter::try_any::TryAny;
+use crate::async_iter::try_any_async::TryAnyAsync;
+use crate::async_iter::try_any_async_fn::TryAnyAsyncFn;
+use crate::async_iter::try_find::TryFind;
+use crate::async_iter::try_find_async::TryFindAsync;
+use crate::async_iter::try_find_async_fn::TryFindAsyncFn;
+use crate::async_iter::try_find_map::TryFindMap;
+use crate::async_iter::try_find_map_async::TryFindMapAsync;
 use crate::async_iter::try_flatten::TryFlatten;
 use crate::async_iter::try_fold::TryFold;
 use crate::async_iter::try_fold_async::TryFoldAsync;
+use crate::async_iter::try_fold_async_fn::TryFoldAsyncFn;
 use crate::async_iter::try_for_each::TryForEach;
 use crate::async_iter::try_for_each_async::TryForEachAsync;
+use crate::async_iter::try_map_while::TryMapWhile;
+use crate::async_iter::try_map_while_async::TryMapWhileAsync;
+use crate::async_iter::try_position::TryPosition;
+use crate::async_iter::try_position_async::TryPositionAsync;
+use crate::async_iter::try_position_async_fn::TryPositionAsyncFn;
+use crate::async_iter::try_reduce::TryReduce;
+use crate::async_iter::try_reduce_async::TryReduceAsync;
+use crate::async_iter::try_scan::TryScan;
+use crate::async_iter::try_scan_async::TryScanAsync;
+use crate::async_iter::try_skip_while::TrySkipWhile;
+use crate::async_iter::try_skip_while_async::TrySkipWhileAsync;
+use crate::async_iter::try_skip_while_async_fn::TrySkipWhileAsyncFn;
+use crate::async_iter::try_take_while::TryTakeWhile;
+use crate::async_iter::try_take_while_async::TryTakeWhileAsync;
+use crate::async_iter::try_take_while_async_fn::TryTakeWhileAsyncFn;
+use crate::async_iter::unwrap_or_else::UnwrapOrElse;
+use crate::async_iter::unwrap_or_else_async::UnwrapOrElseAsync;
 use crate::async_iter::zip::Zip;
+use crate::fns::AsyncFnMutCall;
 use crate::support::{AsyncIterator, FromResidual, IntoAsyncIterator, Residual, ResultAsyncIterator, Try};
-use core::future::IntoFuture;
+use crate::task::{AbortHandle, AbortRegistration};
+use crate::time::{TimedOut, Timer};
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::future::{Future, IntoFuture};
+use core::ops::Deref;
+use core::time::Duration;
 use fn_traits::fns::{CloneFn, CopyFn, MemTakeFn};
 
 pub trait AsyncIteratorExt: AsyncIterator {
+    fn slim_abortable<R>(self, registration: R) -> (Abortable<Self, R>, AbortHandle<R>)
+    where
+        Self: Sized,
+        R: Deref<Target = AbortRegistration> + Clone,
+    {
+        let handle = AbortHandle::new(registration.clone());
+
+        (
+            crate::support::assert_async_iter::<_, Self::Item>(Abortable::new(self, registration)),
+            handle,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn slim_abortable_arc(
+        self,
+    ) -> (
+        Abortable<Self, Arc<AbortRegistration>>,
+        AbortHandle<Arc<AbortRegistration>>,
+    )
+    where
+        Self: Sized,
+    {
+        self.slim_abortable(Arc::new(AbortRegistration::new()))
+    }
+
     fn slim_all<P>(self, predicate: P) -> All<Self, P>
     where
         Self: Sized,
@@ -71,6 +149,19 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, bool>(AllAsync::new(self, predicate))
     }
 
+    fn slim_all_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> AllAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        P: AsyncFnMut(Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_future::<_, bool>(AllAsyncFn::new(self, predicate, |predicate: &'a mut P, item| {
+            predicate(item)
+        }))
+    }
+
     fn slim_and_then<F, R>(self, f: F) -> AndThen<Self, F>
     where
         Self: Sized,
@@ -109,6 +200,27 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, bool>(AnyAsync::new(self, predicate))
     }
 
+    fn slim_any_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> AnyAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        P: AsyncFnMut(Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_future::<_, bool>(AnyAsyncFn::new(self, predicate, |predicate: &'a mut P, item| {
+            predicate(item)
+        }))
+    }
+
+    fn slim_debounce<T>(self, timer: T, quiet_period: Duration) -> Debounce<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(Debounce::new(self, timer, quiet_period))
+    }
+
     fn slim_err_into<E>(self) -> ErrInto<Self, E>
     where
         Self: ResultAsyncIterator + Sized,
@@ -134,6 +246,29 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, Self::Item>(FilterAsync::new(self, predicate))
     }
 
+    fn slim_filter_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> FilterAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(FilterAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item: &'a Self::Item| predicate(item),
+        ))
+    }
+
+    fn slim_filter_err(self) -> FilterErr<Self>
+    where
+        Self: ResultAsyncIterator + Sized,
+    {
+        crate::support::assert_async_iter::<_, Self::Error>(FilterErr::new(self))
+    }
+
     fn slim_filter_map<F, T>(self, f: F) -> FilterMap<Self, F>
     where
         Self: Sized,
@@ -151,6 +286,14 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, T>(FilterMapAsync::new(self, f))
     }
 
+    fn slim_filter_ok(self) -> FilterOk<Self>
+    where
+        Self: Sized,
+        Self::Item: Try,
+    {
+        crate::support::assert_async_iter::<_, <Self::Item as Try>::Output>(FilterOk::new(self))
+    }
+
     fn slim_find<P>(self, predicate: P) -> Find<Self, P>
     where
         Self: Sized,
@@ -168,6 +311,22 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, Option<Self::Item>>(FindAsync::new(self, predicate))
     }
 
+    fn slim_find_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> FindAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_future::<_, Option<Self::Item>>(FindAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item: &'a Self::Item| predicate(item),
+        ))
+    }
+
     fn slim_find_map<F, T>(self, f: F) -> FindMap<Self, F>
     where
         Self: Sized,
@@ -214,6 +373,19 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, <Self::Item as IntoAsyncIterator>::Item>(Flatten::new(self))
     }
 
+    fn slim_flatten_ok(self) -> FlattenOk<Self>
+    where
+        Self: Sized,
+        Self::Item: Try,
+        <Self::Item as Try>::Output: IntoAsyncIterator,
+        <Self::Item as Try>::Residual: Residual<<<Self::Item as Try>::Output as IntoAsyncIterator>::Item>,
+    {
+        crate::support::assert_async_iter::<
+            _,
+            <<Self::Item as Try>::Residual as Residual<<<Self::Item as Try>::Output as IntoAsyncIterator>::Item>>::TryType,
+        >(FlattenOk::new(self))
+    }
+
     fn slim_fold_by<T, G, F>(self, init: T, getter: G, f: F) -> Fold<Self, T, G, F>
     where
         Self: Sized,
@@ -290,6 +462,23 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, T>(FoldAsync::new(self, init, MemTakeFn::default(), f))
     }
 
+    fn slim_fold_async_fn<'a, T, F>(
+        self,
+        init: T,
+        f: F,
+    ) -> FoldAsyncFn<'a, Self, T, F, impl AsyncFnMutCall<'a, F, (T, Self::Item), Future: Future<Output = T>>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(T, Self::Item) -> T + 'a,
+    {
+        crate::support::assert_future::<_, T>(FoldAsyncFn::new(
+            self,
+            init,
+            f,
+            |f: &'a mut F, (acc, item): (T, Self::Item)| f(acc, item),
+        ))
+    }
+
     fn slim_for_each<F>(self, f: F) -> ForEach<Self, F>
     where
         Self: Sized,
@@ -355,6 +544,17 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, Fut::Output>(MapAsync::new(self, f))
     }
 
+    fn slim_map_async_fn<'a, F, T>(
+        self,
+        f: F,
+    ) -> MapAsyncFn<'a, Self, F, impl AsyncFnMutCall<'a, F, Self::Item, Future: Future<Output = T>>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> T + 'a,
+    {
+        crate::support::assert_async_iter::<_, T>(MapAsyncFn::new(self, f, |f: &'a mut F, item| f(item)))
+    }
+
     fn slim_map_err<F, E>(self, f: F) -> MapErr<Self, F>
     where
         Self: ResultAsyncIterator + Sized,
@@ -462,6 +662,14 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, Option<Self::Item>>(ReduceAsync::new(self, f))
     }
 
+    fn slim_sample<T>(self, timer: T, period: Duration) -> Sample<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(Sample::new(self, timer, period))
+    }
+
     fn slim_scan<S, F, T>(self, state: S, f: F) -> Scan<Self, S, F>
     where
         Self: Sized,
@@ -496,6 +704,22 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, Self::Item>(SkipWhileAsync::new(self, predicate))
     }
 
+    fn slim_skip_while_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> SkipWhileAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(SkipWhileAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item: &'a Self::Item| predicate(item),
+        ))
+    }
+
     fn slim_take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
     where
         Self: Sized,
@@ -513,6 +737,184 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_async_iter::<_, Self::Item>(TakeWhileAsync::new(self, predicate))
     }
 
+    fn slim_take_while_async_fn<'a, P>(
+        self,
+        predicate: P,
+    ) -> TakeWhileAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = bool>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> bool + 'a,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(TakeWhileAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item: &'a Self::Item| predicate(item),
+        ))
+    }
+
+    fn slim_throttle<T>(self, timer: T, period: Duration) -> Throttle<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        crate::support::assert_async_iter::<_, Self::Item>(Throttle::new(self, timer, period))
+    }
+
+    fn slim_timeout<T>(self, timer: T, duration: Duration) -> Timeout<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        crate::support::assert_async_iter::<_, Result<Self::Item, TimedOut>>(Timeout::new(self, timer, duration))
+    }
+
+    fn slim_try_all<P, R>(self, predicate: P) -> TryAll<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<bool>>::TryType>(TryAll::new(self, predicate))
+    }
+
+    fn slim_try_all_async<P, Fut>(self, predicate: P) -> TryAllAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<bool>>::TryType>(
+            TryAllAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_all_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TryAllAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        P: AsyncFnMut(Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<bool>>::TryType>(TryAllAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item| predicate(item),
+        ))
+    }
+
+    fn slim_try_any<P, R>(self, predicate: P) -> TryAny<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<bool>>::TryType>(TryAny::new(self, predicate))
+    }
+
+    fn slim_try_any_async<P, Fut>(self, predicate: P) -> TryAnyAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<bool>>::TryType>(
+            TryAnyAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_any_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TryAnyAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        P: AsyncFnMut(Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<bool>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<bool>>::TryType>(TryAnyAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item| predicate(item),
+        ))
+    }
+
+    fn slim_try_find<P, R>(self, predicate: P) -> TryFind<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<Self::Item>>>::TryType>(TryFind::new(
+            self, predicate,
+        ))
+    }
+
+    fn slim_try_find_async<P, Fut>(self, predicate: P) -> TryFindAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<Option<Self::Item>>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<Option<Self::Item>>>::TryType>(
+            TryFindAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_find_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TryFindAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<Self::Item>>>::TryType>(TryFindAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item: &'a Self::Item| predicate(item),
+        ))
+    }
+
+    fn slim_try_find_map<F, R, T>(self, f: F) -> TryFindMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = Option<T>>,
+        R::Residual: Residual<Option<T>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<T>>>::TryType>(TryFindMap::new(self, f))
+    }
+
+    fn slim_try_find_map_async<F, Fut, T>(self, f: F) -> TryFindMapAsync<Self, F, T>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = Option<T>>,
+        <Fut::Output as Try>::Residual: Residual<Option<T>>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<Option<T>>>::TryType>(
+            TryFindMapAsync::new(self, f),
+        )
+    }
+
     fn slim_try_flatten(self) -> TryFlatten<Self>
     where
         Self: Sized,
@@ -609,6 +1011,24 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, Fut::Output>(TryFoldAsync::new(self, init, MemTakeFn::default(), f))
     }
 
+    fn slim_try_fold_async_fn<'a, T, F, R>(
+        self,
+        init: T,
+        f: F,
+    ) -> TryFoldAsyncFn<'a, Self, T, F, impl AsyncFnMutCall<'a, F, (T, Self::Item), Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(T, Self::Item) -> R + 'a,
+        R: Try<Output = T>,
+    {
+        crate::support::assert_future::<_, R>(TryFoldAsyncFn::new(
+            self,
+            init,
+            f,
+            |f: &'a mut F, (acc, item): (T, Self::Item)| f(acc, item),
+        ))
+    }
+
     fn slim_try_for_each<F, R>(self, f: F) -> TryForEach<Self, F>
     where
         Self: Sized,
@@ -628,6 +1048,222 @@ pub trait AsyncIteratorExt: AsyncIterator {
         crate::support::assert_future::<_, Fut::Output>(TryForEachAsync::new(self, f))
     }
 
+    fn slim_try_map_while<F, R, T>(self, f: F) -> TryMapWhile<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = Option<T>>,
+        R::Residual: Residual<T>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<T>>::TryType>(TryMapWhile::new(self, f))
+    }
+
+    fn slim_try_map_while_async<F, Fut, T>(self, f: F) -> TryMapWhileAsync<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = Option<T>>,
+        <Fut::Output as Try>::Residual: Residual<T>,
+    {
+        crate::support::assert_async_iter::<_, <<Fut::Output as Try>::Residual as Residual<T>>::TryType>(
+            TryMapWhileAsync::new(self, f),
+        )
+    }
+
+    fn slim_try_position<P, R>(self, predicate: P) -> TryPosition<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<usize>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<usize>>>::TryType>(TryPosition::new(
+            self, predicate,
+        ))
+    }
+
+    fn slim_try_position_async<P, Fut>(self, predicate: P) -> TryPositionAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<Option<usize>>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<Option<usize>>>::TryType>(
+            TryPositionAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_position_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TryPositionAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        P: AsyncFnMut(Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Option<usize>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<usize>>>::TryType>(TryPositionAsyncFn::new(
+            self,
+            predicate,
+            |predicate: &'a mut P, item| predicate(item),
+        ))
+    }
+
+    fn slim_try_reduce<F, R>(self, f: F) -> TryReduce<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Output = Self::Item>,
+        R::Residual: Residual<Option<Self::Item>>,
+    {
+        crate::support::assert_future::<_, <R::Residual as Residual<Option<Self::Item>>>::TryType>(TryReduce::new(
+            self, f,
+        ))
+    }
+
+    fn slim_try_reduce_async<F, Fut>(self, f: F) -> TryReduceAsync<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = Self::Item>,
+        <Fut::Output as Try>::Residual: Residual<Option<Self::Item>>,
+    {
+        crate::support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<Option<Self::Item>>>::TryType>(
+            TryReduceAsync::new(self, f),
+        )
+    }
+
+    fn slim_try_scan<S, F, R, T>(self, state: S, f: F) -> TryScan<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> R,
+        R: Try<Output = Option<T>>,
+        R::Residual: Residual<T>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<T>>::TryType>(TryScan::new(self, state, f))
+    }
+
+    fn slim_try_scan_async<S, F, Fut, T>(self, state: S, f: F) -> TryScanAsync<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = Option<T>>,
+        <Fut::Output as Try>::Residual: Residual<T>,
+    {
+        crate::support::assert_async_iter::<_, <<Fut::Output as Try>::Residual as Residual<T>>::TryType>(
+            TryScanAsync::new(self, state, f),
+        )
+    }
+
+    fn slim_try_skip_while<P, R>(self, predicate: P) -> TrySkipWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<Self::Item>>::TryType>(TrySkipWhile::new(
+            self, predicate,
+        ))
+    }
+
+    fn slim_try_skip_while_async<P, Fut>(self, predicate: P) -> TrySkipWhileAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <<Fut::Output as Try>::Residual as Residual<Self::Item>>::TryType>(
+            TrySkipWhileAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_skip_while_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TrySkipWhileAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<Self::Item>>::TryType>(
+            TrySkipWhileAsyncFn::new(self, predicate, |predicate: &'a mut P, item: &'a Self::Item| {
+                predicate(item)
+            }),
+        )
+    }
+
+    fn slim_try_take_while<P, R>(self, predicate: P) -> TryTakeWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> R,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<Self::Item>>::TryType>(TryTakeWhile::new(
+            self, predicate,
+        ))
+    }
+
+    fn slim_try_take_while_async<P, Fut>(self, predicate: P) -> TryTakeWhileAsync<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: Try<Output = bool>,
+        <Fut::Output as Try>::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <<Fut::Output as Try>::Residual as Residual<Self::Item>>::TryType>(
+            TryTakeWhileAsync::new(self, predicate),
+        )
+    }
+
+    fn slim_try_take_while_async_fn<'a, P, R>(
+        self,
+        predicate: P,
+    ) -> TryTakeWhileAsyncFn<'a, Self, P, impl AsyncFnMutCall<'a, P, &'a Self::Item, Future: Future<Output = R>>>
+    where
+        Self: Sized,
+        Self::Item: 'a,
+        P: AsyncFnMut(&Self::Item) -> R + 'a,
+        R: Try<Output = bool>,
+        R::Residual: Residual<Self::Item>,
+    {
+        crate::support::assert_async_iter::<_, <R::Residual as Residual<Self::Item>>::TryType>(
+            TryTakeWhileAsyncFn::new(self, predicate, |predicate: &'a mut P, item: &'a Self::Item| {
+                predicate(item)
+            }),
+        )
+    }
+
+    fn slim_unwrap_or_else<F>(self, f: F) -> UnwrapOrElse<Self, F>
+    where
+        Self: ResultAsyncIterator + Sized,
+        F: FnMut(Self::Error) -> Self::Ok,
+    {
+        crate::support::assert_async_iter::<_, Self::Ok>(UnwrapOrElse::new(self, f))
+    }
+
+    fn slim_unwrap_or_else_async<F, Fut>(self, f: F) -> UnwrapOrElseAsync<Self, F>
+    where
+        Self: ResultAsyncIterator + Sized,
+        F: FnMut(Self::Error) -> Fut,
+        Fut: IntoFuture<Output = Self::Ok>,
+    {
+        crate::support::assert_async_iter::<_, Self::Ok>(UnwrapOrElseAsync::new(self, f))
+    }
+
     fn slim_zip<I>(self, other: I) -> Zip<Self, I::IntoAsyncIter>
     where
         Self: Sized,
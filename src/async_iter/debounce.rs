@@ -0,0 +1,241 @@
+use crate::support::{self, AsyncIterator, FusedAsyncIterator};
+use crate::time::Timer;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use core::time::Duration;
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    pub struct Debounce<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: ThreeStates<(), (), T::Sleep, I::Item, (), ()>,
+        timer: T,
+        quiet_period: Duration,
+    }
+}
+
+impl<I, T> Debounce<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    pub(crate) const fn new(iter: I, timer: T, quiet_period: Duration) -> Self {
+        Self {
+            iter,
+            state: ThreeStates::A {
+                pinned: (),
+                unpinned: (),
+            },
+            timer,
+            quiet_period,
+        }
+    }
+}
+
+impl<I, T> Clone for Debounce<I, T>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    T: Timer + Clone,
+    T::Sleep: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            timer: self.timer.clone(),
+            quiet_period: self.quiet_period,
+        }
+    }
+}
+
+impl<I, T> Debug for Debounce<I, T>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    T: Timer,
+    T::Sleep: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Debounce")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .field("quiet_period", &self.quiet_period)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T> AsyncIterator for Debounce<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+        let mut remaining = support::DRAIN_LIMIT;
+
+        loop {
+            match state.as_mut().pin_project() {
+                ThreeStatesPinProject::A(idle_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                    None => {
+                        idle_state.replace_state_c((), ());
+
+                        return Poll::Ready(None);
+                    }
+                    Some(item) => {
+                        idle_state.replace_state_b(this.timer.sleep(*this.quiet_period), item);
+                    }
+                },
+                ThreeStatesPinProject::B(mut pending_state) => match iter.as_mut().poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(Some(pending_state.replace_state_c((), ()).1)),
+                    Poll::Ready(Some(item)) => {
+                        pending_state.replace_state_b(this.timer.sleep(*this.quiet_period), item);
+
+                        remaining -= 1;
+
+                        if remaining == 0 {
+                            cx.waker().wake_by_ref();
+
+                            return Poll::Pending;
+                        }
+                    }
+                    Poll::Pending => {
+                        task::ready!(pending_state.get_project().pinned.poll(cx));
+
+                        return Poll::Ready(Some(pending_state.replace_state_a((), ()).1));
+                    }
+                },
+                ThreeStatesPinProject::C(_) => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            ThreeStates::A { .. } => (0, self.iter.size_hint().1),
+            ThreeStates::B { .. } => (1, self.iter.size_hint().1.and_then(|high| high.checked_add(1))),
+            ThreeStates::C { .. } => (0, Some(0)),
+        }
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, T> for Debounce<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+);
+
+impl<I, T> FusedAsyncIterator for Debounce<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, ThreeStates::C { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::time::ManualClock;
+    use core::cell::Cell;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::{stream, task, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_debounce() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_debounce(&clock, Duration::from_secs(1));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5]);
+    }
+
+    #[test]
+    fn test_debounce_quiet_period() {
+        let clock = ManualClock::new();
+        let source = Cell::new(Poll::Pending);
+        let mut iter =
+            pin!(stream::poll_fn(|_| source.replace(Poll::Pending)).slim_debounce(&clock, Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        source.set(Poll::Ready(Some(2)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(iter.size_hint(), (1, None));
+
+        clock.advance(Duration::from_secs(1));
+        source.set(Poll::Ready(Some(3)));
+
+        // The new item restarts the quiet period.
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(3)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        source.set(Poll::Ready(Some(5)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        // The pending item is flushed when the inner async iterator ends.
+        source.set(Poll::Ready(None));
+
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(5)));
+        assert!(iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn test_debounce_clone() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_debounce(&clock, Duration::from_secs(1));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [5]);
+    }
+
+    #[test]
+    fn test_debounce_always_ready_source() {
+        let clock = ManualClock::new();
+        let mut iter = pin!(stream::repeat(2)
+            .take(100)
+            .slim_debounce(&clock, Duration::from_secs(1)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::async_iter::map_err::MapErr;
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::fns::IntoFn;
@@ -30,6 +31,15 @@ where
     }
 }
 
+impl<I, E> Debug for ErrInto<I, E>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ErrInto").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, E> AsyncIterator for ErrInto<I, E>
 where
     I: ResultAsyncIterator,
@@ -46,6 +56,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, E> for ErrInto<I, E>
+    where
+        I: ResultAsyncIterator,
+        I::Error: Into<E>,
+);
+
 impl<I, E> FusedAsyncIterator for ErrInto<I, E>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -56,6 +73,13 @@ where
     }
 }
 
+impl<I, E> ExactSizeAsyncIterator for ErrInto<I, E>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    I::Error: Into<E>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
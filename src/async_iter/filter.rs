@@ -1,5 +1,6 @@
 use crate::async_iter::filter_map::FilterMap;
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -34,7 +35,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, P> Filter<I, P> {
-    pub(crate) fn new(iter: I, predicate: P) -> Self {
+    pub(crate) const fn new(iter: I, predicate: P) -> Self {
         Self {
             inner: FilterMap::new(iter, FilterFn { predicate }),
         }
@@ -53,6 +54,15 @@ where
     }
 }
 
+impl<I, P> Debug for Filter<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Filter").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, P> AsyncIterator for Filter<I, P>
 where
     I: AsyncIterator,
@@ -69,6 +79,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, P> for Filter<I, P>
+    where
+        I: AsyncIterator,
+        P: for<'a> FnMut<(&'a I::Item,), Output = bool> + ?Sized,
+);
+
 impl<I, P> FusedAsyncIterator for Filter<I, P>
 where
     I: FusedAsyncIterator,
@@ -1,5 +1,6 @@
 use crate::support::states::{PredicateState, PredicateStateProject};
 use crate::support::{AsyncIterator, FusedAsyncIterator, PredicateFn};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -53,6 +54,22 @@ where
     }
 }
 
+impl<I, P> Debug for FilterAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    P: PredicateFn<I::Item> + ?Sized,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, P> AsyncIterator for FilterAsync<I, P>
 where
     I: AsyncIterator,
@@ -78,6 +95,7 @@ where
                     }
                 },
                 PredicateStateProject::Future(fut_state) => fut_state,
+                PredicateStateProject::Done => break Poll::Ready(None),
             };
 
             let keep = task::ready!(fut.get_pin_mut().poll(cx));
@@ -102,6 +120,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, P> for FilterAsync<I, P>
+    where
+        I: AsyncIterator,
+        P: PredicateFn<I::Item> + ?Sized,
+        <P as PredicateFn<I::Item>>::Output: IntoFuture<Output = bool>,
+);
+
 impl<I, P> FusedAsyncIterator for FilterAsync<I, P>
 where
     I: FusedAsyncIterator,
@@ -0,0 +1,146 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncPredicateState;
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`FilterAsync`](super::FilterAsync), but takes an async closure whose future may borrow from the closure and
+    /// the item.
+    pub struct FilterAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: AsyncPredicateState<'a, I::Item, P, C>,
+    }
+}
+
+impl<'a, I, P, C> FilterAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            state: AsyncPredicateState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for FilterAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterAsyncFn")
+            .field("iter", &self.iter)
+            .field("item", &self.state.get_item())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> AsyncIterator for FilterAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if let Some((keep, item)) = task::ready!(state.as_mut().poll(cx)) {
+                if keep {
+                    return Poll::Ready(Some(item));
+                }
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(None),
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let high = self.iter.size_hint().1;
+
+        (
+            0,
+            if self.state.get_item().is_some() {
+                high.and_then(|high| high.checked_add(1))
+            } else {
+                high
+            },
+        )
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<'a, I, P, C> for FilterAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        P: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+        C::Future: Future<Output = bool>,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::{stream, StreamExt};
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_filter_async_fn() {
+        let iter = stream::iter([2, 3, 5, 6]).slim_filter_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            x % 2 == 0
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 6]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_async_fn_borrows_item() {
+        let mut seen = 0;
+
+        let iter = stream::iter([String::from("a"), String::from("bc"), String::from("def")]).slim_filter_async_fn(
+            async |item: &String| {
+                let item = item.as_str();
+
+                Yield::new(1).await;
+
+                seen += 1;
+
+                item.len() != 2
+            },
+        );
+
+        assert_eq!(iter.collect::<Vec<_>>().await, ["a", "def"]);
+        assert_eq!(seen, 3);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::async_iter::filter_map::FilterMap;
+use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct FilterErrFn;
+
+impl<T, E> FnMut<(Result<T, E>,)> for FilterErrFn {
+    type Output = Option<E>;
+
+    fn call_mut(&mut self, args: (Result<T, E>,)) -> Self::Output {
+        args.0.err()
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct FilterErr<I> {
+        #[pin]
+        inner: FilterMap<I, FilterErrFn>,
+    }
+}
+
+impl<I> FilterErr<I> {
+    pub(crate) const fn new(iter: I) -> Self {
+        Self {
+            inner: FilterMap::new(iter, FilterErrFn),
+        }
+    }
+}
+
+impl<I> Clone for FilterErr<I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I> Debug for FilterErr<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterErr").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I> AsyncIterator for FilterErr<I>
+where
+    I: ResultAsyncIterator,
+{
+    type Item = I::Error;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I> for FilterErr<I>
+    where
+        I: ResultAsyncIterator,
+);
+
+impl<I> FusedAsyncIterator for FilterErr<I>
+where
+    I: ResultAsyncIterator + FusedAsyncIterator,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_filter_err() {
+        let iter = stream::iter([Ok::<u32, _>(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)]).slim_filter_err();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5, 7]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_err_clone() {
+        let iter = stream::iter([Ok::<u32, _>(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)]).slim_filter_err();
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5, 7]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [5, 7]);
+    }
+}
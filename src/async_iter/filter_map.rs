@@ -1,4 +1,5 @@
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
@@ -15,7 +16,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> FilterMap<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, f }
     }
 }
@@ -33,6 +34,18 @@ where
     }
 }
 
+impl<I, F> Debug for FilterMap<I, F>
+where
+    I: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterMap")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F, T> AsyncIterator for FilterMap<I, F>
 where
     I: AsyncIterator,
@@ -46,7 +59,9 @@ where
         let f = this.f;
 
         loop {
-            let Some(item) = task::ready!(iter.as_mut().poll_next(cx)) else { break Poll::Ready(None) };
+            let Some(item) = task::ready!(iter.as_mut().poll_next(cx)) else {
+                break Poll::Ready(None);
+            };
             let item = f.call_mut((item,));
 
             if let Some(item) = item {
@@ -60,6 +75,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F, T> for FilterMap<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,), Output = Option<T>> + ?Sized,
+);
+
 impl<I, F, T> FusedAsyncIterator for FilterMap<I, F>
 where
     I: FusedAsyncIterator,
@@ -77,7 +99,7 @@ mod tests {
     use std::vec::Vec;
 
     fn filter_map_fn(x: u32) -> Option<u32> {
-        (x % 2 == 0).then_some(x * 10)
+        x.is_multiple_of(2).then_some(x * 10)
     }
 
     #[tokio::test]
@@ -1,4 +1,5 @@
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -28,7 +29,7 @@ where
     F: FnMut<(I::Item,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: None, f }
     }
 }
@@ -49,6 +50,21 @@ where
     }
 }
 
+impl<I, F> Debug for FilterMapAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterMapAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F, T> AsyncIterator for FilterMapAsync<I, F>
 where
     I: AsyncIterator,
@@ -93,6 +109,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F, T> for FilterMapAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoFuture<Output = Option<T>>,
+);
+
 impl<I, F, T> FusedAsyncIterator for FilterMapAsync<I, F>
 where
     I: FusedAsyncIterator,
@@ -115,7 +139,7 @@ mod tests {
     use std::vec::Vec;
 
     fn filter_map_fn(x: u32) -> Ready<Option<u32>> {
-        future::ready((x % 2 == 0).then_some(x * 10))
+        future::ready(x.is_multiple_of(2).then_some(x * 10))
     }
 
     #[tokio::test]
@@ -0,0 +1,122 @@
+use crate::async_iter::filter_map::FilterMap;
+use crate::support::{AsyncIterator, FusedAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct FilterOkFn;
+
+impl<T> FnMut<(T,)> for FilterOkFn
+where
+    T: Try,
+{
+    type Output = Option<T::Output>;
+
+    fn call_mut(&mut self, args: (T,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(output) => Some(output),
+            ControlFlow::Break(_) => None,
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct FilterOk<I> {
+        #[pin]
+        inner: FilterMap<I, FilterOkFn>,
+    }
+}
+
+impl<I> FilterOk<I> {
+    pub(crate) const fn new(iter: I) -> Self {
+        Self {
+            inner: FilterMap::new(iter, FilterOkFn),
+        }
+    }
+}
+
+impl<I> Clone for FilterOk<I>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I> Debug for FilterOk<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FilterOk").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I> AsyncIterator for FilterOk<I>
+where
+    I: AsyncIterator,
+    I::Item: Try,
+{
+    type Item = <I::Item as Try>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I> for FilterOk<I>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+);
+
+impl<I> FusedAsyncIterator for FilterOk<I>
+where
+    I: FusedAsyncIterator,
+    I::Item: Try,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_filter_ok() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok::<_, u32>(13)]).slim_filter_ok();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 11, 13]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_ok_with_option() {
+        let iter = stream::iter([Some(2), None, Some(3)]).slim_filter_ok();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_ok_clone() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok::<_, u32>(13)]).slim_filter_ok();
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 11, 13]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [2, 3, 11, 13]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::async_iter::filter::Filter;
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, P> Find<I, P> {
-    pub(crate) fn new(iter: I, predicate: P) -> Self {
+    pub(crate) const fn new(iter: I, predicate: P) -> Self {
         Self {
             inner: Filter::new(iter, predicate),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, P> Debug for Find<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Find").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, P> Future for Find<I, P>
 where
     I: AsyncIterator,
@@ -1,5 +1,6 @@
 use crate::async_iter::filter_async::FilterAsync;
 use crate::support::{AsyncIterator, PredicateFn};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -45,6 +46,19 @@ where
     }
 }
 
+impl<I, P> Debug for FindAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    P: PredicateFn<I::Item>,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FindAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, P> Future for FindAsync<I, P>
 where
     I: AsyncIterator,
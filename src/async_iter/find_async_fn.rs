@@ -0,0 +1,101 @@
+use crate::async_iter::filter_async_fn::FilterAsyncFn;
+use crate::fns::AsyncFnMutCall;
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`FindAsync`](super::FindAsync), but takes an async closure whose future may borrow from the closure and
+    /// the item.
+    pub struct FindAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        inner: FilterAsyncFn<'a, I, P, C>,
+    }
+}
+
+impl<'a, I, P, C> FindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            inner: FilterAsyncFn::new(iter, predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for FindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FindAsyncFn").field("inner", &self.inner).finish()
+    }
+}
+
+impl<'a, I, P, C> Future for FindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    type Output = Option<I::Item>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_find_async_fn() {
+        let future = stream::iter([2, 3, 5]).slim_find_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            x > 2
+        });
+
+        assert_eq!(future.await, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_find_async_fn_fail() {
+        let future = stream::iter([2, 3, 5]).slim_find_async_fn(async |&x: &u32| x < 1);
+
+        assert!(future.await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_async_fn_borrows_item() {
+        let future = stream::iter([String::from("a"), String::from("bc")]).slim_find_async_fn(async |item: &String| {
+            let item = item.as_str();
+
+            Yield::new(1).await;
+
+            item.len() == 2
+        });
+
+        assert_eq!(future.await.as_deref(), Some("bc"));
+    }
+}
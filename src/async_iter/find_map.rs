@@ -1,5 +1,6 @@
 use crate::async_iter::filter_map::FilterMap;
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> FindMap<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: FilterMap::new(iter, f),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for FindMap<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FindMap").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F, T> Future for FindMap<I, F>
 where
     I: AsyncIterator,
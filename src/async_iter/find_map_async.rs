@@ -1,5 +1,6 @@
 use crate::async_iter::filter_map_async::FilterMapAsync;
 use crate::support::{AsyncIterator, OptionFuture};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -26,7 +27,7 @@ where
     F::Output: IntoFuture,
     <F::Output as IntoFuture>::IntoFuture: OptionFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: FilterMapAsync::new(iter, f),
         }
@@ -47,6 +48,18 @@ where
     }
 }
 
+impl<I, F> Debug for FindMapAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: OptionFuture + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FindMapAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F, T> Future for FindMapAsync<I, F>
 where
     I: AsyncIterator,
@@ -1,6 +1,7 @@
 use crate::async_iter::flatten::Flatten;
 use crate::async_iter::map::Map;
 use crate::support::{AsyncIterator, FusedAsyncIterator, IntoAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -24,7 +25,7 @@ where
     F: FnMut<(I::Item,)>,
     F::Output: IntoAsyncIterator,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Flatten::new(Map::new(iter, f)),
         }
@@ -45,6 +46,18 @@ where
     }
 }
 
+impl<I, F> Debug for FlatMap<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoAsyncIterator,
+    <F::Output as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FlatMap").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for FlatMap<I, F>
 where
     I: AsyncIterator,
@@ -62,6 +75,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for FlatMap<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoAsyncIterator,
+);
+
 impl<I, F> FusedAsyncIterator for FlatMap<I, F>
 where
     I: FusedAsyncIterator,
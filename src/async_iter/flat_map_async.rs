@@ -1,6 +1,7 @@
 use crate::async_iter::flatten::Flatten;
 use crate::async_iter::map_async::MapAsync;
 use crate::support::{AsyncIterator, FusedAsyncIterator, IntoAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -28,7 +29,7 @@ where
     F::Output: IntoFuture,
     <F::Output as IntoFuture>::Output: IntoAsyncIterator,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Flatten::new(MapAsync::new(iter, f)),
         }
@@ -51,6 +52,20 @@ where
     }
 }
 
+impl<I, F> Debug for FlatMapAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: IntoAsyncIterator,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+    <<F::Output as IntoFuture>::Output as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FlatMapAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for FlatMapAsync<I, F>
 where
     I: AsyncIterator,
@@ -69,6 +84,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for FlatMapAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: IntoAsyncIterator,
+);
+
 impl<I, F> FusedAsyncIterator for FlatMapAsync<I, F>
 where
     I: FusedAsyncIterator,
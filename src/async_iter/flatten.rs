@@ -1,28 +1,9 @@
 use crate::support::{AsyncIterator, FusedAsyncIterator, IntoAsyncIterator};
-use core::ops::ControlFlow;
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
-use fn_traits::FnMut;
 use option_entry::{OptionEntryExt, OptionPinnedEntry};
 
-#[derive(Clone)]
-struct FlattenFn<F> {
-    f: F,
-}
-
-impl<T, F, U> FnMut<((), T)> for FlattenFn<F>
-where
-    F: FnMut<(T,), Output = Option<U>>,
-{
-    type Output = ControlFlow<U>;
-
-    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
-        self.f
-            .call_mut((args.1,))
-            .map_or(ControlFlow::Continue(()), ControlFlow::Break)
-    }
-}
-
 pin_project_lite::pin_project! {
     pub struct Flatten<I>
     where
@@ -42,7 +23,7 @@ where
     I: AsyncIterator,
     I::Item: IntoAsyncIterator,
 {
-    pub(crate) fn new(iter: I) -> Self {
+    pub(crate) const fn new(iter: I) -> Self {
         Self { state: None, iter }
     }
 }
@@ -61,6 +42,20 @@ where
     }
 }
 
+impl<I> Debug for Flatten<I>
+where
+    I: AsyncIterator + Debug,
+    I::Item: IntoAsyncIterator,
+    <I::Item as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Flatten")
+            .field("state", &self.state)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I> AsyncIterator for Flatten<I>
 where
     I: AsyncIterator + ?Sized,
@@ -110,6 +105,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I> for Flatten<I>
+    where
+        I: AsyncIterator + ?Sized,
+        I::Item: IntoAsyncIterator,
+);
+
 impl<I> FusedAsyncIterator for Flatten<I>
 where
     I: FusedAsyncIterator + ?Sized,
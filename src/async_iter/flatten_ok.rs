@@ -0,0 +1,192 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, IntoAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct FlattenOk<I>
+    where
+        I: AsyncIterator,
+        I: ?Sized,
+        I::Item: Try,
+        <I::Item as Try>::Output: IntoAsyncIterator,
+    {
+        #[pin]
+        state: Option<<<I::Item as Try>::Output as IntoAsyncIterator>::IntoAsyncIter>,
+        #[pin]
+        iter: I,
+    }
+}
+
+impl<I> FlattenOk<I>
+where
+    I: AsyncIterator,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+{
+    pub(crate) const fn new(iter: I) -> Self {
+        Self { state: None, iter }
+    }
+}
+
+impl<I> Clone for FlattenOk<I>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+    <<I::Item as Try>::Output as IntoAsyncIterator>::IntoAsyncIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I> Debug for FlattenOk<I>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+    <<I::Item as Try>::Output as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FlattenOk")
+            .field("state", &self.state)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> AsyncIterator for FlattenOk<I>
+where
+    I: AsyncIterator + ?Sized,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+    <I::Item as Try>::Residual: Residual<<<I::Item as Try>::Output as IntoAsyncIterator>::Item>,
+{
+    type Item =
+        <<I::Item as Try>::Residual as Residual<<<I::Item as Try>::Output as IntoAsyncIterator>::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut state = this.state.pinned_entry();
+        let mut iter = this.iter;
+
+        loop {
+            let mut sub_iter = match state {
+                OptionPinnedEntry::None(none_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                    None => break Poll::Ready(None),
+                    Some(item) => match item.branch() {
+                        ControlFlow::Continue(output) => none_state.replace_some(output.into_async_iter()),
+                        ControlFlow::Break(residual) => break Poll::Ready(Some(Self::Item::from_residual(residual))),
+                    },
+                },
+                OptionPinnedEntry::Some(some_state) => some_state,
+            };
+
+            let item = task::ready!(sub_iter.get_pin_mut().poll_next(cx));
+
+            if let Some(item) = item {
+                break Poll::Ready(Some(Self::Item::from_output(item)));
+            }
+
+            state = OptionPinnedEntry::None(sub_iter.replace_none());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let has_more = self.iter.size_hint().1 != Some(0);
+
+        self.state.as_ref().map_or_else(
+            || (0, (!has_more).then_some(0)),
+            |state| {
+                let mut candidate = state.size_hint();
+
+                if has_more {
+                    candidate.1 = None;
+                }
+
+                candidate
+            },
+        )
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I> for FlattenOk<I>
+    where
+        I: AsyncIterator + ?Sized,
+        I::Item: Try,
+        <I::Item as Try>::Output: IntoAsyncIterator,
+        <I::Item as Try>::Residual: Residual<<<I::Item as Try>::Output as IntoAsyncIterator>::Item>,
+);
+
+impl<I> FusedAsyncIterator for FlattenOk<I>
+where
+    I: FusedAsyncIterator + ?Sized,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+    <I::Item as Try>::Residual: Residual<<<I::Item as Try>::Output as IntoAsyncIterator>::Item>,
+    <<I::Item as Try>::Output as IntoAsyncIterator>::IntoAsyncIter: FusedAsyncIterator,
+{
+    fn is_terminated(&self) -> bool {
+        self.state
+            .as_ref()
+            .map_or_else(|| self.iter.is_terminated(), FusedAsyncIterator::is_terminated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_flatten_ok() {
+        let iter = stream::iter([
+            Ok(stream::iter(std::vec![2, 3])),
+            Err(5),
+            Ok(stream::iter(std::vec![7, 11, 13])),
+        ])
+        .slim_flatten_ok();
+
+        assert_eq!(
+            iter.collect::<Vec<_>>().await,
+            [Ok(2), Ok(3), Err(5), Ok(7), Ok(11), Ok(13)],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_flatten_ok_with_option() {
+        let iter = stream::iter([Some(stream::iter(std::vec![2, 3])), None]).slim_flatten_ok();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Some(2), Some(3), None]);
+    }
+
+    #[tokio::test]
+    async fn test_flatten_ok_clone() {
+        let iter = stream::iter([
+            Ok(stream::iter(std::vec![2, 3])),
+            Err(5),
+            Ok(stream::iter(std::vec![7, 11, 13])),
+        ])
+        .slim_flatten_ok();
+
+        let iter_2 = iter.clone();
+
+        assert_eq!(
+            iter.collect::<Vec<_>>().await,
+            [Ok(2), Ok(3), Err(5), Ok(7), Ok(11), Ok(13)],
+        );
+
+        assert_eq!(
+            iter_2.collect::<Vec<_>>().await,
+            [Ok(2), Ok(3), Err(5), Ok(7), Ok(11), Ok(13)],
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -19,7 +20,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, T, G, F> Fold<I, T, G, F> {
-    pub(crate) fn new(iter: I, acc: T, getter: G, f: F) -> Self {
+    pub(crate) const fn new(iter: I, acc: T, getter: G, f: F) -> Self {
         Self { iter, acc, getter, f }
     }
 }
@@ -41,6 +42,20 @@ where
     }
 }
 
+impl<I, T, G, F> Debug for Fold<I, T, G, F>
+where
+    I: Debug,
+    T: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Fold")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, T, G, F> Future for Fold<I, T, G, F>
 where
     I: AsyncIterator,
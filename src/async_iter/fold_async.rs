@@ -1,5 +1,6 @@
 use crate::support::states::{FoldState, FoldStateProject};
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -29,7 +30,7 @@ where
     F: FnMut<(T, I::Item)>,
     F::Output: IntoFuture<Output = T>,
 {
-    pub(crate) fn new(iter: I, acc: T, getter: G, f: F) -> Self {
+    pub(crate) const fn new(iter: I, acc: T, getter: G, f: F) -> Self {
         Self {
             iter,
             getter,
@@ -58,6 +59,22 @@ where
     }
 }
 
+impl<I, T, G, F> Debug for FoldAsync<I, T, G, F>
+where
+    I: AsyncIterator + Debug,
+    T: Debug,
+    F: FnMut<(T, I::Item)> + ?Sized,
+    F::Output: IntoFuture<Output = T>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FoldAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, T, G, F> Future for FoldAsync<I, T, G, F>
 where
     I: AsyncIterator,
@@ -77,7 +94,7 @@ where
         loop {
             let mut fut = match state {
                 FoldStateProject::Accumulate(mut acc_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
-                    None => break Poll::Ready(getter.call_mut((acc_state.get_mut(),))),
+                    None => break Poll::Ready(acc_state.set_done()),
                     Some(item) => {
                         let fut = f
                             .call_mut((getter.call_mut((acc_state.get_mut(),)), item))
@@ -87,6 +104,7 @@ where
                     }
                 },
                 FoldStateProject::Future(fut_state) => fut_state,
+                FoldStateProject::Done => break Poll::Pending,
             };
 
             let acc = task::ready!(fut.get_pin_mut().poll(cx));
@@ -98,16 +116,13 @@ where
 
 impl<I, T, G, F> FusedFuture for FoldAsync<I, T, G, F>
 where
-    I: FusedAsyncIterator,
+    I: AsyncIterator,
     G: for<'a> FnMut<(&'a mut T,), Output = T>,
     F: FnMut<(T, I::Item)> + ?Sized,
     F::Output: IntoFuture<Output = T>,
-    <F::Output as IntoFuture>::IntoFuture: FusedFuture,
 {
     fn is_terminated(&self) -> bool {
-        self.state
-            .get_future()
-            .map_or_else(|| self.iter.is_terminated(), FusedFuture::is_terminated)
+        self.state.is_done()
     }
 }
 
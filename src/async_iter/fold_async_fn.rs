@@ -0,0 +1,139 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    /// Like [`FoldAsync`](super::FoldAsync), but takes an async closure whose future may borrow from the closure
+    /// itself. The accumulator is moved into each call, so no getter is needed.
+    pub struct FoldAsyncFn<'a, I, T, F, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    {
+        #[pin]
+        iter: I,
+        acc: Option<T>,
+        #[pin]
+        state: AsyncFnState<'a, F, (T, I::Item), C>,
+    }
+}
+
+impl<'a, I, T, F, C> FoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+{
+    pub(crate) const fn new(iter: I, init: T, f: F, call: C) -> Self {
+        Self {
+            iter,
+            acc: Some(init),
+            state: AsyncFnState::new(f, call),
+        }
+    }
+}
+
+impl<'a, I, T, F, C> Debug for FoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator + Debug,
+    T: Debug,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FoldAsyncFn")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, T, F, C> Future for FoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    C::Future: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if let Some(acc) = task::ready!(state.as_mut().poll(cx)) {
+                *this.acc = Some(acc);
+            }
+
+            let Some(acc) = this.acc.take() else {
+                return Poll::Pending;
+            };
+
+            match iter.as_mut().poll_next(cx) {
+                Poll::Ready(None) => return Poll::Ready(acc),
+                Poll::Ready(Some(item)) => state.as_mut().start((acc, item)),
+                Poll::Pending => {
+                    *this.acc = Some(acc);
+
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<'a, I, T, F, C> FusedFuture for FoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    C::Future: Future<Output = T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none() && !self.state.is_pending()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_fold_async_fn() {
+        let factor = 2;
+
+        let future = stream::iter([2, 3, 5]).slim_fold_async_fn(1, async move |acc: u32, x: u32| {
+            let factor = &factor;
+
+            Yield::new(1).await;
+
+            acc + x * factor
+        });
+
+        assert_eq!(future.await, 21);
+    }
+
+    #[tokio::test]
+    async fn test_fold_async_fn_moves_accumulator() {
+        let future = stream::iter(["a", "b", "c"]).slim_fold_async_fn(String::new(), async |mut acc: String, x| {
+            Yield::new(1).await;
+
+            acc.push_str(x);
+
+            acc
+        });
+
+        assert_eq!(future.await, "abc");
+    }
+}
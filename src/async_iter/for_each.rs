@@ -1,6 +1,7 @@
 use crate::async_iter::fold::Fold;
 use crate::support::fns::ForEachFn;
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -38,6 +39,15 @@ where
     }
 }
 
+impl<I, F> Debug for ForEach<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ForEach").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> Future for ForEach<I, F>
 where
     I: AsyncIterator,
@@ -1,6 +1,7 @@
 use crate::async_iter::fold_async::FoldAsync;
 use crate::support::fns::ForEachFn;
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -48,6 +49,18 @@ where
     }
 }
 
+impl<I, F> Debug for ForEachAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture<Output = ()>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ForEachAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> Future for ForEachAsync<I, F>
 where
     I: AsyncIterator,
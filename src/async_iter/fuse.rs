@@ -1,4 +1,5 @@
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use option_entry::{OptionEntryExt, OptionPinnedEntry};
@@ -11,7 +12,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I> Fuse<I> {
-    pub(crate) fn new(iter: I) -> Self {
+    pub const fn new(iter: I) -> Self {
         Self { iter: Some(iter) }
     }
 }
@@ -27,6 +28,15 @@ where
     }
 }
 
+impl<I> Debug for Fuse<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Fuse").field("iter", &self.iter).finish()
+    }
+}
+
 impl<I> AsyncIterator for Fuse<I>
 where
     I: AsyncIterator,
@@ -54,6 +64,12 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I> for Fuse<I>
+    where
+        I: AsyncIterator,
+);
+
 impl<I> FusedAsyncIterator for Fuse<I>
 where
     I: AsyncIterator,
@@ -63,6 +79,8 @@ where
     }
 }
 
+impl<I> ExactSizeAsyncIterator for Fuse<I> where I: ExactSizeAsyncIterator {}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,6 +1,7 @@
 use crate::async_iter::map::Map;
 use crate::support::fns::InspectFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> Inspect<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Map::new(iter, InspectFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for Inspect<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Inspect").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for Inspect<I, F>
 where
     I: AsyncIterator,
@@ -51,6 +61,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for Inspect<I, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a I::Item,), Output = ()> + ?Sized,
+);
+
 impl<I, F> FusedAsyncIterator for Inspect<I, F>
 where
     I: FusedAsyncIterator,
@@ -61,6 +78,13 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for Inspect<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    F: for<'a> FnMut<(&'a I::Item,), Output = ()> + ?Sized,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
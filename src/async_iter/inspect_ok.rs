@@ -1,6 +1,7 @@
 use crate::async_iter::inspect::Inspect;
 use crate::support::fns::InspectOkFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> InspectOk<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Inspect::new(iter, InspectOkFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for InspectOk<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("InspectOk").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for InspectOk<I, F>
 where
     I: ResultAsyncIterator,
@@ -51,6 +61,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for InspectOk<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: for<'a> FnMut<(&'a I::Ok,), Output = ()> + ?Sized,
+);
+
 impl<I, F> FusedAsyncIterator for InspectOk<I, F>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -61,6 +78,13 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for InspectOk<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: for<'a> FnMut<(&'a I::Ok,), Output = ()> + ?Sized,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
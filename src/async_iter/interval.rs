@@ -0,0 +1,174 @@
+use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::time::Timer;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use core::time::Duration;
+
+pin_project_lite::pin_project! {
+    pub struct Interval<T>
+    where
+        T: Timer,
+    {
+        timer: T,
+        period: Duration,
+        deadline: T::Instant,
+        #[pin]
+        sleep: T::Sleep,
+    }
+}
+
+impl<T> Clone for Interval<T>
+where
+    T: Timer + Clone,
+    T::Sleep: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            timer: self.timer.clone(),
+            period: self.period,
+            deadline: self.deadline,
+            sleep: self.sleep.clone(),
+        }
+    }
+}
+
+impl<T> Debug for Interval<T>
+where
+    T: Timer,
+    T::Sleep: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("period", &self.period)
+            .field("sleep", &self.sleep)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> AsyncIterator for Interval<T>
+where
+    T: Timer,
+{
+    type Item = T::Instant;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut sleep = this.sleep;
+
+        task::ready!(sleep.as_mut().poll(cx));
+
+        let tick = *this.deadline;
+
+        *this.deadline = tick + *this.period;
+        sleep.set(this.timer.sleep_until(*this.deadline));
+
+        Poll::Ready(Some(tick))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<T> for Interval<T>
+    where
+        T: Timer,
+);
+
+impl<T> FusedAsyncIterator for Interval<T>
+where
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        false
+    }
+}
+
+/// Creates an async iterator that yields the scheduled instant of each tick, with the first tick one `period` after
+/// the call. Ticks are scheduled relative to the previous deadline, so missed ticks are yielded back to back.
+pub fn interval<T>(timer: T, period: Duration) -> Interval<T>
+where
+    T: Timer,
+{
+    let deadline = timer.now() + period;
+    let sleep = timer.sleep_until(deadline);
+
+    crate::support::assert_async_iter::<_, T::Instant>(Interval {
+        timer,
+        period,
+        deadline,
+        sleep,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::{ManualClock, ManualInstant};
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::task;
+
+    #[test]
+    fn test_interval() {
+        let clock = ManualClock::new();
+        let mut iter = pin!(super::interval(&clock, Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        let tick = iter.as_mut().poll_next(&mut cx);
+
+        assert_eq!(
+            tick.map(|tick| tick.map(ManualInstant::since_start)),
+            Poll::Ready(Some(Duration::from_secs(2)))
+        );
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        // Missed ticks are yielded immediately.
+        clock.advance(Duration::from_secs(6));
+
+        let ticks = [(); 3].map(|()| {
+            iter.as_mut()
+                .poll_next(&mut cx)
+                .map(|tick| tick.map(ManualInstant::since_start))
+        });
+
+        assert_eq!(
+            ticks,
+            [4, 6, 8].map(|secs| Poll::Ready(Some(Duration::from_secs(secs))))
+        );
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_interval_clone() {
+        let clock = ManualClock::new();
+        let iter = super::interval(&clock, Duration::from_secs(1));
+        let mut iter_2 = pin!(iter.clone());
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        clock.advance(Duration::from_secs(1));
+
+        assert!(matches!(iter_2.as_mut().poll_next(&mut cx), Poll::Ready(Some(_))));
+    }
+
+    #[tokio::test]
+    async fn test_interval_fused_async_iter() {
+        let clock = ManualClock::new();
+        let iter = super::interval(&clock, Duration::from_secs(1));
+
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+    }
+}
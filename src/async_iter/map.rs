@@ -1,4 +1,5 @@
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
@@ -15,7 +16,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> Map<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self { iter, f }
     }
 }
@@ -33,6 +34,16 @@ where
     }
 }
 
+impl<I, F> Debug for Map<I, F>
+where
+    I: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Map").field("iter", &self.iter).finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for Map<I, F>
 where
     I: AsyncIterator,
@@ -51,6 +62,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for Map<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+);
+
 impl<I, F> FusedAsyncIterator for Map<I, F>
 where
     I: FusedAsyncIterator,
@@ -61,9 +79,20 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for Map<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::async_iter::Map;
+    use crate::fns::FnMut;
+    use core::ops::Range;
+    use futures_util::stream::Iter;
     use futures_util::{stream, StreamExt};
     use std::vec::Vec;
 
@@ -78,6 +107,23 @@ mod tests {
         assert_eq!(iter.collect::<Vec<_>>().await, [0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
     }
 
+    #[tokio::test]
+    async fn test_map_with_named_function() {
+        struct Times10;
+
+        impl FnMut<(u32,)> for Times10 {
+            type Output = u64;
+
+            fn call_mut(&mut self, args: (u32,)) -> Self::Output {
+                map_fn(args.0)
+            }
+        }
+
+        let iter: Map<Iter<Range<u32>>, Times10> = Map::new(stream::iter(0..4), Times10);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [0, 10, 20, 30]);
+    }
+
     #[tokio::test]
     async fn test_map_clone() {
         let iter = stream::iter(0..10).slim_map(map_fn);
@@ -90,4 +136,21 @@ mod tests {
             [0, 10, 20, 30, 40, 50, 60, 70, 80, 90],
         );
     }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_map_core_async_iterator() {
+        use core::async_iter::AsyncIterator;
+        use core::pin::pin;
+        use core::task::{Context, Poll};
+        use futures_util::task;
+
+        let mut iter = pin!(stream::iter([2, 3]).slim_map(map_fn));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(AsyncIterator::size_hint(&iter), (2, Some(2)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(20)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(30)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
 }
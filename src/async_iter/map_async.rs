@@ -1,4 +1,5 @@
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -28,7 +29,7 @@ where
     F: FnMut<(I::Item,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: None, f }
     }
 }
@@ -49,6 +50,21 @@ where
     }
 }
 
+impl<I, F> Debug for MapAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for MapAsync<I, F>
 where
     I: AsyncIterator,
@@ -90,6 +106,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for MapAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoFuture,
+);
+
 impl<I, F> FusedAsyncIterator for MapAsync<I, F>
 where
     I: FusedAsyncIterator,
@@ -104,6 +128,14 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for MapAsync<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -122,6 +154,14 @@ mod tests {
         assert_eq!(iter.collect::<Vec<_>>().await, [0, 10, 20, 30, 40, 50, 60, 70, 80, 90]);
     }
 
+    #[tokio::test]
+    async fn test_map_async_with_async_closure() {
+        let factor = 10;
+        let iter = stream::iter(0..5).slim_map_async(async |x: u32| u64::from(x) * factor);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [0, 10, 20, 30, 40]);
+    }
+
     #[tokio::test]
     async fn test_map_async_clone() {
         let iter = stream::iter(0..10).slim_map_async(map_fn);
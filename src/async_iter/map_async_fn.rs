@@ -0,0 +1,141 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`MapAsync`](super::MapAsync), but takes an async closure whose future may borrow from the closure itself.
+    pub struct MapAsyncFn<'a, I, F, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, F, I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: AsyncFnState<'a, F, I::Item, C>,
+    }
+}
+
+impl<'a, I, F, C> MapAsyncFn<'a, I, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, I::Item>,
+{
+    pub(crate) const fn new(iter: I, f: F, call: C) -> Self {
+        Self {
+            iter,
+            state: AsyncFnState::new(f, call),
+        }
+    }
+}
+
+impl<'a, I, F, C> Debug for MapAsyncFn<'a, I, F, C>
+where
+    I: AsyncIterator + Debug,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapAsyncFn")
+            .field("iter", &self.iter)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, F, C> AsyncIterator for MapAsyncFn<'a, I, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, I::Item>,
+{
+    type Item = <C::Future as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut state = this.state;
+
+        if let Some(item) = task::ready!(state.as_mut().poll(cx)) {
+            return Poll::Ready(Some(item));
+        }
+
+        Poll::Ready(match task::ready!(this.iter.poll_next(cx)) {
+            None => None,
+            Some(item) => {
+                state.as_mut().start(item);
+
+                task::ready!(state.poll(cx))
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut candidate = self.iter.size_hint();
+
+        if self.state.is_pending() {
+            candidate.0 = candidate.0.saturating_add(1);
+            candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+        }
+
+        candidate
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<'a, I, F, C> for MapAsyncFn<'a, I, F, C>
+    where
+        I: AsyncIterator,
+        F: 'a,
+        C: AsyncFnMutCall<'a, F, I::Item>,
+);
+
+impl<'a, I, F, C> ExactSizeAsyncIterator for MapAsyncFn<'a, I, F, C>
+where
+    I: ExactSizeAsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_map_async_fn() {
+        let mut offset = 0;
+
+        let iter = stream::iter([2, 3, 5]).slim_map_async_fn(async |x: u32| {
+            Yield::new(1).await;
+
+            offset += 1;
+
+            x + offset
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [3_u32, 5, 8]);
+    }
+
+    #[tokio::test]
+    async fn test_map_async_fn_borrows_closure() {
+        let factor = 10;
+
+        let iter = stream::iter([2, 3]).slim_map_async_fn(async move |x: u32| {
+            let factor = &factor;
+
+            Yield::new(1).await;
+
+            x * factor
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [20_u32, 30]);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::async_iter::map::Map;
 use crate::support::fns::MapErrFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> MapErr<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Map::new(iter, MapErrFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for MapErr<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapErr").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for MapErr<I, F>
 where
     I: ResultAsyncIterator,
@@ -51,6 +61,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for MapErr<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)> + ?Sized,
+);
+
 impl<I, F> FusedAsyncIterator for MapErr<I, F>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -61,6 +78,13 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for MapErr<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
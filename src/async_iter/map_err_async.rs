@@ -1,6 +1,7 @@
 use crate::async_iter::or_else_async::OrElseAsync;
 use crate::support::fns::MapErrAsyncFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -26,7 +27,7 @@ where
     F: FnMut<(I::Error,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: OrElseAsync::new(iter, MapErrAsyncFn::new(f)),
         }
@@ -47,6 +48,18 @@ where
     }
 }
 
+impl<I, F> Debug for MapErrAsync<I, F>
+where
+    I: ResultAsyncIterator + Debug,
+    F: FnMut<(I::Error,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapErrAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for MapErrAsync<I, F>
 where
     I: ResultAsyncIterator,
@@ -64,6 +77,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for MapErrAsync<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)> + ?Sized,
+        F::Output: IntoFuture,
+);
+
 impl<I, F> FusedAsyncIterator for MapErrAsync<I, F>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -76,6 +97,14 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for MapErrAsync<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
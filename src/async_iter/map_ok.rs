@@ -1,6 +1,7 @@
 use crate::async_iter::map::Map;
 use crate::support::fns::MapOkFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, Residual, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> MapOk<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Map::new(iter, MapOkFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for MapOk<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOk").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for MapOk<I, F>
 where
     I: AsyncIterator,
@@ -53,6 +63,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for MapOk<I, F>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+        <I::Item as Try>::Residual: Residual<F::Output>,
+        F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+);
+
 impl<I, F> FusedAsyncIterator for MapOk<I, F>
 where
     I: FusedAsyncIterator,
@@ -65,6 +84,15 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for MapOk<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    I::Item: Try,
+    <I::Item as Try>::Residual: Residual<F::Output>,
+    F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,6 +1,7 @@
 use crate::async_iter::and_then_async::AndThenAsync;
 use crate::support::fns::MapOkAsyncFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, Residual, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -30,7 +31,7 @@ where
     F: FnMut<(<I::Item as Try>::Output,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self {
             inner: AndThenAsync::new(iter, MapOkAsyncFn::new(f)),
         }
@@ -53,6 +54,20 @@ where
     }
 }
 
+impl<I, F> Debug for MapOkAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Try,
+    <I::Item as Try>::Residual: Residual<<F::Output as IntoFuture>::Output>,
+    F: FnMut<(<I::Item as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOkAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for MapOkAsync<I, F>
 where
     I: AsyncIterator,
@@ -72,6 +87,16 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for MapOkAsync<I, F>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+        <I::Item as Try>::Residual: Residual<<F::Output as IntoFuture>::Output>,
+        F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+        F::Output: IntoFuture,
+);
+
 impl<I, F> FusedAsyncIterator for MapOkAsync<I, F>
 where
     I: FusedAsyncIterator,
@@ -86,6 +111,16 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for MapOkAsync<I, F>
+where
+    I: ExactSizeAsyncIterator,
+    I::Item: Try,
+    <I::Item as Try>::Residual: Residual<<F::Output as IntoFuture>::Output>,
+    F: FnMut<(<I::Item as Try>::Output,)> + ?Sized,
+    F::Output: IntoFuture,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,4 +1,5 @@
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
@@ -15,7 +16,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> MapWhile<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, f }
     }
 }
@@ -33,6 +34,18 @@ where
     }
 }
 
+impl<I, F> Debug for MapWhile<I, F>
+where
+    I: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapWhile")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F, T> AsyncIterator for MapWhile<I, F>
 where
     I: AsyncIterator,
@@ -51,6 +64,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F, T> for MapWhile<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,), Output = Option<T>> + ?Sized,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
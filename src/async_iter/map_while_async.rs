@@ -1,4 +1,5 @@
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -28,7 +29,7 @@ where
     F: FnMut<(I::Item,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: None, f }
     }
 }
@@ -49,6 +50,21 @@ where
     }
 }
 
+impl<I, F> Debug for MapWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F, T> AsyncIterator for MapWhileAsync<I, F>
 where
     I: AsyncIterator,
@@ -89,6 +105,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F, T> for MapWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoFuture<Output = Option<T>>,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
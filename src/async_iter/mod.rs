@@ -1,31 +1,44 @@
+pub use self::abortable::Abortable;
 pub use self::all::All;
 pub use self::all_async::AllAsync;
+pub use self::all_async_fn::AllAsyncFn;
 pub use self::and_then::AndThen;
 pub use self::and_then_async::AndThenAsync;
 pub use self::any::Any;
 pub use self::any_async::AnyAsync;
+pub use self::any_async_fn::AnyAsyncFn;
 pub use self::async_iterator_ext::AsyncIteratorExt;
+pub use self::debounce::Debounce;
 pub use self::err_into::ErrInto;
 pub use self::filter::Filter;
 pub use self::filter_async::FilterAsync;
+pub use self::filter_async_fn::FilterAsyncFn;
+pub use self::filter_err::FilterErr;
 pub use self::filter_map::FilterMap;
 pub use self::filter_map_async::FilterMapAsync;
+pub use self::filter_ok::FilterOk;
 pub use self::find::Find;
 pub use self::find_async::FindAsync;
+pub use self::find_async_fn::FindAsyncFn;
 pub use self::find_map::FindMap;
 pub use self::find_map_async::FindMapAsync;
 pub use self::flat_map::FlatMap;
 pub use self::flat_map_async::FlatMapAsync;
 pub use self::flatten::Flatten;
+pub use self::flatten_ok::FlattenOk;
 pub use self::fold::Fold;
 pub use self::fold_async::FoldAsync;
+pub use self::fold_async_fn::FoldAsyncFn;
 pub use self::for_each::ForEach;
 pub use self::for_each_async::ForEachAsync;
 pub use self::fuse::Fuse;
 pub use self::inspect::Inspect;
+pub use self::inspect_err::InspectErr;
 pub use self::inspect_ok::InspectOk;
+pub use self::interval::{interval, Interval};
 pub use self::map::Map;
 pub use self::map_async::MapAsync;
+pub use self::map_async_fn::MapAsyncFn;
 pub use self::map_err::MapErr;
 pub use self::map_err_async::MapErrAsync;
 pub use self::map_ok::MapOk;
@@ -37,48 +50,94 @@ pub use self::or_else::OrElse;
 pub use self::or_else_async::OrElseAsync;
 pub use self::reduce::Reduce;
 pub use self::reduce_async::ReduceAsync;
+pub use self::sample::Sample;
 pub use self::scan::Scan;
 pub use self::scan_async::ScanAsync;
 pub use self::skip_while::SkipWhile;
 pub use self::skip_while_async::SkipWhileAsync;
+pub use self::skip_while_async_fn::SkipWhileAsyncFn;
 pub use self::take_while::TakeWhile;
 pub use self::take_while_async::TakeWhileAsync;
+pub use self::take_while_async_fn::TakeWhileAsyncFn;
+pub use self::throttle::Throttle;
+pub use self::timeout::Timeout;
+pub use self::try_all::TryAll;
+pub use self::try_all_async::TryAllAsync;
+pub use self::try_all_async_fn::TryAllAsyncFn;
+pub use self::try_any::TryAny;
+pub use self::try_any_async::TryAnyAsync;
+pub use self::try_any_async_fn::TryAnyAsyncFn;
+pub use self::try_find::TryFind;
+pub use self::try_find_async::TryFindAsync;
+pub use self::try_find_async_fn::TryFindAsyncFn;
+pub use self::try_find_map::TryFindMap;
+pub use self::try_find_map_async::TryFindMapAsync;
 pub use self::try_flatten::TryFlatten;
 pub use self::try_fold::TryFold;
 pub use self::try_fold_async::TryFoldAsync;
+pub use self::try_fold_async_fn::TryFoldAsyncFn;
 pub use self::try_for_each::TryForEach;
 pub use self::try_for_each_async::TryForEachAsync;
+pub use self::try_map_while::TryMapWhile;
+pub use self::try_map_while_async::TryMapWhileAsync;
+pub use self::try_position::TryPosition;
+pub use self::try_position_async::TryPositionAsync;
+pub use self::try_position_async_fn::TryPositionAsyncFn;
+pub use self::try_reduce::TryReduce;
+pub use self::try_reduce_async::TryReduceAsync;
+pub use self::try_scan::TryScan;
+pub use self::try_scan_async::TryScanAsync;
+pub use self::try_skip_while::TrySkipWhile;
+pub use self::try_skip_while_async::TrySkipWhileAsync;
+pub use self::try_skip_while_async_fn::TrySkipWhileAsyncFn;
+pub use self::try_take_while::TryTakeWhile;
+pub use self::try_take_while_async::TryTakeWhileAsync;
+pub use self::try_take_while_async_fn::TryTakeWhileAsyncFn;
+pub use self::unwrap_or_else::UnwrapOrElse;
+pub use self::unwrap_or_else_async::UnwrapOrElseAsync;
 pub use self::zip::Zip;
 
+mod abortable;
 mod all;
 mod all_async;
+mod all_async_fn;
 mod and_then;
 mod and_then_async;
 mod any;
 mod any_async;
+mod any_async_fn;
 mod async_iterator_ext;
+mod debounce;
 mod err_into;
 mod filter;
 mod filter_async;
+mod filter_async_fn;
+mod filter_err;
 mod filter_map;
 mod filter_map_async;
+mod filter_ok;
 mod find;
 mod find_async;
+mod find_async_fn;
 mod find_map;
 mod find_map_async;
 mod flat_map;
 mod flat_map_async;
 mod flatten;
+mod flatten_ok;
 mod fold;
 mod fold_async;
+mod fold_async_fn;
 mod for_each;
 mod for_each_async;
 mod fuse;
 mod inspect;
 mod inspect_err;
 mod inspect_ok;
+mod interval;
 mod map;
 mod map_async;
+mod map_async_fn;
 mod map_err;
 mod map_err_async;
 mod map_ok;
@@ -90,15 +149,49 @@ mod or_else;
 mod or_else_async;
 mod reduce;
 mod reduce_async;
+mod sample;
 mod scan;
 mod scan_async;
 mod skip_while;
 mod skip_while_async;
+mod skip_while_async_fn;
 mod take_while;
 mod take_while_async;
+mod take_while_async_fn;
+mod throttle;
+mod timeout;
+mod try_all;
+mod try_all_async;
+mod try_all_async_fn;
+mod try_any;
+mod try_any_async;
+mod try_any_async_fn;
+mod try_find;
+mod try_find_async;
+mod try_find_async_fn;
+mod try_find_map;
+mod try_find_map_async;
 mod try_flatten;
 mod try_fold;
 mod try_fold_async;
+mod try_fold_async_fn;
 mod try_for_each;
 mod try_for_each_async;
+mod try_map_while;
+mod try_map_while_async;
+mod try_position;
+mod try_position_async;
+mod try_position_async_fn;
+mod try_reduce;
+mod try_reduce_async;
+mod try_scan;
+mod try_scan_async;
+mod try_skip_while;
+mod try_skip_while_async;
+mod try_skip_while_async_fn;
+mod try_take_while;
+mod try_take_while_async;
+mod try_take_while_async_fn;
+mod unwrap_or_else;
+mod unwrap_or_else_async;
 mod zip;
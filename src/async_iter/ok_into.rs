@@ -1,5 +1,6 @@
 use crate::async_iter::map_ok::MapOk;
-use crate::support::{AsyncIterator, FusedAsyncIterator, Residual, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::fns::IntoFn;
@@ -30,6 +31,15 @@ where
     }
 }
 
+impl<I, T> Debug for OkInto<I, T>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OkInto").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, T> AsyncIterator for OkInto<I, T>
 where
     I: AsyncIterator,
@@ -48,6 +58,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, T> for OkInto<I, T>
+    where
+        I: AsyncIterator,
+        I::Item: Try,
+        <I::Item as Try>::Output: Into<T>,
+        <I::Item as Try>::Residual: Residual<T>,
+);
+
 impl<I, T> FusedAsyncIterator for OkInto<I, T>
 where
     I: FusedAsyncIterator,
@@ -60,6 +79,15 @@ where
     }
 }
 
+impl<I, T> ExactSizeAsyncIterator for OkInto<I, T>
+where
+    I: ExactSizeAsyncIterator,
+    I::Item: Try,
+    <I::Item as Try>::Output: Into<T>,
+    <I::Item as Try>::Residual: Residual<T>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
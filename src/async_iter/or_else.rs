@@ -1,6 +1,7 @@
 use crate::async_iter::map::Map;
 use crate::support::fns::OrElseFn;
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> OrElse<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub const fn new(iter: I, f: F) -> Self {
         Self {
             inner: Map::new(iter, OrElseFn::new(f)),
         }
@@ -35,6 +36,15 @@ where
     }
 }
 
+impl<I, F> Debug for OrElse<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OrElse").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> AsyncIterator for OrElse<I, F>
 where
     I: ResultAsyncIterator,
@@ -52,6 +62,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for OrElse<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)> + ?Sized,
+        F::Output: Try<Output = I::Ok>,
+);
+
 impl<I, F> FusedAsyncIterator for OrElse<I, F>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -63,6 +81,14 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for OrElse<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: Try<Output = I::Ok>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
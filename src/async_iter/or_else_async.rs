@@ -1,4 +1,5 @@
-use crate::support::{AsyncIterator, FusedAsyncIterator, ResultAsyncIterator, Try};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -28,7 +29,7 @@ where
     F: FnMut<(I::Error,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: None, f }
     }
 }
@@ -49,6 +50,21 @@ where
     }
 }
 
+impl<I, F> Debug for OrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + Debug,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OrElseAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for OrElseAsync<I, F>
 where
     I: ResultAsyncIterator,
@@ -94,6 +110,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for OrElseAsync<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)> + ?Sized,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: Try<Output = I::Ok>,
+);
+
 impl<I, F> FusedAsyncIterator for OrElseAsync<I, F>
 where
     I: ResultAsyncIterator + FusedAsyncIterator,
@@ -109,6 +134,15 @@ where
     }
 }
 
+impl<I, F> ExactSizeAsyncIterator for OrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = I::Ok>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,5 +1,6 @@
 use crate::async_iter::fold::Fold;
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -64,6 +65,16 @@ where
     }
 }
 
+impl<I, F> Debug for Reduce<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Reduce").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> Future for Reduce<I, F>
 where
     I: AsyncIterator,
@@ -1,99 +1,11 @@
+use crate::support::states::{ReduceState, ReduceStateProject};
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
 use futures_core::FusedFuture;
-use three_states::{StateAPinProject, StateBPinProject, StateCPinProject, ThreeStates, ThreeStatesPinProject};
-
-pin_project_lite::pin_project! {
-    #[derive(Clone)]
-    pub struct State<T, Fut> {
-        #[pin]
-        inner: ThreeStates<(), (), (), T, Fut, ()>,
-    }
-}
-
-impl<T, Fut> Default for State<T, Fut> {
-    fn default() -> Self {
-        Self {
-            inner: ThreeStates::A {
-                pinned: (),
-                unpinned: (),
-            },
-        }
-    }
-}
-
-impl<T, Fut> State<T, Fut> {
-    fn get_future(&self) -> Option<&Fut> {
-        match &self.inner {
-            ThreeStates::C { pinned, .. } => Some(pinned),
-            _ => None,
-        }
-    }
-
-    fn pin_project(self: Pin<&mut Self>) -> StateProject<T, Fut> {
-        match self.project().inner.pin_project() {
-            ThreeStatesPinProject::A(project) => StateProject::Empty(EmptyState { inner: project }),
-            ThreeStatesPinProject::B(project) => StateProject::Accumulate(AccumulateState { inner: project }),
-            ThreeStatesPinProject::C(project) => StateProject::Future(FutureState { inner: project }),
-        }
-    }
-}
-
-pub struct EmptyState<'a, T, Fut> {
-    inner: StateAPinProject<'a, (), (), (), T, Fut, ()>,
-}
-
-impl<'a, T, Fut> EmptyState<'a, T, Fut> {
-    fn set_accumulate(self, acc: T) -> AccumulateState<'a, T, Fut> {
-        AccumulateState {
-            inner: self.inner.replace_state_b((), acc).0,
-        }
-    }
-
-    fn set_future(self, fut: Fut) -> FutureState<'a, T, Fut> {
-        FutureState {
-            inner: self.inner.replace_state_c(fut, ()).0,
-        }
-    }
-}
-
-pub struct AccumulateState<'a, T, Fut> {
-    inner: StateBPinProject<'a, (), (), (), T, Fut, ()>,
-}
-
-impl<'a, T, Fut> AccumulateState<'a, T, Fut> {
-    fn set_empty(self) -> (EmptyState<'a, T, Fut>, T) {
-        let (inner, item) = self.inner.replace_state_a((), ());
-
-        (EmptyState { inner }, item)
-    }
-}
-
-pub struct FutureState<'a, T, Fut> {
-    inner: StateCPinProject<'a, (), (), (), T, Fut, ()>,
-}
-
-impl<'a, T, Fut> FutureState<'a, T, Fut> {
-    fn get_pin_mut(&mut self) -> Pin<&mut Fut> {
-        self.inner.get_project().pinned
-    }
-
-    fn set_accumulate(self, acc: T) -> AccumulateState<'a, T, Fut> {
-        AccumulateState {
-            inner: self.inner.replace_state_b((), acc).0,
-        }
-    }
-}
-
-#[allow(clippy::module_name_repetitions)]
-pub enum StateProject<'a, T, Fut> {
-    Empty(EmptyState<'a, T, Fut>),
-    Accumulate(AccumulateState<'a, T, Fut>),
-    Future(FutureState<'a, T, Fut>),
-}
 
 pin_project_lite::pin_project! {
     pub struct ReduceAsync<I, F>
@@ -106,7 +18,7 @@ pin_project_lite::pin_project! {
         #[pin]
         iter: I,
         #[pin]
-        state: State<<F::Output as IntoFuture>::Output, <F::Output as IntoFuture>::IntoFuture>,
+        state: ReduceState<<F::Output as IntoFuture>::Output, <F::Output as IntoFuture>::IntoFuture>,
         f: F,
     }
 }
@@ -120,7 +32,7 @@ where
     pub(crate) fn new(iter: I, f: F) -> Self {
         Self {
             iter,
-            state: State::default(),
+            state: ReduceState::default(),
             f,
         }
     }
@@ -143,6 +55,22 @@ where
     }
 }
 
+impl<I, F> Debug for ReduceAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: FnMut<(I::Item, I::Item)> + ?Sized,
+    F::Output: IntoFuture<Output = I::Item>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ReduceAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> Future for ReduceAsync<I, F>
 where
     I: AsyncIterator,
@@ -159,15 +87,15 @@ where
 
         loop {
             let mut fut = match state {
-                StateProject::Empty(empty_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                ReduceStateProject::Empty(empty_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
                     None => return Poll::Ready(None),
                     Some(item) => {
-                        state = StateProject::Accumulate(empty_state.set_accumulate(item));
+                        state = ReduceStateProject::Accumulate(empty_state.set_accumulate(item));
 
                         continue;
                     }
                 },
-                StateProject::Accumulate(acc_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                ReduceStateProject::Accumulate(acc_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
                     None => return Poll::Ready(Some(acc_state.set_empty().1)),
                     Some(item) => {
                         let (empty_state, acc) = acc_state.set_empty();
@@ -176,12 +104,12 @@ where
                         empty_state.set_future(fut)
                     }
                 },
-                StateProject::Future(fut_state) => fut_state,
+                ReduceStateProject::Future(fut_state) => fut_state,
             };
 
             let acc = task::ready!(fut.get_pin_mut().poll(cx));
 
-            state = StateProject::Accumulate(fut.set_accumulate(acc));
+            state = ReduceStateProject::Accumulate(fut.set_accumulate(acc));
         }
     }
 }
@@ -0,0 +1,266 @@
+use crate::support::{self, AsyncIterator, FusedAsyncIterator};
+use crate::time::Timer;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use three_states::{ThreeStates, ThreeStatesProject};
+
+pin_project_lite::pin_project! {
+    pub struct Sample<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+    {
+        #[pin]
+        iter: I,
+        state: ThreeStates<(), (), (), I::Item, (), ()>,
+        #[pin]
+        sleep: T::Sleep,
+        deadline: T::Instant,
+        timer: T,
+        period: Duration,
+    }
+}
+
+impl<I, T> Sample<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    pub(crate) fn new(iter: I, timer: T, period: Duration) -> Self {
+        let deadline = timer.now() + period;
+
+        Self {
+            iter,
+            state: ThreeStates::A {
+                pinned: (),
+                unpinned: (),
+            },
+            sleep: timer.sleep_until(deadline),
+            deadline,
+            timer,
+            period,
+        }
+    }
+}
+
+impl<I, T> Clone for Sample<I, T>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    T: Timer + Clone,
+    T::Sleep: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            sleep: self.sleep.clone(),
+            deadline: self.deadline,
+            timer: self.timer.clone(),
+            period: self.period,
+        }
+    }
+}
+
+impl<I, T> Debug for Sample<I, T>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    T: Timer,
+    T::Sleep: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Sample")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .field("sleep", &self.sleep)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T> AsyncIterator for Sample<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut sleep = this.sleep;
+        let mut remaining = support::DRAIN_LIMIT;
+
+        loop {
+            let is_pending = match this.state.project_mut() {
+                ThreeStatesProject::A(empty_state) => match iter.as_mut().poll_next(cx) {
+                    Poll::Ready(None) => {
+                        empty_state.replace_state_c((), ());
+
+                        return Poll::Ready(None);
+                    }
+                    Poll::Ready(Some(item)) => {
+                        empty_state.replace_state_b((), item);
+
+                        false
+                    }
+                    Poll::Pending => true,
+                },
+                ThreeStatesProject::B(mut holding_state) => match iter.as_mut().poll_next(cx) {
+                    Poll::Ready(None) => return Poll::Ready(Some(holding_state.replace_state_c((), ()).1)),
+                    Poll::Ready(Some(item)) => {
+                        holding_state.replace_state_b((), item);
+
+                        false
+                    }
+                    Poll::Pending => true,
+                },
+                ThreeStatesProject::C(_) => return Poll::Ready(None),
+            };
+
+            if sleep.as_mut().poll(cx).is_ready() {
+                *this.deadline = *this.deadline + *this.period;
+                sleep.set(this.timer.sleep_until(*this.deadline));
+
+                if let ThreeStatesProject::B(holding_state) = this.state.project_mut() {
+                    return Poll::Ready(Some(holding_state.replace_state_a((), ()).1));
+                }
+            } else if is_pending {
+                return Poll::Pending;
+            } else {
+                remaining -= 1;
+
+                if remaining == 0 {
+                    cx.waker().wake_by_ref();
+
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            ThreeStates::A { .. } => (0, self.iter.size_hint().1),
+            ThreeStates::B { .. } => (1, self.iter.size_hint().1.and_then(|high| high.checked_add(1))),
+            ThreeStates::C { .. } => (0, Some(0)),
+        }
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, T> for Sample<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+);
+
+impl<I, T> FusedAsyncIterator for Sample<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, ThreeStates::C { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::time::ManualClock;
+    use core::cell::Cell;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::{stream, task, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_sample() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_sample(&clock, Duration::from_secs(1));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5]);
+    }
+
+    #[test]
+    fn test_sample_period() {
+        let clock = ManualClock::new();
+        let source = Cell::new(Poll::Pending);
+        let mut iter =
+            pin!(stream::poll_fn(|_| source.replace(Poll::Pending)).slim_sample(&clock, Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        source.set(Poll::Ready(Some(2)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+        source.set(Poll::Ready(Some(3)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(3)));
+
+        // Ticks without a new item yield nothing.
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        source.set(Poll::Ready(Some(5)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(5)));
+
+        source.set(Poll::Ready(Some(7)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+        assert_eq!(iter.size_hint(), (1, None));
+
+        // The held item is flushed when the inner async iterator ends.
+        source.set(Poll::Ready(None));
+
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(7)));
+        assert!(iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn test_sample_clone() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_sample(&clock, Duration::from_secs(1));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [5]);
+    }
+
+    #[test]
+    fn test_sample_always_ready_source() {
+        let clock = ManualClock::new();
+        let mut iter = pin!(stream::repeat(2).slim_sample(&clock, Duration::from_secs(1)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(5));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+    }
+}
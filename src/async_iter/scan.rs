@@ -1,4 +1,5 @@
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, S, F> Scan<I, S, F> {
-    pub(crate) fn new(iter: I, state: S, f: F) -> Self {
+    pub(crate) const fn new(iter: I, state: S, f: F) -> Self {
         Self { iter, state, f }
     }
 }
@@ -36,6 +37,20 @@ where
     }
 }
 
+impl<I, S, F> Debug for Scan<I, S, F>
+where
+    I: Debug,
+    S: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Scan")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, S, F, T> AsyncIterator for Scan<I, S, F>
 where
     I: AsyncIterator,
@@ -54,6 +69,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, S, F, T> for Scan<I, S, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a mut S, I::Item), Output = Option<T>> + ?Sized,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -1,4 +1,5 @@
 use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
 use core::future::IntoFuture;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -40,7 +41,7 @@ where
     F: ScanFn<S, I::Item>,
     <F as ScanFn<S, I::Item>>::Output: IntoFuture,
 {
-    pub(crate) fn new(iter: I, state: S, f: F) -> Self {
+    pub(crate) const fn new(iter: I, state: S, f: F) -> Self {
         Self {
             iter,
             state,
@@ -68,6 +69,23 @@ where
     }
 }
 
+impl<I, S, F> Debug for ScanAsync<I, S, F>
+where
+    I: AsyncIterator + Debug,
+    S: Debug,
+    F: ScanFn<S, I::Item> + ?Sized,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ScanAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .field("fut", &self.fut)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, S, F, T> AsyncIterator for ScanAsync<I, S, F>
 where
     I: AsyncIterator,
@@ -109,6 +127,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, S, F, T> for ScanAsync<I, S, F>
+    where
+        I: AsyncIterator,
+        F: ScanFn<S, I::Item> + ?Sized,
+        <F as ScanFn<S, I::Item>>::Output: IntoFuture<Output = Option<T>>,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
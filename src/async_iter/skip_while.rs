@@ -1,4 +1,5 @@
 use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
@@ -13,7 +14,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, F> SkipWhile<I, F> {
-    pub(crate) fn new(iter: I, f: F) -> Self {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
         Self { iter, state: Some(f) }
     }
 }
@@ -31,6 +32,18 @@ where
     }
 }
 
+impl<I, F> Debug for SkipWhile<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SkipWhile")
+            .field("iter", &self.iter)
+            .field("skipping", &self.state.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for SkipWhile<I, F>
 where
     I: AsyncIterator,
@@ -41,7 +54,9 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
         let this = self.project();
         let mut iter = this.iter;
-        let OptionEntry::Some(mut f) = this.state.entry() else { return iter.poll_next(cx) };
+        let OptionEntry::Some(mut f) = this.state.entry() else {
+            return iter.poll_next(cx);
+        };
 
         loop {
             let item = task::ready!(iter.as_mut().poll_next(cx));
@@ -69,6 +84,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for SkipWhile<I, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a I::Item,), Output = bool>,
+);
+
 impl<I, F> FusedAsyncIterator for SkipWhile<I, F>
 where
     I: FusedAsyncIterator,
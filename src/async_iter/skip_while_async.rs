@@ -1,5 +1,6 @@
 use crate::support::states::{PredicateState, PredicateStateProject};
 use crate::support::{AsyncIterator, FusedAsyncIterator, PredicateFn};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -15,6 +16,18 @@ pin_project_lite::pin_project! {
     }
 }
 
+impl<F, T, Fut> Debug for State<F, T, Fut>
+where
+    T: Debug,
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("State")
+            .field("predicate_state", &self.predicate_state)
+            .finish_non_exhaustive()
+    }
+}
+
 type StateType<I, F> = State<
     F,
     <I as AsyncIterator>::Item,
@@ -68,6 +81,22 @@ where
     }
 }
 
+impl<I, F> Debug for SkipWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SkipWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
 impl<I, F> AsyncIterator for SkipWhileAsync<I, F>
 where
     I: AsyncIterator,
@@ -79,7 +108,9 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
         let this = self.project();
         let mut iter = this.iter;
-        let OptionPinnedEntry::Some(mut state) = this.state.pinned_entry() else { return iter.poll_next(cx) };
+        let OptionPinnedEntry::Some(mut state) = this.state.pinned_entry() else {
+            return iter.poll_next(cx);
+        };
         let state_projection = state.get_pin_mut().project();
         let mut predicate_state = state_projection.predicate_state.pin_project();
         let f = state_projection.f;
@@ -95,6 +126,7 @@ where
                     }
                 },
                 PredicateStateProject::Future(fut_state) => fut_state,
+                PredicateStateProject::Done => break Poll::Ready(None),
             };
 
             let skip = task::ready!(fut.get_pin_mut().poll(cx));
@@ -125,6 +157,14 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for SkipWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item>,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture<Output = bool>,
+);
+
 impl<I, F> FusedAsyncIterator for SkipWhileAsync<I, F>
 where
     I: FusedAsyncIterator,
@@ -0,0 +1,147 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncPredicateState;
+use crate::support::AsyncIterator;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    /// Like [`SkipWhileAsync`](super::SkipWhileAsync), but takes an async closure whose future may borrow from the
+    /// closure and the item.
+    pub struct SkipWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: Option<AsyncPredicateState<'a, I::Item, P, C>>,
+    }
+}
+
+impl<'a, I, P, C> SkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            state: Some(AsyncPredicateState::new(predicate, call)),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for SkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SkipWhileAsyncFn")
+            .field("iter", &self.iter)
+            .field("item", &self.state.as_ref().and_then(AsyncPredicateState::get_item))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> AsyncIterator for SkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let OptionPinnedEntry::Some(mut state) = this.state.pinned_entry() else {
+            return iter.poll_next(cx);
+        };
+
+        loop {
+            if let Some((skip, item)) = task::ready!(state.get_pin_mut().poll(cx)) {
+                if !skip {
+                    state.replace_none();
+
+                    return Poll::Ready(Some(item));
+                }
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(None),
+                Some(item) => state.get_pin_mut().start(item),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut candidate = self.iter.size_hint();
+
+        if let Some(state) = &self.state {
+            candidate.0 = 0;
+
+            if state.get_item().is_some() {
+                candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+            }
+        }
+
+        candidate
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<'a, I, P, C> for SkipWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        P: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+        C::Future: Future<Output = bool>,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::{stream, StreamExt};
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_skip_while_async_fn() {
+        let iter = stream::iter(0..10).slim_skip_while_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            x < 5
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [5, 6, 7, 8, 9]);
+    }
+
+    #[tokio::test]
+    async fn test_skip_while_async_fn_borrows_item() {
+        let iter = stream::iter([String::from("a"), String::from("bc"), String::from("d")]).slim_skip_while_async_fn(
+            async |item: &String| {
+                let item = item.as_str();
+
+                Yield::new(1).await;
+
+                item.len() == 1
+            },
+        );
+
+        assert_eq!(iter.collect::<Vec<_>>().await, ["bc", "d"]);
+    }
+}
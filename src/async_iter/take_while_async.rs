@@ -1,5 +1,6 @@
 use crate::support::states::{PredicateState, PredicateStateProject};
-use crate::support::{AsyncIterator, PredicateFn};
+use crate::support::{AsyncIterator, FusedAsyncIterator, PredicateFn};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -52,6 +53,22 @@ where
     }
 }
 
+impl<I, F> Debug for TakeWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: PredicateFn<I::Item> + ?Sized,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TakeWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, F> AsyncIterator for TakeWhileAsync<I, F>
 where
     I: AsyncIterator,
@@ -68,7 +85,11 @@ where
 
         let mut fut = match state {
             PredicateStateProject::Empty(empty_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
-                None => return Poll::Ready(None),
+                None => {
+                    empty_state.set_done();
+
+                    return Poll::Ready(None);
+                }
                 Some(item) => {
                     let fut = f.call_mut((&item,)).into_future();
 
@@ -76,19 +97,25 @@ where
                 }
             },
             PredicateStateProject::Future(fut_state) => fut_state,
+            PredicateStateProject::Done => return Poll::Ready(None),
         };
 
         let take = task::ready!(fut.get_pin_mut().poll(cx));
-        let item = fut.set_empty().1;
 
-        if take {
-            Poll::Ready(Some(item))
+        Poll::Ready(if take {
+            Some(fut.set_empty().1)
         } else {
-            Poll::Ready(None)
-        }
+            fut.set_done();
+
+            None
+        })
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.state.is_done() {
+            return (0, Some(0));
+        }
+
         let mut candidate = (0, self.iter.size_hint().1);
 
         if self.state.get_future().is_some() {
@@ -99,6 +126,25 @@ where
     }
 }
 
+impl<I, F> FusedAsyncIterator for TakeWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item> + ?Sized,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.state.is_done()
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for TakeWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item> + ?Sized,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture<Output = bool>,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
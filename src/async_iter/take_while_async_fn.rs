@@ -0,0 +1,166 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncPredicateState;
+use crate::support::{AsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    /// Like [`TakeWhileAsync`](super::TakeWhileAsync), but takes an async closure whose future may borrow from the
+    /// closure and the item.
+    pub struct TakeWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        iter: Option<I>,
+        #[pin]
+        state: AsyncPredicateState<'a, I::Item, P, C>,
+    }
+}
+
+impl<'a, I, P, C> TakeWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter: Some(iter),
+            state: AsyncPredicateState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for TakeWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TakeWhileAsyncFn")
+            .field("iter", &self.iter)
+            .field("item", &self.state.get_item())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> AsyncIterator for TakeWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+        let mut state = this.state;
+
+        if state.get_item().is_none() {
+            match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                None => {
+                    iter.replace_none();
+
+                    return Poll::Ready(None);
+                }
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+
+        if let Some((true, item)) = task::ready!(state.poll(cx)) {
+            return Poll::Ready(Some(item));
+        }
+
+        iter.replace_none();
+
+        Poll::Ready(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = (0, iter.size_hint().1);
+
+            if self.state.get_item().is_some() {
+                candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<'a, I, P, C> for TakeWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        P: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+        C::Future: Future<Output = bool>,
+);
+
+impl<'a, I, P, C> FusedAsyncIterator for TakeWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    C::Future: Future<Output = bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::{stream, StreamExt};
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_take_while_async_fn() {
+        let iter = stream::iter(0..10).slim_take_while_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            x < 5
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_take_while_async_fn_borrows_item() {
+        let iter = stream::iter([
+            String::from("a"),
+            String::from("b"),
+            String::from("cd"),
+            String::from("e"),
+        ])
+        .slim_take_while_async_fn(async |item: &String| {
+            let item = item.as_str();
+
+            Yield::new(1).await;
+
+            item.len() == 1
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, ["a", "b"]);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::time::Timer;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use core::time::Duration;
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    pub struct Throttle<I, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: ThreeStates<(), (), T::Sleep, (), (), ()>,
+        timer: T,
+        period: Duration,
+    }
+}
+
+impl<I, T> Throttle<I, T>
+where
+    T: Timer,
+{
+    pub(crate) const fn new(iter: I, timer: T, period: Duration) -> Self {
+        Self {
+            iter,
+            state: ThreeStates::A {
+                pinned: (),
+                unpinned: (),
+            },
+            timer,
+            period,
+        }
+    }
+}
+
+impl<I, T> Clone for Throttle<I, T>
+where
+    I: Clone,
+    T: Timer + Clone,
+    T::Sleep: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            timer: self.timer.clone(),
+            period: self.period,
+        }
+    }
+}
+
+impl<I, T> Debug for Throttle<I, T>
+where
+    I: Debug,
+    T: Timer,
+    T::Sleep: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Throttle")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T> AsyncIterator for Throttle<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let ready_state = match this.state.pin_project() {
+            ThreeStatesPinProject::A(ready_state) => ready_state,
+            ThreeStatesPinProject::B(mut sleeping_state) => {
+                task::ready!(sleeping_state.get_project().pinned.poll(cx));
+
+                sleeping_state.replace_state_a((), ()).0
+            }
+            ThreeStatesPinProject::C(_) => return Poll::Ready(None),
+        };
+
+        let item = task::ready!(this.iter.poll_next(cx));
+
+        if item.is_some() {
+            ready_state.replace_state_b(this.timer.sleep(*this.period), ());
+        } else {
+            ready_state.replace_state_c((), ());
+        }
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if matches!(self.state, ThreeStates::C { .. }) {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, T> for Throttle<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+);
+
+impl<I, T> FusedAsyncIterator for Throttle<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, ThreeStates::C { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::time::ManualClock;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::{stream, task};
+
+    #[test]
+    fn test_throttle() {
+        let clock = ManualClock::new();
+        let mut iter = pin!(stream::iter([2, 3, 5]).slim_throttle(&clock, Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(3)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(5)));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(2));
+
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+        assert!(iter.is_terminated());
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[test]
+    fn test_throttle_clone() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3]).slim_throttle(&clock, Duration::from_secs(2));
+        let mut iter_2 = pin!(iter.clone());
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter_2.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+}
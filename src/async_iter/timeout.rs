@@ -0,0 +1,196 @@
+use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::time::{TimedOut, Timer};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use core::time::Duration;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct Timeout<I, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        sleep: Option<T::Sleep>,
+        timer: T,
+        duration: Duration,
+    }
+}
+
+impl<I, T> Timeout<I, T>
+where
+    T: Timer,
+{
+    pub(crate) const fn new(iter: I, timer: T, duration: Duration) -> Self {
+        Self {
+            iter,
+            sleep: None,
+            timer,
+            duration,
+        }
+    }
+}
+
+impl<I, T> Clone for Timeout<I, T>
+where
+    I: Clone,
+    T: Timer + Clone,
+    T::Sleep: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            sleep: self.sleep.clone(),
+            timer: self.timer.clone(),
+            duration: self.duration,
+        }
+    }
+}
+
+impl<I, T> Debug for Timeout<I, T>
+where
+    I: Debug,
+    T: Timer,
+    T::Sleep: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("iter", &self.iter)
+            .field("sleep", &self.sleep)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, T> AsyncIterator for Timeout<I, T>
+where
+    I: AsyncIterator,
+    T: Timer,
+{
+    type Item = Result<I::Item, TimedOut>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut sleep_state = this.sleep;
+
+        if let Poll::Ready(item) = this.iter.poll_next(cx) {
+            sleep_state.set(None);
+
+            return Poll::Ready(item.map(Ok));
+        }
+
+        let mut sleep = match sleep_state.pinned_entry() {
+            OptionPinnedEntry::None(none_state) => none_state.replace_some(this.timer.sleep(*this.duration)),
+            OptionPinnedEntry::Some(some_state) => some_state,
+        };
+
+        task::ready!(sleep.get_pin_mut().poll(cx));
+
+        sleep.replace_none();
+
+        Poll::Ready(Some(Err(TimedOut)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0, None)
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, T> for Timeout<I, T>
+    where
+        I: AsyncIterator,
+        T: Timer,
+);
+
+impl<I, T> FusedAsyncIterator for Timeout<I, T>
+where
+    I: FusedAsyncIterator,
+    T: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::time::{ManualClock, TimedOut};
+    use core::cell::Cell;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::{FusedStream, Stream};
+    use futures_util::{stream, task, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_timeout(&clock, Duration::from_secs(1));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(2), Ok(3), Ok(5)]);
+    }
+
+    #[test]
+    fn test_timeout_per_item() {
+        let clock = ManualClock::new();
+        let source = Cell::new(Poll::Pending);
+        let mut iter =
+            pin!(stream::poll_fn(|_| source.replace(Poll::Pending)).slim_timeout(&clock, Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+        source.set(Poll::Ready(Some(2)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(Ok(2))));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        // The deadline restarts for each item.
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(Err(TimedOut))));
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+
+        source.set(Poll::Ready(Some(3)));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(Ok(3))));
+
+        source.set(Poll::Ready(None));
+
+        assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_clone() {
+        let clock = ManualClock::new();
+        let iter = stream::iter([2, 3, 5]).slim_timeout(&clock, Duration::from_secs(1));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(2), Ok(3), Ok(5)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(2), Ok(3), Ok(5)]);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_fused_async_iter() {
+        let clock = ManualClock::new();
+        let mut iter = stream::iter([2]).fuse().slim_timeout(&clock, Duration::from_secs(1));
+
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.next().await, Some(Ok(2)));
+        assert!(!iter.is_terminated());
+        assert_eq!(iter.next().await, None);
+        assert!(iter.is_terminated());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::async_iter::try_fold_async::TryFoldAsync;
+use crate::future::Map;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+
+#[derive(Clone)]
+struct ContinueIfTrue;
+
+impl<R> FnMut<(R,)> for ContinueIfTrue
+where
+    R: Try<Output = bool>,
+    R::Residual: Residual<bool>,
+{
+    type Output = RawResidual<<R::Residual as Residual<bool>>::TryType, ()>;
+
+    #[inline]
+    fn call_mut(&mut self, args: (R,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(true) => Self::Output::from_output(()),
+            ControlFlow::Continue(false) => Self::Output::from_residual(Try::from_output(false)),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TryAllAsyncFoldFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<((), T)> for TryAllAsyncFoldFn<P>
+where
+    P: FnMut<(T,)> + ?Sized,
+    P::Output: IntoFuture,
+{
+    type Output = Map<<P::Output as IntoFuture>::IntoFuture, ContinueIfTrue>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        Map::new(self.predicate.call_mut((args.1,)).into_future(), ContinueIfTrue)
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryAllAsync<I, P>
+    where
+        I: AsyncIterator,
+        P: FnMut<(I::Item,)>,
+        P: ?Sized,
+        P::Output: IntoFuture,
+        <P::Output as IntoFuture>::Output: Try<Output = bool>,
+        <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    {
+        #[pin]
+        inner: TryFoldAsync<I, (), CopyFn, TryAllAsyncFoldFn<P>>,
+    }
+}
+
+impl<I, P> TryAllAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFoldAsync::new(iter, (), CopyFn::default(), TryAllAsyncFoldFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryAllAsync<I, P>
+where
+    I: AsyncIterator + Clone,
+    P: FnMut<(I::Item,)> + Clone,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    <P::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryAllAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    <P::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryAllAsync").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryAllAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    type Output = <<<P::Output as IntoFuture>::Output as Try>::Residual as Residual<bool>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(true),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+impl<I, P> FusedFuture for TryAllAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::stream;
+
+    fn less_than_10(x: u32) -> Ready<Result<bool, u32>> {
+        future::ready(if x < 7 { Ok(x < 10) } else { Err(x) })
+    }
+
+    fn equals_2(x: u32) -> Ready<Result<bool, u32>> {
+        future::ready(if x < 7 { Ok(x == 2) } else { Err(x) })
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async() {
+        let future = stream::iter([2, 3, 5]).slim_try_all_async(less_than_10);
+
+        assert_eq!(future.await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_fail() {
+        let future = stream::iter([2, 3, 7]).slim_try_all_async(equals_2);
+
+        assert_eq!(future.await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_error() {
+        let future = stream::iter([2, 3, 7, 11]).slim_try_all_async(less_than_10);
+
+        assert_eq!(future.await, Err(7));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_all_async(|x: u32| future::ready((x != 3).then_some(x < 10)));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_all_async(less_than_10);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(true));
+        assert_eq!(future_2.await, Ok(true));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::{AsyncIterator, FromResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`TryAllAsync`](super::TryAllAsync), but takes an async closure whose future may borrow from the closure itself.
+    pub struct TryAllAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, P, I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: AsyncFnState<'a, P, I::Item, C>,
+    }
+}
+
+impl<'a, I, P, C> TryAllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            state: AsyncFnState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for TryAllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryAllAsyncFn")
+            .field("iter", &self.iter)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> Future for TryAllAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+    <C::Future as Future>::Output: Try<Output = bool>,
+    <<C::Future as Future>::Output as Try>::Residual: Residual<bool>,
+{
+    type Output = <<<C::Future as Future>::Output as Try>::Residual as Residual<bool>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if let Some(result) = task::ready!(state.as_mut().poll(cx)) {
+                match result.branch() {
+                    ControlFlow::Continue(false) => return Poll::Ready(Self::Output::from_output(false)),
+                    ControlFlow::Continue(true) => {}
+                    ControlFlow::Break(residual) => return Poll::Ready(Self::Output::from_residual(residual)),
+                }
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(Self::Output::from_output(true)),
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_try_all_async_fn() {
+        let future = stream::iter([2, 3, 4]).slim_try_all_async_fn(async |x: u32| {
+            Yield::new(1).await;
+
+            if x < 5 {
+                Ok(x < 10)
+            } else {
+                Err(x)
+            }
+        });
+
+        assert_eq!(future.await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_fn_fail() {
+        let future =
+            stream::iter([2, 3, 5]).slim_try_all_async_fn(async |x: u32| if x < 5 { Ok(x == 2) } else { Err(x) });
+
+        assert_eq!(future.await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_fn_error() {
+        let future =
+            stream::iter([2, 5, 3]).slim_try_all_async_fn(async |x: u32| if x < 5 { Ok(x < 10) } else { Err(x) });
+
+        assert_eq!(future.await, Err(5_u32));
+    }
+
+    #[tokio::test]
+    async fn test_try_all_async_fn_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_all_async_fn(async |x: u32| (x != 3).then_some(true));
+
+        assert_eq!(future.await, None);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::async_iter::try_fold::TryFold;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct TryAnyFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<((), T)> for TryAnyFn<P>
+where
+    P: FnMut<(T,)> + ?Sized,
+    P::Output: Try<Output = bool>,
+    <P::Output as Try>::Residual: Residual<bool>,
+{
+    type Output = RawResidual<<<P::Output as Try>::Residual as Residual<bool>>::TryType, ()>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        match self.predicate.call_mut((args.1,)).branch() {
+            ControlFlow::Continue(true) => Self::Output::from_residual(Try::from_output(true)),
+            ControlFlow::Continue(false) => Self::Output::from_output(()),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryAny<I, P>
+    where
+        P: ?Sized
+    {
+        #[pin]
+        inner: TryFold<I, (), CopyFn, TryAnyFn<P>>,
+    }
+}
+
+impl<I, P> TryAny<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFold::new(iter, (), CopyFn::default(), TryAnyFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryAny<I, P>
+where
+    I: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryAny<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryAny").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryAny<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: Try<Output = bool>,
+    <P::Output as Try>::Residual: Residual<bool>,
+{
+    type Output = <<P::Output as Try>::Residual as Residual<bool>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(false),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::stream;
+
+    fn greater_than_2(x: u32) -> Result<bool, u32> {
+        if x < 5 {
+            Ok(x > 2)
+        } else {
+            Err(x)
+        }
+    }
+
+    fn equals_10(x: u32) -> Result<bool, u32> {
+        if x < 5 {
+            Ok(x == 10)
+        } else {
+            Err(x)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_any() {
+        let future = stream::iter([2, 3, 5]).slim_try_any(greater_than_2);
+
+        assert_eq!(future.await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_fail() {
+        let future = stream::iter([2, 3, 4]).slim_try_any(equals_10);
+
+        assert_eq!(future.await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_error() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_any(equals_10);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_any(|x: u32| (x != 3).then_some(x == 5));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_any_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_any(greater_than_2);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(true));
+        assert_eq!(future_2.await, Ok(true));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::async_iter::try_fold_async::TryFoldAsync;
+use crate::future::Map;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+
+#[derive(Clone)]
+struct BreakIfTrue;
+
+impl<R> FnMut<(R,)> for BreakIfTrue
+where
+    R: Try<Output = bool>,
+    R::Residual: Residual<bool>,
+{
+    type Output = RawResidual<<R::Residual as Residual<bool>>::TryType, ()>;
+
+    #[inline]
+    fn call_mut(&mut self, args: (R,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(true) => Self::Output::from_residual(Try::from_output(true)),
+            ControlFlow::Continue(false) => Self::Output::from_output(()),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TryAnyAsyncFoldFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<((), T)> for TryAnyAsyncFoldFn<P>
+where
+    P: FnMut<(T,)> + ?Sized,
+    P::Output: IntoFuture,
+{
+    type Output = Map<<P::Output as IntoFuture>::IntoFuture, BreakIfTrue>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        Map::new(self.predicate.call_mut((args.1,)).into_future(), BreakIfTrue)
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryAnyAsync<I, P>
+    where
+        I: AsyncIterator,
+        P: FnMut<(I::Item,)>,
+        P: ?Sized,
+        P::Output: IntoFuture,
+        <P::Output as IntoFuture>::Output: Try<Output = bool>,
+        <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    {
+        #[pin]
+        inner: TryFoldAsync<I, (), CopyFn, TryAnyAsyncFoldFn<P>>,
+    }
+}
+
+impl<I, P> TryAnyAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFoldAsync::new(iter, (), CopyFn::default(), TryAnyAsyncFoldFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryAnyAsync<I, P>
+where
+    I: AsyncIterator + Clone,
+    P: FnMut<(I::Item,)> + Clone,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    <P::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryAnyAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+    <P::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryAnyAsync").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryAnyAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    type Output = <<<P::Output as IntoFuture>::Output as Try>::Residual as Residual<bool>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(false),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+impl<I, P> FusedFuture for TryAnyAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<bool>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::stream;
+
+    fn greater_than_2(x: u32) -> Ready<Result<bool, u32>> {
+        future::ready(if x < 5 { Ok(x > 2) } else { Err(x) })
+    }
+
+    fn equals_10(x: u32) -> Ready<Result<bool, u32>> {
+        future::ready(if x < 5 { Ok(x == 10) } else { Err(x) })
+    }
+
+    #[tokio::test]
+    async fn test_try_any_async() {
+        let future = stream::iter([2, 3, 5]).slim_try_any_async(greater_than_2);
+
+        assert_eq!(future.await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_async_fail() {
+        let future = stream::iter([2, 3, 4]).slim_try_any_async(equals_10);
+
+        assert_eq!(future.await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_async_error() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_any_async(equals_10);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_any_async_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_any_async(|x: u32| future::ready((x != 3).then_some(x == 5)));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_any_async_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_any_async(greater_than_2);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(true));
+        assert_eq!(future_2.await, Ok(true));
+    }
+}
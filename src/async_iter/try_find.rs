@@ -0,0 +1,135 @@
+use crate::async_iter::try_fold::TryFold;
+use crate::support::{AsyncIterator, FromResidual, PredicateFn, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct TryFindFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<((), T)> for TryFindFn<P>
+where
+    P: PredicateFn<T> + ?Sized,
+    <P as PredicateFn<T>>::Output: Try<Output = bool>,
+    <<P as PredicateFn<T>>::Output as Try>::Residual: Residual<Option<T>>,
+{
+    type Output = RawResidual<<<<P as PredicateFn<T>>::Output as Try>::Residual as Residual<Option<T>>>::TryType, ()>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        let item = args.1;
+
+        match self.predicate.call_mut((&item,)).branch() {
+            ControlFlow::Continue(true) => Self::Output::from_residual(Try::from_output(Some(item))),
+            ControlFlow::Continue(false) => Self::Output::from_output(()),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryFind<I, P>
+    where
+        P: ?Sized
+    {
+        #[pin]
+        inner: TryFold<I, (), CopyFn, TryFindFn<P>>,
+    }
+}
+
+impl<I, P> TryFind<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFold::new(iter, (), CopyFn::default(), TryFindFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryFind<I, P>
+where
+    I: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryFind<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFind").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryFind<I, P>
+where
+    I: AsyncIterator,
+    P: PredicateFn<I::Item> + ?Sized,
+    <P as PredicateFn<I::Item>>::Output: Try<Output = bool>,
+    <<P as PredicateFn<I::Item>>::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    type Output = <<<P as PredicateFn<I::Item>>::Output as Try>::Residual as Residual<Option<I::Item>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(None),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_try_find() {
+        let future = stream::iter([2, 3, 5]).slim_try_find(|&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+
+        assert_eq!(future.await, Ok(Some(3)));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_fail() {
+        let future = stream::iter([1, 2, 2]).slim_try_find(|&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_error() {
+        let future = stream::iter([2, 5, 3]).slim_try_find(|&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_find(|&x: &u32| (x != 2).then_some(x > 2));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_find_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_find(|&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(3)));
+        assert_eq!(future_2.await, Ok(Some(3)));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::support::states::{PredicateState, PredicateStateProject};
+use crate::support::{AsyncIterator, FromResidual, PredicateFn, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    pub struct TryFindAsync<I, P>
+    where
+        I: AsyncIterator,
+        P: PredicateFn<I::Item>,
+        P: ?Sized,
+        <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: PredicateState<I::Item, <<P as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture>,
+        predicate: P,
+    }
+}
+
+impl<I, P> TryFindAsync<I, P>
+where
+    I: AsyncIterator,
+    P: PredicateFn<I::Item>,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+{
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter,
+            state: PredicateState::default(),
+            predicate,
+        }
+    }
+}
+
+impl<I, P> Clone for TryFindAsync<I, P>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    P: PredicateFn<I::Item> + Clone,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryFindAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    P: PredicateFn<I::Item> + ?Sized,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFindAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, P> Future for TryFindAsync<I, P>
+where
+    I: AsyncIterator,
+    P: PredicateFn<I::Item> + ?Sized,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<P as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    type Output = <<<<P as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual as Residual<
+        Option<I::Item>,
+    >>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state.pin_project();
+        let predicate = this.predicate;
+
+        loop {
+            let mut fut = match state {
+                PredicateStateProject::Empty(empty_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                    None => {
+                        empty_state.set_done();
+
+                        break Poll::Ready(Self::Output::from_output(None));
+                    }
+                    Some(item) => {
+                        let fut = predicate.call_mut((&item,)).into_future();
+
+                        empty_state.set_future(item, fut)
+                    }
+                },
+                PredicateStateProject::Future(fut_state) => fut_state,
+                PredicateStateProject::Done => break Poll::Pending,
+            };
+
+            let result = task::ready!(fut.get_pin_mut().poll(cx));
+
+            match result.branch() {
+                ControlFlow::Continue(true) => break Poll::Ready(Self::Output::from_output(Some(fut.set_done()))),
+                ControlFlow::Continue(false) => state = PredicateStateProject::Empty(fut.set_empty().0),
+                ControlFlow::Break(residual) => {
+                    fut.set_done();
+
+                    break Poll::Ready(Self::Output::from_residual(residual));
+                }
+            }
+        }
+    }
+}
+
+impl<I, P> FusedFuture for TryFindAsync<I, P>
+where
+    I: AsyncIterator,
+    P: PredicateFn<I::Item> + ?Sized,
+    <P as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<P as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<P as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.state.is_done()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_try_find_async() {
+        let future = stream::iter([2, 3, 5])
+            .slim_try_find_async(|&x: &u32| future::ready(if x < 5 { Ok(x > 2) } else { Err(x) }));
+
+        assert_eq!(future.await, Ok(Some(3)));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_fail() {
+        let future = stream::iter([1, 2, 2])
+            .slim_try_find_async(|&x: &u32| future::ready(if x < 5 { Ok(x > 2) } else { Err(x) }));
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_error() {
+        let future = stream::iter([2, 5, 3])
+            .slim_try_find_async(|&x: &u32| future::ready(if x < 5 { Ok(x > 2) } else { Err(x) }));
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_async(|&x: &u32| future::ready((x != 2).then_some(x > 2)));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_clone() {
+        let future = stream::iter([2, 3, 5])
+            .slim_try_find_async(|&x: &u32| future::ready(if x < 5 { Ok(x > 2) } else { Err(x) }));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(3)));
+        assert_eq!(future_2.await, Ok(Some(3)));
+    }
+}
@@ -0,0 +1,142 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncPredicateState;
+use crate::support::{AsyncIterator, FromResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`TryFindAsync`](super::TryFindAsync), but takes an async closure whose future may borrow from the closure
+    /// and the item.
+    pub struct TryFindAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: AsyncPredicateState<'a, I::Item, P, C>,
+    }
+}
+
+impl<'a, I, P, C> TryFindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            state: AsyncPredicateState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for TryFindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFindAsyncFn")
+            .field("iter", &self.iter)
+            .field("item", &self.state.get_item())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> Future for TryFindAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    <C::Future as Future>::Output: Try<Output = bool>,
+    <<C::Future as Future>::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    type Output = <<<C::Future as Future>::Output as Try>::Residual as Residual<Option<I::Item>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if let Some((result, item)) = task::ready!(state.as_mut().poll(cx)) {
+                match result.branch() {
+                    ControlFlow::Continue(true) => return Poll::Ready(Self::Output::from_output(Some(item))),
+                    ControlFlow::Continue(false) => {}
+                    ControlFlow::Break(residual) => return Poll::Ready(Self::Output::from_residual(residual)),
+                }
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(Self::Output::from_output(None)),
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_try_find_async_fn() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            if x < 5 {
+                Ok(x > 2)
+            } else {
+                Err(x)
+            }
+        });
+
+        assert_eq!(future.await, Ok(Some(3_u32)));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_fn_fail() {
+        let future =
+            stream::iter([1, 2, 2]).slim_try_find_async_fn(async |&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_fn_error() {
+        let future =
+            stream::iter([2, 5, 3]).slim_try_find_async_fn(async |&x: &u32| if x < 5 { Ok(x > 2) } else { Err(x) });
+
+        assert_eq!(future.await, Err(5_u32));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_async_fn_borrows_item() {
+        let future =
+            stream::iter([String::from("a"), String::from("bc")]).slim_try_find_async_fn(async |item: &String| {
+                let item = item.as_str();
+
+                Yield::new(1).await;
+
+                Some(item.len() == 2)
+            });
+
+        let item: Option<Option<String>> = future.await;
+
+        assert_eq!(item.flatten().as_deref(), Some("bc"));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::async_iter::try_fold::TryFold;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct TryFindMapFn<F>
+where
+    F: ?Sized,
+{
+    f: F,
+}
+
+impl<T, F, U> FnMut<((), T)> for TryFindMapFn<F>
+where
+    F: FnMut<(T,)> + ?Sized,
+    F::Output: Try<Output = Option<U>>,
+    <F::Output as Try>::Residual: Residual<Option<U>>,
+{
+    type Output = RawResidual<<<F::Output as Try>::Residual as Residual<Option<U>>>::TryType, ()>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        match self.f.call_mut((args.1,)).branch() {
+            ControlFlow::Continue(None) => Self::Output::from_output(()),
+            ControlFlow::Continue(Some(item)) => Self::Output::from_residual(Try::from_output(Some(item))),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryFindMap<I, F>
+    where
+        F: ?Sized,
+    {
+        #[pin]
+        inner: TryFold<I, (), CopyFn, TryFindMapFn<F>>,
+    }
+}
+
+impl<I, F> TryFindMap<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            inner: TryFold::new(iter, (), CopyFn::default(), TryFindMapFn { f }),
+        }
+    }
+}
+
+impl<I, F> Clone for TryFindMap<I, F>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryFindMap<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFindMap").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, F, T> Future for TryFindMap<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: Try<Output = Option<T>>,
+    <F::Output as Try>::Residual: Residual<Option<T>>,
+{
+    type Output = <<F::Output as Try>::Residual as Residual<Option<T>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(None),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::stream;
+
+    fn find_3_then_mul_10(x: u32) -> Result<Option<u64>, u32> {
+        if x < 5 {
+            Ok((x == 3).then_some(u64::from(x) * 10))
+        } else {
+            Err(x)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_map(find_3_then_mul_10);
+
+        assert_eq!(future.await, Ok(Some(30)));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_fail() {
+        let future = stream::iter([2, 4, 1]).slim_try_find_map(find_3_then_mul_10);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_error() {
+        let future = stream::iter([2, 5, 3]).slim_try_find_map(find_3_then_mul_10);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_map(find_3_then_mul_10);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(30)));
+        assert_eq!(future_2.await, Ok(Some(30)));
+    }
+}
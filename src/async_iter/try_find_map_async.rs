@@ -0,0 +1,187 @@
+use crate::async_iter::try_fold_async::TryFoldAsync;
+use crate::future::Map;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+
+#[derive(Clone)]
+struct BreakIfSome;
+
+impl<R, T> FnMut<(R,)> for BreakIfSome
+where
+    R: Try<Output = Option<T>>,
+    R::Residual: Residual<Option<T>>,
+{
+    type Output = RawResidual<<R::Residual as Residual<Option<T>>>::TryType, ()>;
+
+    #[inline]
+    fn call_mut(&mut self, args: (R,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(None) => Self::Output::from_output(()),
+            ControlFlow::Continue(Some(item)) => Self::Output::from_residual(Try::from_output(Some(item))),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TryFindMapAsyncFn<F>
+where
+    F: ?Sized,
+{
+    f: F,
+}
+
+impl<T, F> FnMut<((), T)> for TryFindMapAsyncFn<F>
+where
+    F: FnMut<(T,)> + ?Sized,
+    F::Output: IntoFuture,
+{
+    type Output = Map<<F::Output as IntoFuture>::IntoFuture, BreakIfSome>;
+
+    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
+        Map::new(self.f.call_mut((args.1,)).into_future(), BreakIfSome)
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryFindMapAsync<I, F, T>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)>,
+        F: ?Sized,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+        <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+    {
+        #[pin]
+        inner: TryFoldAsync<I, (), CopyFn, TryFindMapAsyncFn<F>>,
+    }
+}
+
+impl<I, F, T> TryFindMapAsync<I, F, T>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            inner: TryFoldAsync::new(iter, (), CopyFn::default(), TryFindMapAsyncFn { f }),
+        }
+    }
+}
+
+impl<I, F, T> Clone for TryFindMapAsync<I, F, T>
+where
+    I: AsyncIterator + Clone,
+    F: FnMut<(I::Item,)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, F, T> Debug for TryFindMapAsync<I, F, T>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFindMapAsync").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, F, T> Future for TryFindMapAsync<I, F, T>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+{
+    type Output = <<<F::Output as IntoFuture>::Output as Try>::Residual as Residual<Option<T>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(()) => Self::Output::from_output(None),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+impl<I, F, T> FusedFuture for TryFindMapAsync<I, F, T>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<T>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::stream;
+
+    fn find_3_then_mul_10(x: u32) -> Ready<Result<Option<u64>, u32>> {
+        future::ready(if x < 5 {
+            Ok((x == 3).then_some(u64::from(x) * 10))
+        } else {
+            Err(x)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_async() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_map_async(find_3_then_mul_10);
+
+        assert_eq!(future.await, Ok(Some(30)));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_async_fail() {
+        let future = stream::iter([2, 4, 1]).slim_try_find_map_async(find_3_then_mul_10);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_async_error() {
+        let future = stream::iter([2, 5, 3]).slim_try_find_map_async(find_3_then_mul_10);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_find_map_async_clone() {
+        let future = stream::iter([2, 3, 5]).slim_try_find_map_async(find_3_then_mul_10);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(30)));
+        assert_eq!(future_2.await, Ok(Some(30)));
+    }
+}
@@ -1,28 +1,10 @@
 use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, IntoAsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::ops::ControlFlow;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
-use fn_traits::FnMut;
 use option_entry::{OptionEntryExt, OptionPinnedEntry};
 
-#[derive(Clone)]
-struct TryFlattenFn<F> {
-    f: F,
-}
-
-impl<T, F, U> FnMut<((), T)> for TryFlattenFn<F>
-where
-    F: FnMut<(T,), Output = Option<U>>,
-{
-    type Output = ControlFlow<U>;
-
-    fn call_mut(&mut self, args: ((), T)) -> Self::Output {
-        self.f
-            .call_mut((args.1,))
-            .map_or(ControlFlow::Continue(()), ControlFlow::Break)
-    }
-}
-
 pin_project_lite::pin_project! {
     pub struct TryFlatten<I>
     where
@@ -44,7 +26,7 @@ where
     I::Item: Try,
     <I::Item as Try>::Output: IntoAsyncIterator,
 {
-    pub(crate) fn new(iter: I) -> Self {
+    pub(crate) const fn new(iter: I) -> Self {
         Self { state: None, iter }
     }
 }
@@ -64,6 +46,21 @@ where
     }
 }
 
+impl<I> Debug for TryFlatten<I>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Try,
+    <I::Item as Try>::Output: IntoAsyncIterator,
+    <<I::Item as Try>::Output as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFlatten")
+            .field("state", &self.state)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I> AsyncIterator for TryFlatten<I>
 where
     I: AsyncIterator + ?Sized,
@@ -118,6 +115,15 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<I> for TryFlatten<I>
+    where
+        I: AsyncIterator + ?Sized,
+        I::Item: Try,
+        <I::Item as Try>::Output: IntoAsyncIterator,
+        <<I::Item as Try>::Output as IntoAsyncIterator>::Item: FromResidual<<I::Item as Try>::Residual>,
+);
+
 impl<I> FusedAsyncIterator for TryFlatten<I>
 where
     I: FusedAsyncIterator + ?Sized,
@@ -1,4 +1,5 @@
 use crate::support::{AsyncIterator, FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -19,7 +20,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<I, T, G, F> TryFold<I, T, G, F> {
-    pub(crate) fn new(iter: I, acc: T, getter: G, f: F) -> Self {
+    pub(crate) const fn new(iter: I, acc: T, getter: G, f: F) -> Self {
         Self { iter, acc, getter, f }
     }
 }
@@ -41,6 +42,20 @@ where
     }
 }
 
+impl<I, T, G, F> Debug for TryFold<I, T, G, F>
+where
+    I: Debug,
+    T: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFold")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, T, G, F> Future for TryFold<I, T, G, F>
 where
     I: AsyncIterator,
@@ -75,7 +90,7 @@ mod tests {
     use futures_util::stream;
     use std::convert::Infallible;
 
-    #[allow(clippy::unnecessary_wraps)]
+    #[allow(clippy::unnecessary_wraps, reason = "Test function needs to return a `Try` type.")]
     fn accumulate_1(state: u64, item: u32) -> Result<u64, Infallible> {
         Ok(state * u64::from(item))
     }
@@ -1,10 +1,12 @@
 use crate::support::states::{FoldState, FoldStateProject};
 use crate::support::{AsyncIterator, FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use fn_traits::FnMut;
+use futures_core::FusedFuture;
 
 pin_project_lite::pin_project! {
     pub struct TryFoldAsync<I, T, G, F>
@@ -31,7 +33,7 @@ where
     F::Output: IntoFuture,
     <F::Output as IntoFuture>::Output: Try<Output = T>,
 {
-    pub(crate) fn new(iter: I, acc: T, getter: G, f: F) -> Self {
+    pub(crate) const fn new(iter: I, acc: T, getter: G, f: F) -> Self {
         Self {
             iter,
             getter,
@@ -61,6 +63,23 @@ where
     }
 }
 
+impl<I, T, G, F> Debug for TryFoldAsync<I, T, G, F>
+where
+    I: AsyncIterator + Debug,
+    T: Debug,
+    F: FnMut<(T, I::Item)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = T>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFoldAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I, T, G, F> Future for TryFoldAsync<I, T, G, F>
 where
     I: AsyncIterator,
@@ -81,7 +100,7 @@ where
         Poll::Ready(loop {
             let mut fut = match state {
                 FoldStateProject::Accumulate(mut acc_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
-                    None => break Self::Output::from_output(getter.call_mut((acc_state.get_mut(),))),
+                    None => break Self::Output::from_output(acc_state.set_done()),
                     Some(item) => {
                         let fut = f
                             .call_mut((getter.call_mut((acc_state.get_mut(),)), item))
@@ -91,16 +110,34 @@ where
                     }
                 },
                 FoldStateProject::Future(fut_state) => fut_state,
+                FoldStateProject::Done => return Poll::Pending,
             };
 
             match task::ready!(fut.get_pin_mut().poll(cx)).branch() {
                 ControlFlow::Continue(acc) => state = FoldStateProject::Accumulate(fut.set_accumulate(acc)),
-                ControlFlow::Break(residual) => break Self::Output::from_residual(residual),
+                ControlFlow::Break(residual) => {
+                    fut.set_done();
+
+                    break Self::Output::from_residual(residual);
+                }
             }
         })
     }
 }
 
+impl<I, T, G, F> FusedFuture for TryFoldAsync<I, T, G, F>
+where
+    I: AsyncIterator,
+    G: for<'a> FnMut<(&'a mut T,), Output = T>,
+    F: FnMut<(T, I::Item)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.state.is_done()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -0,0 +1,148 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::{AsyncIterator, FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    /// Like [`TryFoldAsync`](super::TryFoldAsync), but takes an async closure whose future may borrow from the closure
+    /// itself. The accumulator is moved into each call, so no getter is needed.
+    pub struct TryFoldAsyncFn<'a, I, T, F, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    {
+        #[pin]
+        iter: I,
+        acc: Option<T>,
+        #[pin]
+        state: AsyncFnState<'a, F, (T, I::Item), C>,
+    }
+}
+
+impl<'a, I, T, F, C> TryFoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+{
+    pub(crate) const fn new(iter: I, init: T, f: F, call: C) -> Self {
+        Self {
+            iter,
+            acc: Some(init),
+            state: AsyncFnState::new(f, call),
+        }
+    }
+}
+
+impl<'a, I, T, F, C> Debug for TryFoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator + Debug,
+    T: Debug,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFoldAsyncFn")
+            .field("iter", &self.iter)
+            .field("acc", &self.acc)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, T, F, C> Future for TryFoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    <C::Future as Future>::Output: Try<Output = T>,
+{
+    type Output = <C::Future as Future>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state;
+
+        loop {
+            if let Some(result) = task::ready!(state.as_mut().poll(cx)) {
+                match result.branch() {
+                    ControlFlow::Continue(acc) => *this.acc = Some(acc),
+                    ControlFlow::Break(residual) => return Poll::Ready(Self::Output::from_residual(residual)),
+                }
+            }
+
+            let Some(acc) = this.acc.take() else {
+                return Poll::Pending;
+            };
+
+            match iter.as_mut().poll_next(cx) {
+                Poll::Ready(None) => return Poll::Ready(Self::Output::from_output(acc)),
+                Poll::Ready(Some(item)) => state.as_mut().start((acc, item)),
+                Poll::Pending => {
+                    *this.acc = Some(acc);
+
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<'a, I, T, F, C> FusedFuture for TryFoldAsyncFn<'a, I, T, F, C>
+where
+    I: AsyncIterator,
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, (T, I::Item)>,
+    <C::Future as Future>::Output: Try<Output = T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.acc.is_none() && !self.state.is_pending()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_try_fold_async_fn() {
+        let limit = 100;
+
+        let future = stream::iter([2, 3, 5]).slim_try_fold_async_fn(1, async move |acc: u32, x: u32| {
+            let limit = &limit;
+
+            Yield::new(1).await;
+
+            if x < *limit {
+                Ok(acc * x)
+            } else {
+                Err(x)
+            }
+        });
+
+        assert_eq!(future.await, Ok(30_u32));
+    }
+
+    #[tokio::test]
+    async fn test_try_fold_async_fn_error() {
+        let future = stream::iter([2, 3, 5])
+            .slim_try_fold_async_fn(1, async |acc: u32, x: u32| if x < 3 { Ok(acc * x) } else { Err(x) });
+
+        assert_eq!(future.await, Err(3_u32));
+    }
+
+    #[tokio::test]
+    async fn test_try_fold_async_fn_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_fold_async_fn(0, async |acc: u32, x: u32| acc.checked_add(x));
+
+        assert_eq!(future.await, Some(10_u32));
+    }
+}
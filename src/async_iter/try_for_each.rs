@@ -1,6 +1,7 @@
 use crate::async_iter::try_fold::TryFold;
 use crate::support::fns::ForEachFn;
 use crate::support::{AsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -37,6 +38,15 @@ where
     }
 }
 
+impl<I, F> Debug for TryForEach<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryForEach").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> Future for TryForEach<I, F>
 where
     I: AsyncIterator,
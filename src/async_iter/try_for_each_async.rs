@@ -1,11 +1,13 @@
 use crate::async_iter::try_fold_async::TryFoldAsync;
 use crate::support::fns::ForEachFn;
 use crate::support::{AsyncIterator, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::fns::CopyFn;
 use fn_traits::FnMut;
+use futures_core::FusedFuture;
 
 pin_project_lite::pin_project! {
     pub struct TryForEachAsync<I, F>
@@ -50,6 +52,19 @@ where
     }
 }
 
+impl<I, F> Debug for TryForEachAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = ()>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryForEachAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<I, F> Future for TryForEachAsync<I, F>
 where
     I: AsyncIterator,
@@ -64,6 +79,18 @@ where
     }
 }
 
+impl<I, F> FusedFuture for TryForEachAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
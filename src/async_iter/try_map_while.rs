@@ -0,0 +1,142 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryMapWhile<I, F>
+    where
+        F: ?Sized,
+    {
+        #[pin]
+        iter: Option<I>,
+        f: F,
+    }
+}
+
+impl<I, F> TryMapWhile<I, F> {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
+        Self { iter: Some(iter), f }
+    }
+}
+
+impl<I, F> Clone for TryMapWhile<I, F>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryMapWhile<I, F>
+where
+    I: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryMapWhile")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, R, T> AsyncIterator for TryMapWhile<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,), Output = R> + ?Sized,
+    R: Try<Output = Option<T>>,
+    R::Residual: Residual<T>,
+{
+    type Item = <R::Residual as Residual<T>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+
+        let item = match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+            None => None,
+            Some(item) => match this.f.call_mut((item,)).branch() {
+                ControlFlow::Continue(Some(item)) => return Poll::Ready(Some(Self::Item::from_output(item))),
+                ControlFlow::Continue(None) => None,
+                ControlFlow::Break(residual) => Some(Self::Item::from_residual(residual)),
+            },
+        };
+
+        iter.replace_none();
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| (0, iter.size_hint().1))
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F, R, T> for TryMapWhile<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,), Output = R> + ?Sized,
+        R: Try<Output = Option<T>>,
+        R::Residual: Residual<T>,
+);
+
+impl<I, F, R, T> FusedAsyncIterator for TryMapWhile<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,), Output = R> + ?Sized,
+    R: Try<Output = Option<T>>,
+    R::Residual: Residual<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    fn try_map_while_fn(x: u32) -> Result<Option<u32>, u32> {
+        if x < 7 {
+            Ok((x < 4).then_some(x * 10))
+        } else {
+            Err(x)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while() {
+        let iter = stream::iter(0..10).slim_try_map_while(try_map_while_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(10), Ok(20), Ok(30)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while_error() {
+        let iter = stream::iter([1, 8, 2]).slim_try_map_while(try_map_while_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while_clone() {
+        let iter = stream::iter([1, 8, 2]).slim_try_map_while(try_map_while_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryMapWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)>,
+        F: ?Sized,
+        F::Output: IntoFuture,
+    {
+        #[pin]
+        iter: Option<I>,
+        #[pin]
+        state: Option<<F::Output as IntoFuture>::IntoFuture>,
+        f: F,
+    }
+}
+
+impl<I, F> TryMapWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)>,
+    F::Output: IntoFuture,
+{
+    pub(crate) const fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state: None,
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for TryMapWhileAsync<I, F>
+where
+    I: AsyncIterator + Clone,
+    F: FnMut<(I::Item,)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryMapWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryMapWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, T> AsyncIterator for TryMapWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+{
+    type Item = <<<F::Output as IntoFuture>::Output as Try>::Residual as Residual<T>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+        let state = this.state.pinned_entry();
+        let f = this.f;
+
+        let mut fut = match state {
+            OptionPinnedEntry::None(none_state) => match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                None => {
+                    iter.replace_none();
+
+                    return Poll::Ready(None);
+                }
+                Some(item) => none_state.replace_some(f.call_mut((item,)).into_future()),
+            },
+            OptionPinnedEntry::Some(some_state) => some_state,
+        };
+
+        let item = task::ready!(fut.get_pin_mut().poll(cx));
+
+        fut.replace_none();
+
+        let item = match item.branch() {
+            ControlFlow::Continue(Some(item)) => return Poll::Ready(Some(Self::Item::from_output(item))),
+            ControlFlow::Continue(None) => None,
+            ControlFlow::Break(residual) => Some(Self::Item::from_residual(residual)),
+        };
+
+        iter.replace_none();
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = (0, iter.size_hint().1);
+
+            if self.state.is_some() {
+                candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F, T> for TryMapWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item,)> + ?Sized,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+        <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+);
+
+impl<I, F, T> FusedAsyncIterator for TryMapWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    fn try_map_while_fn(x: u32) -> Ready<Result<Option<u32>, u32>> {
+        future::ready(if x < 7 { Ok((x < 4).then_some(x * 10)) } else { Err(x) })
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while_async() {
+        let iter = stream::iter(0..10).slim_try_map_while_async(try_map_while_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(10), Ok(20), Ok(30)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while_async_error() {
+        let iter = stream::iter([1, 8, 2]).slim_try_map_while_async(try_map_while_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_map_while_async_clone() {
+        let iter = stream::iter([1, 8, 2]).slim_try_map_while_async(try_map_while_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(10), Err(8)]);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::async_iter::try_fold::TryFold;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct TryPositionFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<(usize, T)> for TryPositionFn<P>
+where
+    P: FnMut<(T,)> + ?Sized,
+    P::Output: Try<Output = bool>,
+    <P::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    type Output = RawResidual<<<P::Output as Try>::Residual as Residual<Option<usize>>>::TryType, usize>;
+
+    fn call_mut(&mut self, args: (usize, T)) -> Self::Output {
+        let (index, item) = args;
+
+        match self.predicate.call_mut((item,)).branch() {
+            ControlFlow::Continue(true) => Self::Output::from_residual(Try::from_output(Some(index))),
+            ControlFlow::Continue(false) => Self::Output::from_output(index + 1),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryPosition<I, P>
+    where
+        P: ?Sized
+    {
+        #[pin]
+        inner: TryFold<I, usize, CopyFn, TryPositionFn<P>>,
+    }
+}
+
+impl<I, P> TryPosition<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFold::new(iter, 0, CopyFn::default(), TryPositionFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryPosition<I, P>
+where
+    I: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryPosition<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryPosition").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryPosition<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: Try<Output = bool>,
+    <P::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    type Output = <<P::Output as Try>::Residual as Residual<Option<usize>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(_) => Self::Output::from_output(None),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::stream;
+
+    fn equals_5(x: u32) -> Result<bool, u32> {
+        if x < 10 {
+            Ok(x == 5)
+        } else {
+            Err(x)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_position() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_position(equals_5);
+
+        assert_eq!(future.await, Ok(Some(2)));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_fail() {
+        let future = stream::iter([2, 3, 7]).slim_try_position(equals_5);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_error() {
+        let future = stream::iter([2, 11, 5]).slim_try_position(equals_5);
+
+        assert_eq!(future.await, Err(11));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_clone() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_position(equals_5);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(2)));
+        assert_eq!(future_2.await, Ok(Some(2)));
+    }
+}
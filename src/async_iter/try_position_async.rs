@@ -0,0 +1,187 @@
+use crate::async_iter::try_fold_async::TryFoldAsync;
+use crate::future::Map;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::CopyFn;
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+
+#[derive(Clone)]
+struct BreakIfTrue {
+    index: usize,
+}
+
+impl<R> FnMut<(R,)> for BreakIfTrue
+where
+    R: Try<Output = bool>,
+    R::Residual: Residual<Option<usize>>,
+{
+    type Output = RawResidual<<R::Residual as Residual<Option<usize>>>::TryType, usize>;
+
+    #[inline]
+    fn call_mut(&mut self, args: (R,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(true) => Self::Output::from_residual(Try::from_output(Some(self.index))),
+            ControlFlow::Continue(false) => Self::Output::from_output(self.index + 1),
+            ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct TryPositionAsyncFoldFn<P>
+where
+    P: ?Sized,
+{
+    predicate: P,
+}
+
+impl<T, P> FnMut<(usize, T)> for TryPositionAsyncFoldFn<P>
+where
+    P: FnMut<(T,)> + ?Sized,
+    P::Output: IntoFuture,
+{
+    type Output = Map<<P::Output as IntoFuture>::IntoFuture, BreakIfTrue>;
+
+    fn call_mut(&mut self, args: (usize, T)) -> Self::Output {
+        let (index, item) = args;
+
+        Map::new(self.predicate.call_mut((item,)).into_future(), BreakIfTrue { index })
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryPositionAsync<I, P>
+    where
+        I: AsyncIterator,
+        P: FnMut<(I::Item,)>,
+        P: ?Sized,
+        P::Output: IntoFuture,
+        <P::Output as IntoFuture>::Output: Try<Output = bool>,
+        <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+    {
+        #[pin]
+        inner: TryFoldAsync<I, usize, CopyFn, TryPositionAsyncFoldFn<P>>,
+    }
+}
+
+impl<I, P> TryPositionAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            inner: TryFoldAsync::new(iter, 0, CopyFn::default(), TryPositionAsyncFoldFn { predicate }),
+        }
+    }
+}
+
+impl<I, P> Clone for TryPositionAsync<I, P>
+where
+    I: AsyncIterator + Clone,
+    P: FnMut<(I::Item,)> + Clone,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+    <P::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, P> Debug for TryPositionAsync<I, P>
+where
+    I: AsyncIterator + Debug,
+    P: FnMut<(I::Item,)>,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+    <P::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryPositionAsync").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, P> Future for TryPositionAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    type Output = <<<P::Output as IntoFuture>::Output as Try>::Residual as Residual<Option<usize>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(_) => Self::Output::from_output(None),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+impl<I, P> FusedFuture for TryPositionAsync<I, P>
+where
+    I: AsyncIterator,
+    P: FnMut<(I::Item,)> + ?Sized,
+    P::Output: IntoFuture,
+    <P::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<P::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::stream;
+
+    fn equals_5(x: u32) -> Ready<Result<bool, u32>> {
+        future::ready(if x < 10 { Ok(x == 5) } else { Err(x) })
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_position_async(equals_5);
+
+        assert_eq!(future.await, Ok(Some(2)));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async_fail() {
+        let future = stream::iter([2, 3, 7]).slim_try_position_async(equals_5);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async_error() {
+        let future = stream::iter([2, 11, 5]).slim_try_position_async(equals_5);
+
+        assert_eq!(future.await, Err(11));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async_clone() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_position_async(equals_5);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(2)));
+        assert_eq!(future_2.await, Ok(Some(2)));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncFnState;
+use crate::support::{AsyncIterator, FromResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+pin_project_lite::pin_project! {
+    /// Like [`TryPositionAsync`](super::TryPositionAsync), but takes an async closure whose future may borrow from the
+    /// closure itself.
+    pub struct TryPositionAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        C: AsyncFnMutCall<'a, P, I::Item>,
+    {
+        #[pin]
+        iter: I,
+        index: usize,
+        #[pin]
+        state: AsyncFnState<'a, P, I::Item, C>,
+    }
+}
+
+impl<'a, I, P, C> TryPositionAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter,
+            index: 0,
+            state: AsyncFnState::new(predicate, call),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for TryPositionAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryPositionAsyncFn")
+            .field("iter", &self.iter)
+            .field("index", &self.index)
+            .field("is_pending", &self.state.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> Future for TryPositionAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, I::Item>,
+    <C::Future as Future>::Output: Try<Output = bool>,
+    <<C::Future as Future>::Output as Try>::Residual: Residual<Option<usize>>,
+{
+    type Output = <<<C::Future as Future>::Output as Try>::Residual as Residual<Option<usize>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let index = this.index;
+        let mut state = this.state;
+
+        loop {
+            if let Some(result) = task::ready!(state.as_mut().poll(cx)) {
+                match result.branch() {
+                    ControlFlow::Continue(true) => return Poll::Ready(Self::Output::from_output(Some(*index))),
+                    ControlFlow::Continue(false) => *index += 1,
+                    ControlFlow::Break(residual) => return Poll::Ready(Self::Output::from_residual(residual)),
+                }
+            }
+
+            match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(Self::Output::from_output(None)),
+                Some(item) => state.as_mut().start(item),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_try_position_async_fn() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_position_async_fn(async |x: u32| {
+            Yield::new(1).await;
+
+            if x < 10 {
+                Ok(x == 5)
+            } else {
+                Err(x)
+            }
+        });
+
+        assert_eq!(future.await, Ok(Some(2_usize)));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async_fn_fail() {
+        let future =
+            stream::iter([2, 3, 7]).slim_try_position_async_fn(async |x: u32| if x < 10 { Ok(x == 5) } else { Err(x) });
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_position_async_fn_error() {
+        let future = stream::iter([2, 11, 5])
+            .slim_try_position_async_fn(async |x: u32| if x < 10 { Ok(x == 5) } else { Err(x) });
+
+        assert_eq!(future.await, Err(11_u32));
+    }
+}
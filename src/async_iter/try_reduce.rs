@@ -0,0 +1,160 @@
+use crate::async_iter::try_fold::TryFold;
+use crate::support::{AsyncIterator, FromResidual, RawResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::fns::MemTakeFn;
+use fn_traits::FnMut;
+
+#[derive(Clone)]
+struct TryReduceFn<F>
+where
+    F: ?Sized,
+{
+    f: F,
+}
+
+impl<T, F> FnMut<(Option<T>, T)> for TryReduceFn<F>
+where
+    F: FnMut<(T, T)> + ?Sized,
+    F::Output: Try<Output = T>,
+    <F::Output as Try>::Residual: Residual<Option<T>>,
+{
+    type Output = RawResidual<<<F::Output as Try>::Residual as Residual<Option<T>>>::TryType, Option<T>>;
+
+    fn call_mut(&mut self, args: (Option<T>, T)) -> Self::Output {
+        match args.0 {
+            None => Self::Output::from_output(Some(args.1)),
+            Some(acc) => match self.f.call_mut((acc, args.1)).branch() {
+                ControlFlow::Continue(acc) => Self::Output::from_output(Some(acc)),
+                ControlFlow::Break(residual) => Self::Output::from_residual(FromResidual::from_residual(residual)),
+            },
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryReduce<I, F>
+    where
+        I: AsyncIterator,
+        F: ?Sized,
+    {
+        #[pin]
+        inner: TryFold<I, Option<I::Item>, MemTakeFn, TryReduceFn<F>>,
+    }
+}
+
+impl<I, F> TryReduce<I, F>
+where
+    I: AsyncIterator,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            inner: TryFold::new(iter, None, MemTakeFn::default(), TryReduceFn { f }),
+        }
+    }
+}
+
+impl<I, F> Clone for TryReduce<I, F>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryReduce<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryReduce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, F> Future for TryReduce<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item, I::Item)> + ?Sized,
+    F::Output: Try<Output = I::Item>,
+    <F::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    type Output = <<F::Output as Try>::Residual as Residual<Option<I::Item>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Poll::Ready(match task::ready!(self.project().inner.poll(cx)).branch() {
+            ControlFlow::Continue(acc) => Self::Output::from_output(acc),
+            ControlFlow::Break(result) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::stream;
+
+    fn add(lhs: u32, rhs: u32) -> Result<u32, u32> {
+        if rhs < 5 {
+            Ok(lhs + rhs)
+        } else {
+            Err(rhs)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce() {
+        let future = stream::iter([2, 3, 4]).slim_try_reduce(add);
+
+        assert_eq!(future.await, Ok(Some(9)));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_empty() {
+        let future = stream::iter(None::<u32>).slim_try_reduce(add);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_single() {
+        let future = stream::iter(Some(7)).slim_try_reduce(add);
+
+        assert_eq!(future.await, Ok(Some(7)));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_error() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_reduce(add);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_with_option() {
+        let future = stream::iter([2, 3, 5]).slim_try_reduce(u32::checked_mul);
+
+        assert_eq!(future.await, Some(Some(30)));
+
+        let future = stream::iter([u32::MAX, 2]).slim_try_reduce(u32::checked_mul);
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_clone() {
+        let future = stream::iter([2, 3, 4]).slim_try_reduce(add);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(9)));
+        assert_eq!(future_2.await, Ok(Some(9)));
+    }
+}
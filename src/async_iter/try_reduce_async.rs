@@ -0,0 +1,191 @@
+use crate::support::states::{ReduceState, ReduceStateProject};
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    pub struct TryReduceAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: FnMut<(I::Item, I::Item)>,
+        F: ?Sized,
+        F::Output: IntoFuture,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: ReduceState<I::Item, <F::Output as IntoFuture>::IntoFuture>,
+        f: F,
+    }
+}
+
+impl<I, F> TryReduceAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item, I::Item)>,
+    F::Output: IntoFuture,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            state: ReduceState::default(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for TryReduceAsync<I, F>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    F: FnMut<(I::Item, I::Item)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryReduceAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: FnMut<(I::Item, I::Item)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryReduceAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Future for TryReduceAsync<I, F>
+where
+    I: AsyncIterator,
+    F: FnMut<(I::Item, I::Item)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = I::Item>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<I::Item>>,
+{
+    type Output = <<<F::Output as IntoFuture>::Output as Try>::Residual as Residual<Option<I::Item>>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let mut state = this.state.pin_project();
+        let f = this.f;
+
+        loop {
+            let mut fut = match state {
+                ReduceStateProject::Empty(empty_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                    None => return Poll::Ready(Self::Output::from_output(None)),
+                    Some(item) => {
+                        state = ReduceStateProject::Accumulate(empty_state.set_accumulate(item));
+
+                        continue;
+                    }
+                },
+                ReduceStateProject::Accumulate(acc_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                    None => return Poll::Ready(Self::Output::from_output(Some(acc_state.set_empty().1))),
+                    Some(item) => {
+                        let (empty_state, acc) = acc_state.set_empty();
+                        let fut = f.call_mut((acc, item)).into_future();
+
+                        empty_state.set_future(fut)
+                    }
+                },
+                ReduceStateProject::Future(fut_state) => fut_state,
+            };
+
+            match task::ready!(fut.get_pin_mut().poll(cx)).branch() {
+                ControlFlow::Continue(acc) => state = ReduceStateProject::Accumulate(fut.set_accumulate(acc)),
+                ControlFlow::Break(residual) => return Poll::Ready(Self::Output::from_residual(residual)),
+            }
+        }
+    }
+}
+
+impl<I, F> FusedFuture for TryReduceAsync<I, F>
+where
+    I: FusedAsyncIterator,
+    F: FnMut<(I::Item, I::Item)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try<Output = I::Item>,
+    <<F::Output as IntoFuture>::Output as Try>::Residual: Residual<Option<I::Item>>,
+    <F::Output as IntoFuture>::IntoFuture: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.state
+            .get_future()
+            .map_or_else(|| self.iter.is_terminated(), FusedFuture::is_terminated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::stream;
+
+    fn add(lhs: u32, rhs: u32) -> Ready<Result<u32, u32>> {
+        future::ready(if rhs < 5 { Ok(lhs + rhs) } else { Err(rhs) })
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async() {
+        let future = stream::iter([2, 3, 4]).slim_try_reduce_async(add);
+
+        assert_eq!(future.await, Ok(Some(9)));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async_empty() {
+        let future = stream::iter(None::<u32>).slim_try_reduce_async(add);
+
+        assert_eq!(future.await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async_single() {
+        let future = stream::iter(Some(7)).slim_try_reduce_async(add);
+
+        assert_eq!(future.await, Ok(Some(7)));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async_error() {
+        let future = stream::iter([2, 3, 5, 7]).slim_try_reduce_async(add);
+
+        assert_eq!(future.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async_with_option() {
+        let future =
+            stream::iter([u32::MAX, 2]).slim_try_reduce_async(|lhs: u32, rhs| future::ready(lhs.checked_mul(rhs)));
+
+        assert_eq!(future.await, None);
+    }
+
+    #[tokio::test]
+    async fn test_try_reduce_async_clone() {
+        let future = stream::iter([2, 3, 4]).slim_try_reduce_async(add);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(Some(9)));
+        assert_eq!(future_2.await, Ok(Some(9)));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryScan<I, S, F>
+    where
+        F: ?Sized,
+    {
+        #[pin]
+        iter: Option<I>,
+        state: S,
+        f: F,
+    }
+}
+
+impl<I, S, F> TryScan<I, S, F> {
+    pub(crate) const fn new(iter: I, state: S, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state,
+            f,
+        }
+    }
+}
+
+impl<I, S, F> Clone for TryScan<I, S, F>
+where
+    I: Clone,
+    S: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, S, F> Debug for TryScan<I, S, F>
+where
+    I: Debug,
+    S: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryScan")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, S, F, R, T> AsyncIterator for TryScan<I, S, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a mut S, I::Item), Output = R> + ?Sized,
+    R: Try<Output = Option<T>>,
+    R::Residual: Residual<T>,
+{
+    type Item = <R::Residual as Residual<T>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        Poll::Ready(if let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() {
+            let item =
+                task::ready!(iter.get_pin_mut().poll_next(cx)).map(|item| this.f.call_mut((this.state, item)).branch());
+
+            match item {
+                Some(ControlFlow::Continue(Some(item))) => Some(Self::Item::from_output(item)),
+                Some(ControlFlow::Continue(None)) | None => {
+                    iter.replace_none();
+
+                    None
+                }
+                Some(ControlFlow::Break(residual)) => {
+                    iter.replace_none();
+
+                    Some(Self::Item::from_residual(residual))
+                }
+            }
+        } else {
+            None
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| (0, iter.size_hint().1))
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, S, F, R, T> for TryScan<I, S, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a mut S, I::Item), Output = R> + ?Sized,
+        R: Try<Output = Option<T>>,
+        R::Residual: Residual<T>,
+);
+
+impl<I, S, F, R, T> FusedAsyncIterator for TryScan<I, S, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a mut S, I::Item), Output = R> + ?Sized,
+    R: Try<Output = Option<T>>,
+    R::Residual: Residual<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    fn scan_fn(state: &mut u32, item: u16) -> Result<Option<u64>, u16> {
+        if item < 5 {
+            *state += u32::from(item);
+
+            Ok((*state < 5).then_some(u64::from(*state)))
+        } else {
+            Err(item)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_scan() {
+        let iter = stream::iter(0..10).slim_try_scan(0, scan_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_error() {
+        let iter = stream::iter([1, 2, 7, 0]).slim_try_scan(0, scan_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_with_option() {
+        let iter = stream::iter([1, 2, 0, 3]).slim_try_scan(0, |state: &mut u32, item: u32| {
+            (item != 0).then(|| {
+                *state += item;
+
+                Some(*state)
+            })
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Some(1), Some(3), None]);
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_clone() {
+        let iter = stream::iter([1, 2, 7, 0]).slim_try_scan(0, scan_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+    }
+}
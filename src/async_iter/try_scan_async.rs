@@ -0,0 +1,203 @@
+use crate::async_iter::scan_async::ScanFn;
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryScanAsync<I, S, F>
+    where
+        I: AsyncIterator,
+        F: ScanFn<S, I::Item>,
+        F: ?Sized,
+        <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    {
+        #[pin]
+        iter: Option<I>,
+        state: S,
+        #[pin]
+        fut: Option<<<F as ScanFn<S, I::Item>>::Output as IntoFuture>::IntoFuture>,
+        f: F,
+    }
+}
+
+impl<I, S, F> TryScanAsync<I, S, F>
+where
+    I: AsyncIterator,
+    F: ScanFn<S, I::Item>,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+{
+    pub(crate) const fn new(iter: I, state: S, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state,
+            fut: None,
+            f,
+        }
+    }
+}
+
+impl<I, S, F> Clone for TryScanAsync<I, S, F>
+where
+    I: AsyncIterator + Clone,
+    S: Clone,
+    F: ScanFn<S, I::Item> + Clone,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            fut: self.fut.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, S, F> Debug for TryScanAsync<I, S, F>
+where
+    I: AsyncIterator + Debug,
+    S: Debug,
+    F: ScanFn<S, I::Item> + ?Sized,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryScanAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .field("fut", &self.fut)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, S, F, T> AsyncIterator for TryScanAsync<I, S, F>
+where
+    I: AsyncIterator,
+    F: ScanFn<S, I::Item> + ?Sized,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+{
+    type Item = <<<<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output as Try>::Residual as Residual<T>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+        let state = this.state;
+        let fut = this.fut.pinned_entry();
+        let f = this.f;
+
+        let mut fut = match fut {
+            OptionPinnedEntry::None(none_state) => match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                None => {
+                    iter.replace_none();
+
+                    return Poll::Ready(None);
+                }
+                Some(item) => none_state.replace_some(f.call_mut((state, item)).into_future()),
+            },
+            OptionPinnedEntry::Some(some_state) => some_state,
+        };
+
+        let item = task::ready!(fut.get_pin_mut().poll(cx));
+
+        fut.replace_none();
+
+        Poll::Ready(match item.branch() {
+            ControlFlow::Continue(Some(item)) => Some(Self::Item::from_output(item)),
+            ControlFlow::Continue(None) => {
+                iter.replace_none();
+
+                None
+            }
+            ControlFlow::Break(residual) => {
+                iter.replace_none();
+
+                Some(Self::Item::from_residual(residual))
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = (0, iter.size_hint().1);
+
+            if self.fut.is_some() {
+                candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, S, F, T> for TryScanAsync<I, S, F>
+    where
+        I: AsyncIterator,
+        F: ScanFn<S, I::Item> + ?Sized,
+        <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+        <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+        <<<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+);
+
+impl<I, S, F, T> FusedAsyncIterator for TryScanAsync<I, S, F>
+where
+    I: AsyncIterator,
+    F: ScanFn<S, I::Item> + ?Sized,
+    <F as ScanFn<S, I::Item>>::Output: IntoFuture,
+    <<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output: Try<Output = Option<T>>,
+    <<<F as ScanFn<S, I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<T>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::{self, Ready};
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    fn scan_async_fn(state: &mut u32, item: u16) -> Ready<Result<Option<u64>, u16>> {
+        future::ready(if item < 5 {
+            *state += u32::from(item);
+
+            Ok((*state < 5).then_some(u64::from(*state)))
+        } else {
+            Err(item)
+        })
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_async() {
+        let iter = stream::iter(0..10).slim_try_scan_async(0, scan_async_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_async_error() {
+        let iter = stream::iter([1, 2, 7, 0]).slim_try_scan_async(0, scan_async_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_scan_async_clone() {
+        let iter = stream::iter([1, 2, 7, 0]).slim_try_scan_async(0, scan_async_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(1), Ok(3), Err(7)]);
+    }
+}
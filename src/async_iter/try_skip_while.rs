@@ -0,0 +1,166 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use option_entry::{OptionEntry, OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TrySkipWhile<I, F> {
+        #[pin]
+        iter: Option<I>,
+        state: Option<F>,
+    }
+}
+
+impl<I, F> TrySkipWhile<I, F> {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state: Some(f),
+        }
+    }
+}
+
+impl<I, F> Clone for TrySkipWhile<I, F>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TrySkipWhile<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrySkipWhile")
+            .field("iter", &self.iter)
+            .field("skipping", &self.state.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, R> AsyncIterator for TrySkipWhile<I, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a I::Item,), Output = R>,
+    R: Try<Output = bool>,
+    R::Residual: Residual<I::Item>,
+{
+    type Item = <R::Residual as Residual<I::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+
+        let item = if let OptionEntry::Some(mut f) = this.state.entry() {
+            loop {
+                let Some(item) = task::ready!(iter.get_pin_mut().poll_next(cx)) else {
+                    break None;
+                };
+
+                match f.get_mut().call_mut((&item,)).branch() {
+                    ControlFlow::Continue(true) => {}
+                    ControlFlow::Continue(false) => {
+                        f.replace_none();
+
+                        break Some(Self::Item::from_output(item));
+                    }
+                    ControlFlow::Break(residual) => {
+                        iter.replace_none();
+
+                        return Poll::Ready(Some(Self::Item::from_residual(residual)));
+                    }
+                }
+            }
+        } else {
+            task::ready!(iter.get_pin_mut().poll_next(cx)).map(Self::Item::from_output)
+        };
+
+        if item.is_none() {
+            iter.replace_none();
+        }
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = iter.size_hint();
+
+            if self.state.is_some() {
+                candidate.0 = 0;
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F, R> for TrySkipWhile<I, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a I::Item,), Output = R>,
+        R: Try<Output = bool>,
+        R::Residual: Residual<I::Item>,
+);
+
+impl<I, F, R> FusedAsyncIterator for TrySkipWhile<I, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a I::Item,), Output = R>,
+    R: Try<Output = bool>,
+    R::Residual: Residual<I::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_try_skip_while() {
+        let iter = stream::iter(0..10).slim_try_skip_while(|&x| Ok::<_, u32>(x < 5));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_error() {
+        let iter = stream::iter(0..10).slim_try_skip_while(|&x| if x < 3 { Ok(true) } else { Err(x) });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Err(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_with_option() {
+        let iter = stream::iter(0..5).slim_try_skip_while(|&x| (x != 1).then_some(true));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [None]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_clone() {
+        let iter = stream::iter(0..10).slim_try_skip_while(|&x| Ok::<_, u32>(x < 5));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+    }
+}
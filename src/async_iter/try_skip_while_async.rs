@@ -0,0 +1,235 @@
+use crate::support::states::{PredicateState, PredicateStateProject};
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, PredicateFn, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    struct State<F, T, Fut> {
+        #[pin]
+        predicate_state: PredicateState<T, Fut>,
+        f: F,
+    }
+}
+
+impl<F, T, Fut> Debug for State<F, T, Fut>
+where
+    T: Debug,
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("State")
+            .field("predicate_state", &self.predicate_state)
+            .finish_non_exhaustive()
+    }
+}
+
+type StateType<I, F> = State<
+    F,
+    <I as AsyncIterator>::Item,
+    <<F as PredicateFn<<I as AsyncIterator>::Item>>::Output as IntoFuture>::IntoFuture,
+>;
+
+pin_project_lite::pin_project! {
+    pub struct TrySkipWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item>,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    {
+        #[pin]
+        iter: Option<I>,
+        #[pin]
+        state: Option<StateType<I, F>>,
+    }
+}
+
+impl<I, F> TrySkipWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state: Some(State {
+                f,
+                predicate_state: PredicateState::default(),
+            }),
+        }
+    }
+}
+
+impl<I, F> Clone for TrySkipWhileAsync<I, F>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    F: PredicateFn<I::Item> + Clone,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TrySkipWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrySkipWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<I, F> AsyncIterator for TrySkipWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+{
+    type Item =
+        <<<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual as Residual<I::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+
+        let item = if let OptionPinnedEntry::Some(mut state) = this.state.pinned_entry() {
+            let state_projection = state.get_pin_mut().project();
+            let mut predicate_state = state_projection.predicate_state.pin_project();
+            let f = state_projection.f;
+
+            loop {
+                let mut fut = match predicate_state {
+                    PredicateStateProject::Empty(empty_state) => match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                        None => break None,
+                        Some(item) => {
+                            let fut = f.call_mut((&item,)).into_future();
+
+                            empty_state.set_future(item, fut)
+                        }
+                    },
+                    PredicateStateProject::Future(fut_state) => fut_state,
+                    PredicateStateProject::Done => break None,
+                };
+
+                let skip = task::ready!(fut.get_pin_mut().poll(cx));
+                let (empty_state, item) = fut.set_empty();
+
+                predicate_state = PredicateStateProject::Empty(empty_state);
+
+                match skip.branch() {
+                    ControlFlow::Continue(true) => {}
+                    ControlFlow::Continue(false) => {
+                        state.replace_none();
+
+                        break Some(Self::Item::from_output(item));
+                    }
+                    ControlFlow::Break(residual) => {
+                        iter.replace_none();
+
+                        return Poll::Ready(Some(Self::Item::from_residual(residual)));
+                    }
+                }
+            }
+        } else {
+            task::ready!(iter.get_pin_mut().poll_next(cx)).map(Self::Item::from_output)
+        };
+
+        if item.is_none() {
+            iter.replace_none();
+        }
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = iter.size_hint();
+
+            if let Some(state) = &self.state {
+                candidate.0 = 0;
+
+                if state.predicate_state.get_future().is_some() {
+                    candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+                }
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for TrySkipWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item>,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture,
+        <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+        <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+);
+
+impl<I, F> FusedAsyncIterator for TrySkipWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{future, stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_try_skip_while_async() {
+        let iter = stream::iter(0..10).slim_try_skip_while_async(|&x| future::ok::<_, u32>(x < 5));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_async_error() {
+        let iter =
+            stream::iter(0..10).slim_try_skip_while_async(|&x| future::ready(if x < 3 { Ok(true) } else { Err(x) }));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Err(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_async_clone() {
+        let iter = stream::iter(0..10).slim_try_skip_while_async(|&x| future::ok::<_, u32>(x < 5));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(5), Ok(6), Ok(7), Ok(8), Ok(9)]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::fns::AsyncFnMutCall;
+use crate::support::states::AsyncPredicateState;
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    /// Like [`TrySkipWhileAsync`](super::TrySkipWhileAsync), but takes an async closure whose future may borrow from
+    /// the closure and the item.
+    pub struct TrySkipWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    {
+        #[pin]
+        iter: Option<I>,
+        #[pin]
+        state: Option<AsyncPredicateState<'a, I::Item, P, C>>,
+    }
+}
+
+impl<'a, I, P, C> TrySkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    pub(crate) const fn new(iter: I, predicate: P, call: C) -> Self {
+        Self {
+            iter: Some(iter),
+            state: Some(AsyncPredicateState::new(predicate, call)),
+        }
+    }
+}
+
+impl<'a, I, P, C> Debug for TrySkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug + 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrySkipWhileAsyncFn")
+            .field("iter", &self.iter)
+            .field("item", &self.state.as_ref().and_then(AsyncPredicateState::get_item))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, I, P, C> AsyncIterator for TrySkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    <C::Future as Future>::Output: Try<Output = bool>,
+    <<C::Future as Future>::Output as Try>::Residual: Residual<I::Item>,
+{
+    type Item = <<<C::Future as Future>::Output as Try>::Residual as Residual<I::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+
+        let item = if let OptionPinnedEntry::Some(mut state) = this.state.pinned_entry() {
+            loop {
+                if let Some((skip, item)) = task::ready!(state.get_pin_mut().poll(cx)) {
+                    match skip.branch() {
+                        ControlFlow::Continue(true) => {}
+                        ControlFlow::Continue(false) => {
+                            state.replace_none();
+
+                            break Some(Self::Item::from_output(item));
+                        }
+                        ControlFlow::Break(residual) => {
+                            iter.replace_none();
+
+                            return Poll::Ready(Some(Self::Item::from_residual(residual)));
+                        }
+                    }
+                }
+
+                match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                    None => break None,
+                    Some(item) => state.get_pin_mut().start(item),
+                }
+            }
+        } else {
+            task::ready!(iter.get_pin_mut().poll_next(cx)).map(Self::Item::from_output)
+        };
+
+        if item.is_none() {
+            iter.replace_none();
+        }
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = iter.size_hint();
+
+            if let Some(state) = &self.state {
+                candidate.0 = 0;
+
+                if state.get_item().is_some() {
+                    candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+                }
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<'a, I, P, C> for TrySkipWhileAsyncFn<'a, I, P, C>
+    where
+        I: AsyncIterator,
+        I::Item: 'a,
+        P: 'a,
+        C: AsyncFnMutCall<'a, P, &'a I::Item>,
+        <C::Future as Future>::Output: Try<Output = bool>,
+        <<C::Future as Future>::Output as Try>::Residual: Residual<I::Item>,
+);
+
+impl<'a, I, P, C> FusedAsyncIterator for TrySkipWhileAsyncFn<'a, I, P, C>
+where
+    I: AsyncIterator,
+    I::Item: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a I::Item>,
+    <C::Future as Future>::Output: Try<Output = bool>,
+    <<C::Future as Future>::Output as Try>::Residual: Residual<I::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use crate::test_utilities::Yield;
+    use futures_util::{stream, StreamExt};
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_try_skip_while_async_fn() {
+        let iter = stream::iter(0..10).slim_try_skip_while_async_fn(async |&x: &u32| {
+            Yield::new(1).await;
+
+            Ok::<_, u32>(x < 5)
+        });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(5_u32), Ok(6), Ok(7), Ok(8), Ok(9)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_async_fn_error() {
+        let iter =
+            stream::iter(0..10).slim_try_skip_while_async_fn(async |&x: &u32| if x < 2 { Ok(true) } else { Err(x) });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Err(2_u32)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_skip_while_async_fn_borrows_item() {
+        let iter = stream::iter([String::from("a"), String::from("bc"), String::from("d")])
+            .slim_try_skip_while_async_fn(async |item: &String| {
+                let item = item.as_str();
+
+                Yield::new(1).await;
+
+                Some(item.len() == 1)
+            });
+
+        assert_eq!(
+            iter.collect::<Vec<_>>().await,
+            [Some(String::from("bc")), Some(String::from("d"))]
+        );
+    }
+}
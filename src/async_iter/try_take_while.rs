@@ -0,0 +1,141 @@
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryTakeWhile<I, F>
+    where
+        F: ?Sized,
+    {
+        #[pin]
+        iter: Option<I>,
+        f: F,
+    }
+}
+
+impl<I, F> TryTakeWhile<I, F> {
+    pub(crate) const fn new(iter: I, f: F) -> Self {
+        Self { iter: Some(iter), f }
+    }
+}
+
+impl<I, F> Clone for TryTakeWhile<I, F>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryTakeWhile<I, F>
+where
+    I: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryTakeWhile")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, R> AsyncIterator for TryTakeWhile<I, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a I::Item,), Output = R> + ?Sized,
+    R: Try<Output = bool>,
+    R::Residual: Residual<I::Item>,
+{
+    type Item = <R::Residual as Residual<I::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+
+        let item = match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+            None => None,
+            Some(item) => match this.f.call_mut((&item,)).branch() {
+                ControlFlow::Continue(true) => return Poll::Ready(Some(Self::Item::from_output(item))),
+                ControlFlow::Continue(false) => None,
+                ControlFlow::Break(residual) => Some(Self::Item::from_residual(residual)),
+            },
+        };
+
+        iter.replace_none();
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| (0, iter.size_hint().1))
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F, R> for TryTakeWhile<I, F>
+    where
+        I: AsyncIterator,
+        F: for<'a> FnMut<(&'a I::Item,), Output = R> + ?Sized,
+        R: Try<Output = bool>,
+        R::Residual: Residual<I::Item>,
+);
+
+impl<I, F, R> FusedAsyncIterator for TryTakeWhile<I, F>
+where
+    I: AsyncIterator,
+    F: for<'a> FnMut<(&'a I::Item,), Output = R> + ?Sized,
+    R: Try<Output = bool>,
+    R::Residual: Residual<I::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_try_take_while() {
+        let iter = stream::iter(0..10).slim_try_take_while(|&x| Ok::<_, u32>(x < 5));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Ok(3), Ok(4)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_take_while_error() {
+        let iter = stream::iter(0..10).slim_try_take_while(|&x| if x < 3 { Ok(true) } else { Err(x) });
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_take_while_with_option() {
+        let iter = stream::iter(0..10).slim_try_take_while(|&x| (x < 2).then_some(true));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Some(0), Some(1), None]);
+    }
+
+    #[tokio::test]
+    async fn test_try_take_while_clone() {
+        let iter = stream::iter(0..10).slim_try_take_while(|&x| if x < 3 { Ok(true) } else { Err(x) });
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::support::states::{PredicateState, PredicateStateProject};
+use crate::support::{AsyncIterator, FromResidual, FusedAsyncIterator, PredicateFn, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct TryTakeWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item>,
+        F: ?Sized,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    {
+        #[pin]
+        iter: Option<I>,
+        #[pin]
+        state: PredicateState<I::Item, <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture>,
+        f: F,
+    }
+}
+
+impl<I, F> TryTakeWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item>,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: Some(iter),
+            state: PredicateState::default(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Clone for TryTakeWhileAsync<I, F>
+where
+    I: AsyncIterator + Clone,
+    I::Item: Clone,
+    F: PredicateFn<I::Item> + Clone,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for TryTakeWhileAsync<I, F>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+    F: PredicateFn<I::Item> + ?Sized,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryTakeWhileAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> AsyncIterator for TryTakeWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item> + ?Sized,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+{
+    type Item =
+        <<<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual as Residual<I::Item>>::TryType;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let OptionPinnedEntry::Some(mut iter) = this.iter.pinned_entry() else {
+            return Poll::Ready(None);
+        };
+        let state = this.state.pin_project();
+        let f = this.f;
+
+        let mut fut = match state {
+            PredicateStateProject::Empty(empty_state) => match task::ready!(iter.get_pin_mut().poll_next(cx)) {
+                None => {
+                    iter.replace_none();
+
+                    return Poll::Ready(None);
+                }
+                Some(item) => {
+                    let fut = f.call_mut((&item,)).into_future();
+
+                    empty_state.set_future(item, fut)
+                }
+            },
+            PredicateStateProject::Future(fut_state) => fut_state,
+            PredicateStateProject::Done => return Poll::Ready(None),
+        };
+
+        let take = task::ready!(fut.get_pin_mut().poll(cx));
+        let item = fut.set_empty().1;
+
+        let item = match take.branch() {
+            ControlFlow::Continue(true) => return Poll::Ready(Some(Self::Item::from_output(item))),
+            ControlFlow::Continue(false) => None,
+            ControlFlow::Break(residual) => Some(Self::Item::from_residual(residual)),
+        };
+
+        iter.replace_none();
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), |iter| {
+            let mut candidate = (0, iter.size_hint().1);
+
+            if self.state.get_future().is_some() {
+                candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+            }
+
+            candidate
+        })
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for TryTakeWhileAsync<I, F>
+    where
+        I: AsyncIterator,
+        F: PredicateFn<I::Item> + ?Sized,
+        <F as PredicateFn<I::Item>>::Output: IntoFuture,
+        <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+        <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+);
+
+impl<I, F> FusedAsyncIterator for TryTakeWhileAsync<I, F>
+where
+    I: AsyncIterator,
+    F: PredicateFn<I::Item> + ?Sized,
+    <F as PredicateFn<I::Item>>::Output: IntoFuture,
+    <<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output: Try<Output = bool>,
+    <<<F as PredicateFn<I::Item>>::Output as IntoFuture>::Output as Try>::Residual: Residual<I::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{future, stream, StreamExt};
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_try_take_while_async() {
+        let iter = stream::iter(0..10).slim_try_take_while_async(|&x| future::ok::<_, u32>(x < 5));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Ok(3), Ok(4)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_take_while_async_error() {
+        let iter =
+            stream::iter(0..10).slim_try_take_while_async(|&x| future::ready(if x < 3 { Ok(true) } else { Err(x) }));
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_try_take_while_async_clone() {
+        let iter =
+            stream::iter(0..10).slim_try_take_while_async(|&x| future::ready(if x < 3 { Ok(true) } else { Err(x) }));
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [Ok(0), Ok(1), Ok(2), Err(3)]);
+    }
+}
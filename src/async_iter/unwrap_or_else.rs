@@ -0,0 +1,113 @@
+use crate::async_iter::map::Map;
+use crate::support::fns::UnwrapOrElseFn;
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+
+pin_project_lite::pin_project! {
+    pub struct UnwrapOrElse<I, F>
+    where
+        F: ?Sized,
+    {
+        #[pin]
+        inner: Map<I, UnwrapOrElseFn<F>>,
+    }
+}
+
+impl<I, F> UnwrapOrElse<I, F> {
+    pub const fn new(iter: I, f: F) -> Self {
+        Self {
+            inner: Map::new(iter, UnwrapOrElseFn::new(f)),
+        }
+    }
+}
+
+impl<I, F> Clone for UnwrapOrElse<I, F>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for UnwrapOrElse<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("UnwrapOrElse").field("inner", &self.inner).finish()
+    }
+}
+
+impl<I, F> AsyncIterator for UnwrapOrElse<I, F>
+where
+    I: ResultAsyncIterator,
+    F: FnMut<(I::Error,), Output = I::Ok> + ?Sized,
+{
+    type Item = I::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for UnwrapOrElse<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,), Output = I::Ok> + ?Sized,
+);
+
+impl<I, F> FusedAsyncIterator for UnwrapOrElse<I, F>
+where
+    I: ResultAsyncIterator + FusedAsyncIterator,
+    F: FnMut<(I::Error,), Output = I::Ok> + ?Sized,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<I, F> ExactSizeAsyncIterator for UnwrapOrElse<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,), Output = I::Ok> + ?Sized,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::{stream, StreamExt};
+    use std::vec::Vec;
+
+    fn unwrap_or_else_fn(x: u32) -> u32 {
+        x * 100
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)]).slim_unwrap_or_else(unwrap_or_else_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_clone() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)]).slim_unwrap_or_else(unwrap_or_else_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator, ResultAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct UnwrapOrElseAsync<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)>,
+        F: ?Sized,
+        F::Output: IntoFuture,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        state: Option<<F::Output as IntoFuture>::IntoFuture>,
+        f: F,
+    }
+}
+
+impl<I, F> UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator,
+    F: FnMut<(I::Error,)>,
+    F::Output: IntoFuture,
+{
+    pub(crate) const fn new(iter: I, f: F) -> Self {
+        Self { iter, state: None, f }
+    }
+}
+
+impl<I, F> Clone for UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + Clone,
+    F: FnMut<(I::Error,)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            state: self.state.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F> Debug for UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + Debug,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("UnwrapOrElseAsync")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F> AsyncIterator for UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture<Output = I::Ok>,
+{
+    type Item = I::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut iter = this.iter;
+        let state = this.state.pinned_entry();
+        let f = this.f;
+
+        let mut fut = match state {
+            OptionPinnedEntry::None(none_state) => match task::ready!(iter.as_mut().poll_next(cx)) {
+                None => return Poll::Ready(None),
+                Some(item) => match item {
+                    Ok(value) => return Poll::Ready(Some(value)),
+                    Err(error) => none_state.replace_some(f.call_mut((error,)).into_future()),
+                },
+            },
+            OptionPinnedEntry::Some(some_state) => some_state,
+        };
+
+        let item = task::ready!(fut.get_pin_mut().poll(cx));
+
+        fut.replace_none();
+
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut candidate = self.iter.size_hint();
+
+        if self.state.is_some() {
+            candidate.0 = candidate.0.saturating_add(1);
+            candidate.1 = candidate.1.and_then(|high| high.checked_add(1));
+        }
+
+        candidate
+    }
+}
+
+crate::support::impl_core_async_iterator!(
+    impl<I, F> for UnwrapOrElseAsync<I, F>
+    where
+        I: ResultAsyncIterator,
+        F: FnMut<(I::Error,)> + ?Sized,
+        F::Output: IntoFuture<Output = I::Ok>,
+);
+
+impl<I, F> FusedAsyncIterator for UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + FusedAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture<Output = I::Ok>,
+    <F::Output as IntoFuture>::IntoFuture: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.state
+            .as_ref()
+            .map_or_else(|| self.iter.is_terminated(), FusedFuture::is_terminated)
+    }
+}
+
+impl<I, F> ExactSizeAsyncIterator for UnwrapOrElseAsync<I, F>
+where
+    I: ResultAsyncIterator + ExactSizeAsyncIterator,
+    F: FnMut<(I::Error,)> + ?Sized,
+    F::Output: IntoFuture<Output = I::Ok>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
+    use futures_util::future::Ready;
+    use futures_util::{future, stream, StreamExt};
+    use std::vec::Vec;
+
+    fn unwrap_or_else_async_fn(x: u32) -> Ready<u32> {
+        future::ready(x * 100)
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_async() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)])
+            .slim_unwrap_or_else_async(unwrap_or_else_async_fn);
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_async_clone() {
+        let iter = stream::iter([Ok(2), Ok(3), Err(5), Err(7), Ok(11), Ok(13)])
+            .slim_unwrap_or_else_async(unwrap_or_else_async_fn);
+        let iter_2 = iter.clone();
+
+        assert_eq!(iter.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+        assert_eq!(iter_2.collect::<Vec<_>>().await, [2, 3, 500, 700, 11, 13]);
+    }
+}
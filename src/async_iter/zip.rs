@@ -1,4 +1,6 @@
-use crate::support::{AsyncIterator, FusedAsyncIterator};
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use crate::task::{SharedWaker, WakeMode};
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
 use three_states::{StateAProject, StateBProject, StateCProject, ThreeStates, ThreeStatesProject};
@@ -9,7 +11,7 @@ struct State<A, B> {
 }
 
 impl<A, B> State<A, B> {
-    fn project(&mut self) -> StateProject<A, B> {
+    fn project(&mut self) -> StateProject<'_, A, B> {
         match self.inner.project_mut() {
             ThreeStatesProject::A(inner) => StateProject::Empty(EmptyState { inner }),
             ThreeStatesProject::B(inner) => StateProject::Left(LeftState { inner }),
@@ -29,6 +31,20 @@ impl<A, B> Default for State<A, B> {
     }
 }
 
+impl<A, B> Debug for State<A, B>
+where
+    A: Debug,
+    B: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { .. } => f.write_str("Empty"),
+            ThreeStates::B { unpinned, .. } => f.debug_tuple("Left").field(unpinned).finish(),
+            ThreeStates::C { unpinned, .. } => f.debug_tuple("Right").field(unpinned).finish(),
+        }
+    }
+}
+
 struct EmptyState<'a, A, B> {
     inner: StateAProject<'a, (), (), (), A, (), B>,
 }
@@ -78,7 +94,7 @@ enum StateProject<'a, A, B> {
 }
 
 pin_project_lite::pin_project! {
-    pub struct Zip<A, B>
+    pub struct Zip<A, B, W = SharedWaker>
     where
         A: AsyncIterator,
         B: AsyncIterator,
@@ -88,43 +104,77 @@ pin_project_lite::pin_project! {
         #[pin]
         right: B,
         state: State<A::Item, B::Item>,
+        wake_mode: W,
     }
 }
 
-impl<A, B> Zip<A, B>
+impl<A, B, W> Zip<A, B, W>
 where
     A: AsyncIterator,
     B: AsyncIterator,
+    W: WakeMode,
 {
     pub(crate) fn new(left: A, right: B) -> Self {
         Self {
             left,
             right,
             state: State::default(),
+            wake_mode: W::INIT,
+        }
+    }
+
+    pub fn with_wake_mode<W2>(self) -> Zip<A, B, W2>
+    where
+        W2: WakeMode,
+    {
+        Zip {
+            left: self.left,
+            right: self.right,
+            state: self.state,
+            wake_mode: W2::INIT,
         }
     }
 }
 
-impl<A, B> Clone for Zip<A, B>
+impl<A, B, W> Clone for Zip<A, B, W>
 where
     A: AsyncIterator + Clone,
     B: AsyncIterator + Clone,
     A::Item: Clone,
     B::Item: Clone,
+    W: Clone,
 {
     fn clone(&self) -> Self {
         Self {
             left: self.left.clone(),
             right: self.right.clone(),
             state: self.state.clone(),
+            wake_mode: self.wake_mode.clone(),
         }
     }
 }
 
-impl<A, B> AsyncIterator for Zip<A, B>
+impl<A, B, W> Debug for Zip<A, B, W>
+where
+    A: AsyncIterator + Debug,
+    B: AsyncIterator + Debug,
+    A::Item: Debug,
+    B::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Zip")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, B, W> AsyncIterator for Zip<A, B, W>
 where
     A: AsyncIterator,
     B: AsyncIterator,
+    W: WakeMode,
 {
     type Item = (A::Item, B::Item);
 
@@ -133,34 +183,45 @@ where
         let mut left = this.left;
         let mut right = this.right;
         let mut state = this.state.project();
+        let wake_mode = this.wake_mode;
+
+        wake_mode.begin(cx);
 
         Poll::Ready(Some(loop {
             match state {
-                StateProject::Empty(empty_state) => match left.as_mut().poll_next(cx) {
-                    Poll::Ready(None) => return Poll::Ready(None),
-                    Poll::Ready(Some(item)) => state = StateProject::Left(empty_state.set_left(item)),
-                    Poll::Pending => match task::ready!(right.as_mut().poll_next(cx)) {
+                StateProject::Empty(empty_state) => {
+                    match wake_mode.poll_branch(cx, 0, |cx| left.as_mut().poll_next(cx)) {
+                        Poll::Ready(None) => return Poll::Ready(None),
+                        Poll::Ready(Some(item)) => state = StateProject::Left(empty_state.set_left(item)),
+                        Poll::Pending => {
+                            match task::ready!(wake_mode.poll_branch(cx, 1, |cx| right.as_mut().poll_next(cx))) {
+                                None => return Poll::Ready(None),
+                                Some(item) => state = StateProject::Right(empty_state.set_right(item)),
+                            }
+                        }
+                    }
+                }
+                StateProject::Left(left_state) => {
+                    match task::ready!(wake_mode.poll_branch(cx, 1, |cx| right.as_mut().poll_next(cx))) {
                         None => return Poll::Ready(None),
-                        Some(item) => state = StateProject::Right(empty_state.set_right(item)),
-                    },
-                },
-                StateProject::Left(left_state) => match task::ready!(right.poll_next(cx)) {
-                    None => return Poll::Ready(None),
-                    Some(right_item) => {
-                        let left_item = left_state.set_empty().1;
-
-                        break (left_item, right_item);
+                        Some(right_item) => {
+                            let left_item = left_state.set_empty().1;
+
+                            break (left_item, right_item);
+                        }
                     }
-                },
-                StateProject::Right(right_state) => match task::ready!(left.poll_next(cx)) {
-                    None => return Poll::Ready(None),
-                    Some(left_item) => {
-                        let right_item = right_state.set_empty().1;
+                }
+                StateProject::Right(right_state) => {
+                    match task::ready!(wake_mode.poll_branch(cx, 0, |cx| left.as_mut().poll_next(cx))) {
+                        None => return Poll::Ready(None),
+                        Some(left_item) => {
+                            let right_item = right_state.set_empty().1;
 
-                        break (left_item, right_item);
+                            break (left_item, right_item);
+                        }
                     }
-                },
-            };
+                }
+            }
         }))
     }
 
@@ -193,10 +254,19 @@ where
     }
 }
 
-impl<A, B> FusedAsyncIterator for Zip<A, B>
+crate::support::impl_core_async_iterator!(
+    impl<A, B, W> for Zip<A, B, W>
+    where
+        A: AsyncIterator,
+        B: AsyncIterator,
+        W: WakeMode,
+);
+
+impl<A, B, W> FusedAsyncIterator for Zip<A, B, W>
 where
     A: FusedAsyncIterator,
     B: FusedAsyncIterator,
+    W: WakeMode,
 {
     fn is_terminated(&self) -> bool {
         match self.state.inner {
@@ -207,6 +277,14 @@ where
     }
 }
 
+impl<A, B, W> ExactSizeAsyncIterator for Zip<A, B, W>
+where
+    A: ExactSizeAsyncIterator,
+    B: ExactSizeAsyncIterator,
+    W: WakeMode,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use crate::async_iter::async_iterator_ext::AsyncIteratorExt;
@@ -222,6 +300,48 @@ mod tests {
         assert_eq!(iter_2.collect::<Vec<_>>().await, [(10, 0), (11, 1), (12, 2)]);
     }
 
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn test_zip_per_branch_wakers() {
+        use crate::task::{PerBranchWakers, SharedWaker, WakeMode};
+        use crate::test_utilities::Yield;
+        use core::cell::Cell;
+        use core::pin::pin;
+        use core::task::{Context, Poll};
+        use futures_core::Stream;
+        use futures_util::task;
+
+        fn count_polls<W>(count: &Cell<usize>)
+        where
+            W: WakeMode,
+        {
+            let right = stream::poll_fn(|_| {
+                count.set(count.get() + 1);
+
+                Poll::<Option<u32>>::Pending
+            });
+
+            let mut iter = pin!(stream::once(Yield::new(3)).slim_zip(right).with_wake_mode::<W>());
+            let mut cx = Context::from_waker(task::noop_waker_ref());
+
+            for _ in 0..6 {
+                assert!(iter.as_mut().poll_next(&mut cx).is_pending());
+            }
+        }
+
+        let count = Cell::new(0);
+
+        count_polls::<SharedWaker>(&count);
+
+        assert_eq!(count.get(), 6);
+
+        count.set(0);
+
+        count_polls::<PerBranchWakers>(&count);
+
+        assert_eq!(count.get(), 1);
+    }
+
     #[tokio::test]
     async fn test_zip_clone() {
         let iter_1 = stream::iter(0..3).slim_zip(stream::iter(10..20));
@@ -0,0 +1,146 @@
+//! Checks that [`Debug`](core::fmt::Debug) output shows inner futures and async iterators, hides closures, and names
+//! the active state-machine phase.
+
+use crate::async_iter::AsyncIteratorExt;
+use crate::future::{self, FutureExt as _};
+use crate::test_utilities::{debug_string, Yield};
+use core::pin::pin;
+use futures_util::future::Either;
+use futures_util::{stream, StreamExt};
+
+#[tokio::test]
+async fn test_map_debug() {
+    let fut = futures_util::future::ready(2).slim_map(|x: u32| x + 3);
+
+    assert_eq!(debug_string(&fut), "Map { fut: Ready(Some(2)), .. }");
+}
+
+#[tokio::test]
+async fn test_wrapper_debug() {
+    let fut = futures_util::future::ready(Ok::<u32, u32>(2)).slim_and_then(|x: u32| Ok::<u32, u32>(x + 3));
+
+    assert_eq!(
+        debug_string(&fut),
+        "AndThen { inner: Map { fut: Ready(Some(Ok(2))), .. } }"
+    );
+}
+
+#[tokio::test]
+async fn test_map_once_debug() {
+    let mut fut = pin!(futures_util::future::ready(2).slim_map_once(|x: u32| x + 3));
+
+    assert_eq!(debug_string(&fut), "MapOnce { fut: Some(Ready(Some(2))), .. }");
+    assert_eq!(fut.as_mut().await, 5);
+    assert_eq!(debug_string(&fut), "MapOnce { fut: None, .. }");
+}
+
+#[tokio::test]
+async fn test_flatten_debug() {
+    let mut fut = pin!(futures_util::future::ready(Yield::new(1)).slim_flatten());
+
+    assert_eq!(
+        debug_string(&fut),
+        "Flatten { inner: First(Ready(Some(Yield { count: Some(1) }))) }"
+    );
+    assert!(futures_util::poll!(fut.as_mut()).is_pending());
+    assert_eq!(
+        debug_string(&fut),
+        "Flatten { inner: Second(Yield { count: Some(0) }) }"
+    );
+}
+
+#[tokio::test]
+async fn test_fold_async_debug() {
+    let mut fut = pin!(stream::iter([2, 3])
+        .slim_fold_async_by_copy(0, |acc: u32, x: u32| { Yield::new(1).slim_map(move |()| acc + x) }));
+
+    assert_eq!(
+        debug_string(&fut),
+        "FoldAsync { iter: Iter { iter: IntoIter([2, 3]) }, state: Accumulate(0), .. }"
+    );
+    assert!(futures_util::poll!(fut.as_mut()).is_pending());
+    assert_eq!(debug_string(&fut), "FoldAsync { iter: Iter { iter: IntoIter([3]) }, state: Future(Map { fut: Yield { count: Some(0) }, .. }), .. }");
+    assert_eq!(fut.as_mut().await, 5);
+    assert_eq!(
+        debug_string(&fut),
+        "FoldAsync { iter: Iter { iter: IntoIter([]) }, state: Done, .. }"
+    );
+}
+
+#[tokio::test]
+async fn test_filter_async_debug() {
+    let mut iter =
+        pin!(stream::iter([2, 3]).slim_filter_async(|&x: &u32| { Yield::new(1).slim_map(move |()| x % 2 == 0) }));
+
+    assert_eq!(
+        debug_string(&iter),
+        "FilterAsync { iter: Iter { iter: IntoIter([2, 3]) }, state: Empty, .. }"
+    );
+    assert!(futures_util::poll!(iter.next()).is_pending());
+    assert_eq!(debug_string(&iter), "FilterAsync { iter: Iter { iter: IntoIter([3]) }, state: Future { fut: Map { fut: Yield { count: Some(0) }, .. }, item: 2 }, .. }");
+}
+
+#[tokio::test]
+async fn test_reduce_async_debug() {
+    let mut fut =
+        pin!(stream::iter([2, 3]).slim_reduce_async(|acc: u32, x: u32| { Yield::new(1).slim_map(move |()| acc + x) }));
+
+    assert_eq!(
+        debug_string(&fut),
+        "ReduceAsync { iter: Iter { iter: IntoIter([2, 3]) }, state: Empty, .. }"
+    );
+    assert!(futures_util::poll!(fut.as_mut()).is_pending());
+    assert_eq!(debug_string(&fut), "ReduceAsync { iter: Iter { iter: IntoIter([]) }, state: Future(Map { fut: Yield { count: Some(0) }, .. }), .. }");
+}
+
+#[tokio::test]
+async fn test_join_debug() {
+    let mut fut = pin!(future::join(futures_util::future::ready(2), Yield::new(1)));
+
+    assert_eq!(
+        debug_string(&fut),
+        "Join { fut_1: Future(Ready(Some(2))), fut_2: Future(Yield { count: Some(1) }) }"
+    );
+    assert!(futures_util::poll!(fut.as_mut()).is_pending());
+    assert_eq!(
+        debug_string(&fut),
+        "Join { fut_1: Done(2), fut_2: Future(Yield { count: Some(0) }) }"
+    );
+}
+
+#[tokio::test]
+async fn test_select_ok_array_debug() {
+    let mut fut = pin!(future::select_ok_array([
+        Either::Left(futures_util::future::ready(Err::<u32, u32>(2))),
+        Either::Right(Yield::new(1).slim_map(|()| Ok(3))),
+    ]));
+
+    assert!(futures_util::poll!(fut.as_mut()).is_pending());
+    assert_eq!(
+        debug_string(&fut),
+        "SelectOkArray { futs: [Failed(2), Future(Right(Map { fut: Yield { count: Some(0) }, .. }))] }"
+    );
+}
+
+#[tokio::test]
+async fn test_zip_debug() {
+    let mut iter = pin!(stream::iter([2]).slim_zip(stream::once(Yield::new(1))));
+
+    assert!(futures_util::poll!(iter.next()).is_pending());
+    assert_eq!(debug_string(&iter), "Zip { left: Iter { iter: IntoIter([]) }, right: Once { future: Some(Yield { count: Some(0) }) }, state: Left(2), .. }");
+}
+
+#[tokio::test]
+async fn test_skip_while_debug() {
+    let mut iter = pin!(stream::iter([2, 3]).slim_skip_while(|&x: &u32| x < 3));
+
+    assert_eq!(
+        debug_string(&iter),
+        "SkipWhile { iter: Iter { iter: IntoIter([2, 3]) }, skipping: true, .. }"
+    );
+    assert_eq!(iter.next().await, Some(3));
+    assert_eq!(
+        debug_string(&iter),
+        "SkipWhile { iter: Iter { iter: IntoIter([]) }, skipping: false, .. }"
+    );
+}
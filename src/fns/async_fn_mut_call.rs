@@ -0,0 +1,25 @@
+use core::future::Future;
+
+/// Calls an `AsyncFnMut` closure through a `&'a mut` borrow. The type of the returned future cannot be named on stable
+/// Rust, so it is inferred from a zero-sized wrapper closure such as `|f: &'a mut F, item| f(item)`.
+pub trait AsyncFnMutCall<'a, F, A>
+where
+    F: 'a,
+{
+    type Future: Future;
+
+    fn call(&mut self, f: &'a mut F, args: A) -> Self::Future;
+}
+
+impl<'a, C, F, A, Fut> AsyncFnMutCall<'a, F, A> for C
+where
+    C: FnMut(&'a mut F, A) -> Fut,
+    F: 'a,
+    Fut: Future,
+{
+    type Future = Fut;
+
+    fn call(&mut self, f: &'a mut F, args: A) -> Self::Future {
+        self(f, args)
+    }
+}
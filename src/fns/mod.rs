@@ -0,0 +1,32 @@
+//! Nameable function objects.
+//!
+//! Closure types cannot be written out, so combinators built from closures can only be stored behind `impl Trait` or
+//! a box. Implementing [`FnMut`] (or [`FnOnce`] for the `_once` combinators) on a named type and passing it to a
+//! combinator constructor such as [`future::MapOk::new`](crate::future::MapOk::new) gives a combinator whose full
+//! type can be spelled out, for example `MapOk<Fut, AddOne>`.
+//!
+//! The traits are re-exported from [`fn_traits`], which implements them for every standard library closure, so
+//! ordinary closures and function items work too.
+
+pub use self::async_fn_mut_call::AsyncFnMutCall;
+pub use crate::support::fns::{
+    AndThenFn, InspectErrFn, InspectFn, InspectOkFn, MapErrFn, MapOkFn, OrElseFn, UnwrapOrElseFn,
+};
+pub use fn_traits::fns::{
+    compose, CloneFn, ComposeFn, ConvertIdentityFn, CopyFn, IntoFn, MemDropFn, MemTakeFn, OptionSomeFn, ResultErrFn,
+    ResultOkFn,
+};
+pub use fn_traits::{Fn, FnMut, FnOnce};
+
+mod async_fn_mut_call;
+
+#[cfg(test)]
+mod tests {
+    use super::MapOkFn;
+    use std::format;
+
+    #[test]
+    fn test_fn_object_debug() {
+        assert_eq!(format!("{:?}", MapOkFn::new(2)), "MapOkFn { f: 2 }");
+    }
+}
@@ -0,0 +1,1044 @@
+//! Polls every [`FusedFuture`](futures_core::FusedFuture) and [`FusedAsyncIterator`](crate::support::FusedAsyncIterator)
+//! implementation after completion.
+
+use crate::async_iter::AsyncIteratorExt;
+use crate::future::{self, FixedBackoff, FutureExt as _};
+use crate::task::AbortRegistration;
+use crate::test_utilities::{assert_fused_async_iter, assert_fused_future};
+use crate::time::{ManualClock, Timer};
+use core::array;
+use core::pin::pin;
+use core::time::Duration;
+use futures_util::future::{Either, Fuse, Pending, Ready};
+use futures_util::stream::{self, Iter};
+use futures_util::{FutureExt, StreamExt};
+
+fn ready<T>(value: T) -> Fuse<Ready<T>> {
+    futures_util::future::ready(value).fuse()
+}
+
+fn iter<T, const N: usize>(items: [T; N]) -> stream::Fuse<Iter<array::IntoIter<T, N>>> {
+    stream::iter(items).fuse()
+}
+
+struct PendingTimer;
+
+impl Timer for PendingTimer {
+    type Instant = Duration;
+    type Sleep = Fuse<Pending<()>>;
+
+    fn now(&self) -> Self::Instant {
+        Duration::ZERO
+    }
+
+    fn sleep_until(&self, _deadline: Self::Instant) -> Self::Sleep {
+        futures_util::future::pending().fuse()
+    }
+}
+
+fn plus_3(value: u32) -> u32 {
+    value + 3
+}
+
+fn plus_3_async(value: u32) -> Fuse<Ready<u32>> {
+    ready(value + 3)
+}
+
+// Futures.
+
+#[tokio::test]
+async fn test_abortable_fused_future() {
+    let registration = AbortRegistration::new();
+
+    assert_eq!(
+        assert_fused_future(ready(2).slim_abortable(&registration).0).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_and_then(|x| Ok::<_, u32>(x + 3))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_and_then_async(|x| ready(Ok::<_, u32>(x + 3)))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_async_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_and_then_async_once(|x| ready(Ok::<_, u32>(x + 3)))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_and_then_once(|x| Ok::<_, u32>(x + 3))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_on_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(2).slim_cancel_on(futures_util::future::pending().fuse())).await,
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn test_err_into_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_err_into::<u64>()).await,
+        Err(2)
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_fused_future() {
+    assert_eq!(assert_fused_future(ready(ready(2)).slim_flatten()).await, 2);
+}
+
+#[tokio::test]
+async fn test_flatten_async_iterator_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(ready(iter([2, 3])).slim_flatten_async_iterator()).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_fuse_fused_future() {
+    assert_eq!(assert_fused_future(future::ready_by_copy(2).slim_fuse()).await, 2);
+}
+
+#[tokio::test]
+async fn test_inspect_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_inspect(|_| {})).await, 2);
+}
+
+#[tokio::test]
+async fn test_inspect_err_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_inspect_err(|_| {})).await,
+        Err(2)
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_ok_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_inspect_ok(|_| {})).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_once_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_inspect_once(|_| {})).await, 2);
+}
+
+#[tokio::test]
+async fn test_into_try_future_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_into_option_future()).await, Some(2));
+}
+
+#[tokio::test]
+async fn test_join_array_fused_future() {
+    assert_eq!(
+        assert_fused_future(future::join_array([ready(2), ready(3)])).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_map_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_map(plus_3)).await, 5);
+}
+
+#[tokio::test]
+async fn test_map_async_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_map_async(plus_3_async)).await, 5);
+}
+
+#[tokio::test]
+async fn test_map_async_once_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_map_async_once(plus_3_async)).await, 5);
+}
+
+#[tokio::test]
+async fn test_map_err_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_map_err(plus_3)).await,
+        Err(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_map_err_async(plus_3_async)).await,
+        Err(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_map_err_once(plus_3)).await,
+        Err(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_into_fused_future() {
+    assert_eq!(assert_fused_future(ready(2_u32).slim_map_into::<u64>()).await, 2);
+}
+
+#[tokio::test]
+async fn test_map_ok_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_map_ok(plus_3)).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_map_ok_async(plus_3_async)).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_map_ok_once(plus_3)).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_or_else_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_map_ok_or_else(plus_3, plus_3)).await,
+        5
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_or_else_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_map_ok_or_else_async(plus_3_async, plus_3_async)).await,
+        5
+    );
+}
+
+#[tokio::test]
+async fn test_map_once_fused_future() {
+    assert_eq!(assert_fused_future(ready(2).slim_map_once(plus_3)).await, 5);
+}
+
+#[tokio::test]
+async fn test_ok_into_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_ok_into::<u64>()).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_or_else(|x| Ok::<_, u32>(x + 3))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_or_else_async(|x| ready(Ok::<_, u32>(x + 3)))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_or_else_once(|x| Ok::<_, u32>(x + 3))).await,
+        Ok(5)
+    );
+}
+
+#[tokio::test]
+async fn test_raw_map_ok_or_else_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<u32, u32>(2)).slim_raw_map_ok_or_else_async(plus_3_async, plus_3_async)).await,
+        5
+    );
+}
+
+#[tokio::test]
+async fn test_raw_select_fused_future() {
+    assert_eq!(assert_fused_future(future::raw_select(ready(2), ready(3))).await, 2);
+}
+
+#[tokio::test]
+async fn test_retry_fused_future() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_fused_future(future::retry(
+            || ready(Ok::<u32, u32>(2)),
+            FixedBackoff::new(&clock, Duration::ZERO, 0)
+        ))
+        .await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_select_array_fused_future() {
+    assert_eq!(
+        assert_fused_future(future::select_array([ready(2), ready(3)])).await,
+        (2, 0)
+    );
+}
+
+#[tokio::test]
+async fn test_select_either_fused_future() {
+    assert!(matches!(
+        assert_fused_future(future::select_either(ready(2), ready(3))).await,
+        Either::Left(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_select_keep_fused_future() {
+    assert!(matches!(
+        assert_fused_future(future::select_keep(ready(2), ready(3))).await,
+        Either::Left(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_select_ok_array_fused_future() {
+    assert_eq!(
+        assert_fused_future(future::select_ok_array([ready(Err::<u32, u32>(2)), ready(Ok(3))])).await,
+        Ok((3, 1))
+    );
+}
+
+#[tokio::test]
+async fn test_select_slice_fused_future() {
+    let mut fut_1 = pin!(ready(2));
+    let mut fut_2 = pin!(ready(3));
+    let mut futs = [fut_1.as_mut(), fut_2.as_mut()];
+
+    assert_eq!(assert_fused_future(future::select_slice(&mut futs)).await, (2, 0));
+}
+
+#[tokio::test]
+async fn test_timeout_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(2).slim_timeout(PendingTimer, Duration::from_secs(1))).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_try_cancel_on_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(2).slim_try_cancel_on(futures_util::future::pending().fuse())).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_try_flatten_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Ok::<_, u32>(ready(Ok::<u32, u32>(2)))).slim_try_flatten()).await,
+        Ok(2)
+    );
+}
+
+#[tokio::test]
+async fn test_try_flatten_err_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, _>(ready(Err::<u32, u32>(2)))).slim_try_flatten_err()).await,
+        Err(2)
+    );
+}
+
+#[tokio::test]
+async fn test_try_join_array_fused_future() {
+    assert_eq!(
+        assert_fused_future(future::try_join_array([ready(Ok::<u32, u32>(2)), ready(Ok(3))])).await,
+        Ok([2, 3])
+    );
+}
+
+#[tokio::test]
+async fn test_try_select_either_fused_future() {
+    assert!(assert_fused_future(future::try_select_either(
+        ready(Ok::<u32, u32>(2)),
+        ready(Ok::<u32, u32>(3))
+    ))
+    .await
+    .is_ok());
+}
+
+#[tokio::test]
+async fn test_try_select_keep_fused_future() {
+    assert!(assert_fused_future(future::try_select_keep(
+        ready(Ok::<u32, u32>(2)),
+        ready(Ok::<u32, u32>(3))
+    ))
+    .await
+    .is_ok());
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_unwrap_or_else(plus_3)).await,
+        5
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_unwrap_or_else_async(plus_3_async)).await,
+        5
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_once_fused_future() {
+    assert_eq!(
+        assert_fused_future(ready(Err::<u32, u32>(2)).slim_unwrap_or_else_once(plus_3)).await,
+        5
+    );
+}
+
+// Futures produced by async iterators.
+
+#[tokio::test]
+async fn test_all_async_fused_future() {
+    assert!(!assert_fused_future(iter([2_u32, 3, 5]).slim_all_async(|x| ready(x.is_multiple_of(2)))).await);
+}
+
+#[tokio::test]
+async fn test_any_async_fused_future() {
+    assert!(assert_fused_future(iter([2_u32, 3, 5]).slim_any_async(|x| ready(x.is_multiple_of(2)))).await);
+}
+
+#[tokio::test]
+async fn test_fold_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_fold_by_copy(0, |acc, x| acc + x)).await,
+        10
+    );
+}
+
+#[tokio::test]
+async fn test_fold_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_fold_async_by_copy(0, |acc, x| ready(acc + x))).await,
+        10
+    );
+}
+
+#[tokio::test]
+async fn test_fold_async_fn_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_fold_async_fn(0, async |acc, x| acc + x)).await,
+        10
+    );
+}
+
+#[tokio::test]
+async fn test_for_each_fused_future() {
+    assert_fused_future(iter([2_u32, 3, 5]).slim_for_each(drop)).await;
+}
+
+#[tokio::test]
+async fn test_for_each_async_fused_future() {
+    assert_fused_future(iter([2_u32, 3, 5]).slim_for_each_async(|_| ready(()))).await;
+}
+
+#[tokio::test]
+async fn test_reduce_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_reduce(|acc, x| acc + x)).await,
+        Some(10)
+    );
+}
+
+#[tokio::test]
+async fn test_reduce_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_reduce_async(|acc, x| ready(acc + x))).await,
+        Some(10)
+    );
+}
+
+#[tokio::test]
+async fn test_try_all_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_all_async(|x| ready(Ok::<_, u32>(x < 5)))).await,
+        Ok(false)
+    );
+}
+
+#[tokio::test]
+async fn test_try_any_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_any_async(|x| ready(Ok::<_, u32>(x < 5)))).await,
+        Ok(true)
+    );
+}
+
+#[tokio::test]
+async fn test_try_find_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_find_async(|&x| ready(Ok::<_, u32>(x > 2)))).await,
+        Ok(Some(3))
+    );
+
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_find_async(|&x| ready(Ok::<_, u32>(x > 5)))).await,
+        Ok(None)
+    );
+
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_find_async(|&x| ready(if x < 3 {
+            Ok(false)
+        } else {
+            Err(x)
+        })))
+        .await,
+        Err(3)
+    );
+}
+
+#[tokio::test]
+async fn test_try_find_map_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_find_map_async(|x| ready(Ok::<_, u32>((x > 2).then_some(x)))))
+            .await,
+        Ok(Some(3))
+    );
+}
+
+#[tokio::test]
+async fn test_try_fold_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_fold_async_by_copy(0, |acc, x| ready(Ok::<_, u32>(acc + x))))
+            .await,
+        Ok(10)
+    );
+
+    assert_eq!(
+        assert_fused_future(
+            iter([2_u32, 3, 5]).slim_try_fold_async_by_copy(0, |acc, x| ready(if x < 3 {
+                Ok(acc + x)
+            } else {
+                Err(x)
+            }))
+        )
+        .await,
+        Err(3)
+    );
+}
+
+#[tokio::test]
+async fn test_try_fold_async_fn_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_fold_async_fn(0, async |acc, x| Ok::<_, u32>(acc + x))).await,
+        Ok(10_u32)
+    );
+
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_fold_async_fn(0, async |acc, x| if x < 3 {
+            Ok(acc + x)
+        } else {
+            Err(x)
+        }))
+        .await,
+        Err(3_u32)
+    );
+}
+
+#[tokio::test]
+async fn test_try_for_each_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_for_each_async(|x| ready(if x < 3 {
+            Ok(())
+        } else {
+            Err(x)
+        })))
+        .await,
+        Err(3)
+    );
+}
+
+#[tokio::test]
+async fn test_try_position_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_position_async(|x| ready(Ok::<_, u32>(x == 3)))).await,
+        Ok(Some(1))
+    );
+}
+
+#[tokio::test]
+async fn test_try_reduce_async_fused_future() {
+    assert_eq!(
+        assert_fused_future(iter([2_u32, 3, 5]).slim_try_reduce_async(|acc, x| ready(Ok::<_, u32>(acc + x)))).await,
+        Ok(Some(10))
+    );
+}
+
+// Async iterators.
+
+#[tokio::test]
+async fn test_abortable_fused_async_iter() {
+    let registration = AbortRegistration::new();
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_abortable(&registration).0).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_and_then(|x| Ok::<_, u32>(x + 3))).await,
+        [Ok(5), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_and_then_async(|x| ready(Ok::<_, u32>(x + 3))))
+            .await,
+        [Ok(5), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_debounce_fused_async_iter() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_debounce(&clock, Duration::from_secs(1))).await,
+        [5]
+    );
+}
+
+#[tokio::test]
+async fn test_err_into_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_err_into::<u64>()).await,
+        [Ok(2), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_filter(|&x| x.is_multiple_of(2))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_filter_async(|&x| ready(x.is_multiple_of(2)))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_err_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_filter_err()).await,
+        [3]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_map_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_filter_map(|x| x.is_multiple_of(2).then_some(x))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_map_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_filter_map_async(|x| ready(x.is_multiple_of(2).then_some(x))))
+            .await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_ok_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_filter_ok()).await,
+        [2]
+    );
+}
+
+#[tokio::test]
+async fn test_flat_map_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2, 3]).slim_flat_map(|x| iter([x, x]))).await,
+        [2, 2, 3, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_flat_map_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2, 3]).slim_flat_map_async(|x| ready(iter([x, x])))).await,
+        [2, 2, 3, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([iter([2, 3]), iter([5, 7])]).slim_flatten()).await,
+        [2, 3, 5, 7]
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_ok_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<_, u32>(iter([2, 3])), Err(5)]).slim_flatten_ok()).await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_fuse_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(stream::iter([2_u32, 3, 5]).slim_fuse()).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_inspect(|_| {})).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_err_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_inspect_err(|_| {})).await,
+        [Ok(2), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_ok_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_inspect_ok(|_| {})).await,
+        [Ok(2), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_fused_async_iter() {
+    assert_eq!(assert_fused_async_iter(iter([2, 3]).slim_map(plus_3)).await, [5, 6]);
+}
+
+#[tokio::test]
+async fn test_map_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2, 3]).slim_map_async(plus_3_async)).await,
+        [5, 6]
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_map_err(plus_3)).await,
+        [Ok(2), Err(6)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_map_err_async(plus_3_async)).await,
+        [Ok(2), Err(6)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_map_ok(plus_3)).await,
+        [Ok(5), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_map_ok_async(plus_3_async)).await,
+        [Ok(5), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_ok_into_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_ok_into::<u64>()).await,
+        [Ok(2), Err(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_or_else(|x| Ok::<_, u32>(x + 3))).await,
+        [Ok(2), Ok(6)]
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_or_else_async(|x| ready(Ok::<_, u32>(x + 3))))
+            .await,
+        [Ok(2), Ok(6)]
+    );
+}
+
+#[tokio::test]
+async fn test_sample_fused_async_iter() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_sample(&clock, Duration::from_secs(1))).await,
+        [5]
+    );
+}
+
+#[tokio::test]
+async fn test_skip_while_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_skip_while(|&x| x.is_multiple_of(2))).await,
+        [3, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_skip_while_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_skip_while_async(|&x| ready(x.is_multiple_of(2)))).await,
+        [3, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_take_while_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_take_while_async(|&x| ready(x.is_multiple_of(2)))).await,
+        [2]
+    );
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 4]).slim_take_while_async(|&x| ready(x.is_multiple_of(2)))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_take_while_async_fn_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 4]).slim_take_while_async_fn(async |&x: &u32| x.is_multiple_of(2)))
+            .await,
+        [2]
+    );
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 4]).slim_take_while_async_fn(async |&x: &u32| x.is_multiple_of(2))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_throttle_fused_async_iter() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_throttle(&clock, Duration::ZERO)).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_timeout_fused_async_iter() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_fused_async_iter(iter([2, 3]).slim_timeout(&clock, Duration::from_secs(1))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_flatten_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<_, u32>(iter([Ok::<u32, u32>(2), Ok(3)])), Err(5)]).slim_try_flatten())
+            .await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_map_while_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_map_while(|x| Ok::<_, u32>((x < 5).then_some(x)))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_map_while_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(
+            iter([2_u32, 3, 5]).slim_try_map_while_async(|x| ready(Ok::<_, u32>((x < 5).then_some(x))))
+        )
+        .await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_scan_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_scan(0, |acc, x| {
+            *acc += x;
+
+            Ok::<_, u32>(Some(*acc))
+        }))
+        .await,
+        [Ok(2), Ok(5), Ok(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_scan_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_scan_async(0, |acc, x| {
+            *acc += x;
+
+            ready(Ok::<_, u32>(Some(*acc)))
+        }))
+        .await,
+        [Ok(2), Ok(5), Ok(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_skip_while_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_skip_while(|&x| Ok::<_, u32>(x < 5))).await,
+        [Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_skip_while_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_skip_while_async(|&x| ready(Ok::<_, u32>(x < 5)))).await,
+        [Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_skip_while_async_fn_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_skip_while_async_fn(async |&x: &u32| Ok::<_, u32>(x < 5)))
+            .await,
+        [Ok(5_u32)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_take_while_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_take_while(|&x| Ok::<_, u32>(x < 5))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_take_while_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_take_while_async(|&x| ready(Ok::<_, u32>(x < 5)))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_take_while_async_fn_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_try_take_while_async_fn(async |&x: &u32| Ok::<_, u32>(x < 5)))
+            .await,
+        [Ok(2_u32), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_unwrap_or_else(plus_3)).await,
+        [2, 6]
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_async_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([Ok::<u32, u32>(2), Err(3)]).slim_unwrap_or_else_async(plus_3_async)).await,
+        [2, 6]
+    );
+}
+
+#[tokio::test]
+async fn test_zip_fused_async_iter() {
+    assert_eq!(
+        assert_fused_async_iter(iter([2_u32, 3, 5]).slim_zip(iter([7, 11]))).await,
+        [(2, 7), (3, 11)]
+    );
+}
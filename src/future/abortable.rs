@@ -0,0 +1,145 @@
+use crate::task::{AbortRegistration, Aborted};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::Deref;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    pub struct Abortable<Fut, R> {
+        #[pin]
+        fut: Fut,
+        registration: R,
+    }
+}
+
+impl<Fut, R> Abortable<Fut, R> {
+    pub(crate) const fn new(fut: Fut, registration: R) -> Self {
+        Self { fut, registration }
+    }
+}
+
+impl<Fut, R> Debug for Abortable<Fut, R>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Abortable")
+            .field("fut", &self.fut)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Fut, R> Future for Abortable<Fut, R>
+where
+    Fut: Future,
+    R: Deref<Target = AbortRegistration>,
+{
+    type Output = Result<Fut::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if this.registration.is_aborted() {
+            return Poll::Ready(Err(Aborted));
+        }
+
+        if let Poll::Ready(output) = this.fut.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        if this.registration.register(cx) {
+            Poll::Ready(Err(Aborted))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut, R> FusedFuture for Abortable<Fut, R>
+where
+    Fut: FusedFuture,
+    R: Deref<Target = AbortRegistration>,
+{
+    fn is_terminated(&self) -> bool {
+        self.registration.is_aborted() || self.fut.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use crate::task::{AbortRegistration, Aborted};
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt as _};
+
+    #[tokio::test]
+    async fn test_abortable() {
+        let registration = AbortRegistration::new();
+        let (future, handle) = ready::ready_by_copy(2).slim_abortable(&registration);
+
+        assert!(!handle.is_aborted());
+        assert_eq!(future.await, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn test_abortable_abort() {
+        static REGISTRATION: AbortRegistration = AbortRegistration::new();
+
+        let (mut future, handle) = future::pending::<u32>().slim_abortable(&REGISTRATION);
+
+        assert_eq!((&mut future).now_or_never(), None);
+
+        handle.abort();
+
+        assert!(handle.is_aborted());
+        assert_eq!(future.await, Err(Aborted));
+
+        REGISTRATION.reset();
+
+        let (future, _) = test_utilities::delayed(ready::ready_by_copy(2)).slim_abortable(&REGISTRATION);
+
+        assert_eq!(future.await, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn test_abortable_abort_wakes_task() {
+        let registration = AbortRegistration::new();
+        let (future, handle) = future::pending::<u32>().slim_abortable(&registration);
+
+        let abort = async {
+            test_utilities::Yield::new(1).await;
+
+            handle.abort();
+        };
+
+        assert_eq!(future::join(future, abort).await, (Err(Aborted), ()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn test_abortable_arc() {
+        let (future, handle) = future::pending::<u32>().slim_abortable_arc();
+        let handle_2 = handle.clone();
+
+        handle_2.abort();
+
+        assert!(handle.is_aborted());
+        assert_eq!(future.await, Err(Aborted));
+    }
+
+    #[tokio::test]
+    async fn test_abortable_fused_future() {
+        let registration = AbortRegistration::new();
+        let (future, handle) = future::pending::<u32>().fuse().slim_abortable(&registration);
+
+        assert!(!future.is_terminated());
+
+        handle.abort();
+
+        assert!(future.is_terminated());
+    }
+}
@@ -1,21 +1,45 @@
 use crate::future::map::Map;
 use crate::future::try_flatten::TryFlatten;
 use crate::support::{FromResidual, RawResidual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use fn_traits::FnMut;
+use fn_traits::{FnMut, FnOnce};
 use futures_core::FusedFuture;
 
 #[derive(Clone)]
-struct AndThenAsyncFn<F>
+pub struct AndThenAsyncFn<F>
 where
     F: ?Sized,
 {
     f: F,
 }
 
+impl<F> AndThenAsyncFn<F> {
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<T, F> FnOnce<(T,)> for AndThenAsyncFn<F>
+where
+    T: Try,
+    F: FnOnce<(T::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<T::Residual>,
+{
+    type Output = RawResidual<T::Residual, F::Output>;
+
+    fn call_once(self, args: (T,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(output) => RawResidual::from_output(self.f.call_once((output,))),
+            ControlFlow::Break(residual) => RawResidual::from_residual(residual),
+        }
+    }
+}
+
 impl<T, F> FnMut<(T,)> for AndThenAsyncFn<F>
 where
     T: Try,
@@ -55,9 +79,9 @@ where
     F::Output: IntoFuture,
     <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
 {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
         Self {
-            inner: TryFlatten::new(Map::new(fut, AndThenAsyncFn { f })),
+            inner: TryFlatten::new(Map::new(fut, AndThenAsyncFn::new(f))),
         }
     }
 }
@@ -78,6 +102,20 @@ where
     }
 }
 
+impl<Fut, F> Debug for AndThenAsync<Fut, F>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    F: FnMut<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AndThenAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for AndThenAsync<Fut, F>
 where
     Fut: Future,
@@ -113,7 +151,6 @@ mod tests {
     use crate::future::{err, ok};
     use futures_core::FusedFuture;
     use futures_util::future::{Ready, TryFutureExt};
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn ok_plus_3(value: u32) -> Ready<Result<u32, u32>> {
@@ -179,8 +216,8 @@ mod tests {
         let future_1 = make_base_future().slim_and_then_async(ok::ok_by_copy::<_, u32>);
         let future_2 = make_base_future().and_then(ok::ok_by_copy);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Ok(()));
         assert_eq!(future_1.await, Ok(()));
         assert_eq!(future_2.await, Ok(()));
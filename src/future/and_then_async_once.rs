@@ -0,0 +1,170 @@
+use crate::future::and_then_async::AndThenAsyncFn;
+use crate::future::map_once::MapOnce;
+use crate::future::try_flatten::TryFlatten;
+use crate::support::{FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    pub struct AndThenAsyncOnce<Fut, F>
+    where
+        Fut: Future,
+        Fut::Output: Try,
+        F: FnOnce<(<Fut::Output as Try>::Output,)>,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+    {
+        #[pin]
+        inner: TryFlatten<MapOnce<Fut, AndThenAsyncFn<F>>>
+    }
+}
+
+impl<Fut, F> AndThenAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+{
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: TryFlatten::new(MapOnce::new(fut, AndThenAsyncFn::new(f))),
+        }
+    }
+}
+
+impl<Fut, F> Clone for AndThenAsyncOnce<Fut, F>
+where
+    Fut: Future + Clone,
+    Fut::Output: Try,
+    F: FnOnce<(<Fut::Output as Try>::Output,)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut, F> Debug for AndThenAsyncOnce<Fut, F>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AndThenAsyncOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for AndThenAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+{
+    type Output = <F::Output as IntoFuture>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for AndThenAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: FromResidual<<Fut::Output as Try>::Residual>,
+    <F::Output as IntoFuture>::IntoFuture: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::{err, ok};
+    use futures_core::FusedFuture;
+    use futures_util::future::TryFutureExt;
+    use std::num::NonZeroU32;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_and_then_async_once() {
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            ok::ok_by_copy::<_, u32>(2)
+                .slim_and_then_async_once(move |value| futures_util::future::ok::<_, u32>((value, suffix)))
+                .await,
+            Ok((2, String::from("abc"))),
+        );
+
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            err::err_by_copy::<u32, u32>(2)
+                .slim_and_then_async_once(move |value| futures_util::future::ok::<_, u32>((value, suffix)))
+                .await,
+            Err(2),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async_once_with_option() {
+        assert_eq!(
+            futures_util::future::ready(Some(2))
+                .slim_and_then_async_once(|x| futures_util::future::ready(Some(x + 3)))
+                .await,
+            Some(5),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async_once_clone() {
+        let future = ok::ok_by_copy::<u32, u32>(2)
+            .slim_and_then_async_once(|value| futures_util::future::ok::<_, u32>(value + 3));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(5));
+        assert_eq!(future_2.await, Ok(5));
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async_once_fused_future() {
+        let mut future = futures_util::future::ok::<u32, u32>(2)
+            .slim_and_then_async_once(|value| futures_util::future::ok::<_, u32>(value + 3));
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.by_ref().await, Ok(5));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_and_then_async_once_is_slim() {
+        let make_base_future = || ok::ok_by_copy::<_, u32>(NonZeroU32::new(2).unwrap()).slim_map_ok(drop);
+        let future_1 = make_base_future().slim_and_then_async_once(ok::ok_by_copy::<_, u32>);
+        let future_2 = make_base_future().and_then(ok::ok_by_copy);
+
+        assert!(size_of_val(&future_1) <= size_of_val(&future_2));
+        assert_eq!(future_1.await, Ok(()));
+        assert_eq!(future_2.await, Ok(()));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::future::map::Map;
+use crate::future::select_either::SelectEither;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+use futures_util::future::Either;
+
+#[derive(Clone)]
+struct CancelOnFn;
+
+impl<T> FnMut<(Either<T, ()>,)> for CancelOnFn {
+    type Output = Option<T>;
+
+    fn call_mut(&mut self, args: (Either<T, ()>,)) -> Self::Output {
+        match args.0 {
+            Either::Left(output) => Some(output),
+            Either::Right(()) => None,
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct CancelOn<Fut, S>
+    where
+        Fut: Future,
+        S: Future,
+    {
+        #[pin]
+        inner: Map<SelectEither<Fut, S>, CancelOnFn>,
+    }
+}
+
+impl<Fut, S> CancelOn<Fut, S>
+where
+    Fut: Future,
+    S: Future,
+{
+    pub(crate) fn new(fut: Fut, signal: S) -> Self {
+        Self {
+            inner: Map::new(SelectEither::new(fut, signal), CancelOnFn),
+        }
+    }
+}
+
+impl<Fut, S> Clone for CancelOn<Fut, S>
+where
+    Fut: Future + Clone,
+    S: Future + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut, S> Debug for CancelOn<Fut, S>
+where
+    Fut: Future + Debug,
+    S: Future + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("CancelOn").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, S> Future for CancelOn<Fut, S>
+where
+    Fut: Future,
+    S: Future<Output = ()>,
+{
+    type Output = Option<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, S> FusedFuture for CancelOn<Fut, S>
+where
+    Fut: FusedFuture,
+    S: FusedFuture<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt as _};
+
+    #[tokio::test]
+    async fn test_cancel_on() {
+        assert_eq!(ready::ready_by_copy(2).slim_cancel_on(future::pending()).await, Some(2));
+        assert_eq!(
+            future::pending::<u32>().slim_cancel_on(ready::ready_by_copy(())).await,
+            None
+        );
+
+        assert_eq!(
+            test_utilities::delayed(ready::ready_by_copy(2))
+                .slim_cancel_on(ready::ready_by_copy(()))
+                .await,
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_prefers_output() {
+        assert_eq!(
+            ready::ready_by_copy(2).slim_cancel_on(ready::ready_by_copy(())).await,
+            Some(2),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_clone() {
+        let future = ready::ready_by_copy(2).slim_cancel_on(future::pending());
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Some(2));
+        assert_eq!(future_2.await, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_on_fused_future() {
+        let mut future = future::pending::<u32>().fuse().slim_cancel_on(future::ready(()).fuse());
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, None);
+        assert!(future.is_terminated());
+    }
+}
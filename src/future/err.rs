@@ -1,11 +1,13 @@
 use crate::future::map::Map;
 use crate::future::ready::Ready;
 use crate::support;
+use crate::support::fns::ResultErrFn;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use fn_traits::fns::{CloneFn, CopyFn, MemTakeFn, ResultErrFn};
+use fn_traits::fns::{CloneFn, CopyFn, MemTakeFn};
 use fn_traits::FnMut;
 
 pin_project_lite::pin_project! {
@@ -16,9 +18,9 @@ pin_project_lite::pin_project! {
 }
 
 impl<G, T, E> Err<G, T, E> {
-    fn new(getter: G, error: E) -> Self {
+    const fn new(getter: G, error: E) -> Self {
         Self {
-            inner: Map::new(Ready::new(getter, error), ResultErrFn::default()),
+            inner: Map::new(Ready::new(getter, error), ResultErrFn::new()),
         }
     }
 }
@@ -35,6 +37,15 @@ where
     }
 }
 
+impl<G, T, E> Debug for Err<G, T, E>
+where
+    E: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Err").field("inner", &self.inner).finish()
+    }
+}
+
 impl<G, T, E> Future for Err<G, T, E>
 where
     G: for<'a> FnMut<(&'a mut E,), Output = E>,
@@ -46,7 +57,7 @@ where
     }
 }
 
-pub fn err_by<G, T, E>(getter: G, error: E) -> Err<G, T, E>
+pub const fn err_by<G, T, E>(getter: G, error: E) -> Err<G, T, E>
 where
     G: ops::FnMut(&mut E) -> E,
 {
@@ -77,13 +88,23 @@ where
 #[cfg(test)]
 mod tests {
     use crate::future::err;
-    use std::mem;
 
     #[tokio::test]
     async fn test_err() {
         assert_eq!(err::err_by_copy::<u32, u32>(7).await, Err(7));
     }
 
+    #[tokio::test]
+    async fn test_err_by_in_const() {
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: err::Err<fn(&mut u32) -> u32, u32, u32> = err::err_by(get, 2);
+
+        assert_eq!(FUTURE.await, Err(2));
+    }
+
     #[tokio::test]
     async fn test_err_clone() {
         let future = err::err_by_copy::<u32, u32>(7);
@@ -99,8 +120,8 @@ mod tests {
         let future_1 = err::err_by_copy::<u32, _>(value);
         let future_2 = futures_util::future::err::<u32, _>(value);
 
-        assert_eq!(mem::size_of_val(&value), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&value), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(future_1.await, Err(value));
         assert_eq!(future_2.await, Err(value));
     }
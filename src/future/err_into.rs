@@ -1,5 +1,6 @@
 use crate::future::map_err::MapErr;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -32,6 +33,15 @@ where
     }
 }
 
+impl<Fut, T> Debug for ErrInto<Fut, T>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ErrInto").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, T> Future for ErrInto<Fut, T>
 where
     Fut: ResultFuture,
@@ -60,7 +70,6 @@ mod tests {
     use crate::future::future_ext::FutureExt;
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
 
     #[tokio::test]
     async fn test_err_into() {
@@ -97,8 +106,8 @@ mod tests {
         let future_1 = make_base_future().slim_err_into::<Option<_>>();
         let future_2 = make_base_future().err_into::<Option<_>>();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Err(2));
         assert_eq!(future_1.await, Err(Some(2)));
         assert_eq!(future_2.await, Err(Some(2)));
@@ -1,4 +1,5 @@
 use crate::support::states::TwoPhases;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -21,7 +22,7 @@ where
     Fut: Future,
     Fut::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut) -> Self {
+    pub(crate) const fn new(fut: Fut) -> Self {
         Self {
             inner: TwoPhases::new(fut),
         }
@@ -41,6 +42,17 @@ where
     }
 }
 
+impl<Fut> Debug for Flatten<Fut>
+where
+    Fut: Future + Debug,
+    Fut::Output: IntoFuture,
+    <Fut::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Flatten").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut> Future for Flatten<Fut>
 where
     Fut: Future,
@@ -80,7 +92,6 @@ mod tests {
     use crate::test_utilities::Yield;
     use futures_core::FusedFuture;
     use futures_util::{future, FutureExt as _};
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn make_flatten_future() -> impl FusedFuture<Output = u32> + Clone {
@@ -119,8 +130,8 @@ mod tests {
         let future_1 = make_base_future().slim_flatten();
         let future_2 = make_base_future().flatten();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert!(matches!(base_future.await.await, ()));
         assert!(matches!(future_1.await, ()));
         assert!(matches!(future_2.await, ()));
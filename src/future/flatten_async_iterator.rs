@@ -1,5 +1,6 @@
 use crate::support::states::TwoPhases;
 use crate::support::{AsyncIterator, FusedAsyncIterator, IntoAsyncIterator};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -22,7 +23,7 @@ where
     Fut: Future,
     Fut::Output: IntoAsyncIterator,
 {
-    pub(crate) fn new(fut: Fut) -> Self {
+    pub(crate) const fn new(fut: Fut) -> Self {
         Self {
             inner: TwoPhases::new(fut),
         }
@@ -42,6 +43,19 @@ where
     }
 }
 
+impl<Fut> Debug for FlattenAsyncIterator<Fut>
+where
+    Fut: Future + Debug,
+    Fut::Output: IntoAsyncIterator,
+    <Fut::Output as IntoAsyncIterator>::IntoAsyncIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("FlattenAsyncIterator")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl<Fut> AsyncIterator for FlattenAsyncIterator<Fut>
 where
     Fut: Future,
@@ -71,6 +85,13 @@ where
     }
 }
 
+crate::support::impl_core_async_iterator!(
+    impl<Fut> for FlattenAsyncIterator<Fut>
+    where
+        Fut: Future,
+        Fut::Output: IntoAsyncIterator,
+);
+
 impl<Fut> FusedAsyncIterator for FlattenAsyncIterator<Fut>
 where
     Fut: FusedFuture,
@@ -87,7 +108,6 @@ mod tests {
     use crate::future::ready;
     use crate::support::FusedAsyncIterator;
     use futures_util::{future, stream, FutureExt as _, StreamExt};
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn make_flatten_async_iterator() -> impl FusedAsyncIterator<Item = u32> {
@@ -122,8 +142,8 @@ mod tests {
         let future_1 = make_base_future().slim_flatten();
         let future_2 = make_base_future().flatten();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert!(matches!(base_future.await.await, ()));
         assert!(matches!(future_1.await, ()));
         assert!(matches!(future_2.await, ()));
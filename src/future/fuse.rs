@@ -0,0 +1,165 @@
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use futures_core::FusedFuture;
+use option_entry::{OptionEntryExt, OptionPinnedEntry};
+
+pin_project_lite::pin_project! {
+    pub struct Fuse<Fut> {
+        #[pin]
+        fut: Option<Fut>,
+    }
+}
+
+impl<Fut> Fuse<Fut> {
+    pub const fn new(fut: Fut) -> Self {
+        Self { fut: Some(fut) }
+    }
+}
+
+impl<Fut> Clone for Fuse<Fut>
+where
+    Fut: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { fut: self.fut.clone() }
+    }
+}
+
+impl<Fut> Debug for Fuse<Fut>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Fuse").field("fut", &self.fut).finish()
+    }
+}
+
+impl<Fut> Future for Fuse<Fut>
+where
+    Fut: Future,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        if let OptionPinnedEntry::Some(mut fut) = self.project().fut.pinned_entry() {
+            let output = task::ready!(fut.get_pin_mut().poll(cx));
+
+            fut.replace_none();
+
+            Poll::Ready(output)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut> FusedFuture for Fuse<Fut>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.fut.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::{self, ready, Fuse, Lazy, Map};
+    use crate::test_utilities;
+    use core::cell::Cell;
+    use core::task::Context;
+    use futures_core::FusedFuture;
+    use futures_util::FutureExt as _;
+    use std::num::NonZeroU32;
+
+    #[tokio::test]
+    async fn test_fuse() {
+        let mut future = future::lazy(|_| 2).slim_fuse();
+
+        assert_eq!((&mut future).now_or_never(), Some(2));
+        assert_eq!((&mut future).now_or_never(), None);
+
+        assert_eq!(test_utilities::delayed(ready::ready_by_copy(3)).slim_fuse().await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_fuse_in_const() {
+        fn lazy_fn(_: &mut Context) -> u32 {
+            2
+        }
+
+        fn plus_3(value: u32) -> u32 {
+            value + 3
+        }
+
+        type ConstFuture = Fuse<Map<Lazy<fn(&mut Context) -> u32>, fn(u32) -> u32>>;
+
+        const FUTURE: ConstFuture = Fuse::new(Map::new(future::lazy(lazy_fn), plus_3));
+
+        let mut future = FUTURE;
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, 5);
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_fuse_drops_inner_future_after_completion() {
+        struct DropCounter<'a>(&'a Cell<u32>);
+
+        impl DropCounter<'_> {
+            fn get(&self) -> u32 {
+                self.0.get()
+            }
+        }
+
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drop_count = Cell::new(0);
+        let counter = DropCounter(&drop_count);
+        let mut future = future::lazy(move |_| counter.get()).slim_fuse();
+
+        assert_eq!(drop_count.get(), 0);
+        assert_eq!((&mut future).now_or_never(), Some(0));
+        assert_eq!(drop_count.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fuse_clone() {
+        let future = ready::ready_by_copy(2).slim_fuse();
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, 2);
+        assert_eq!(future_2.await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_fuse_fused_future() {
+        let mut future = ready::ready_by_copy(2).slim_fuse();
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, 2);
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_fuse_is_slim() {
+        let make_base_future = || ready::ready_by_copy(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future_1 = make_base_future().slim_fuse();
+        let future_2 = make_base_future().fuse();
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) <= size_of_val(&future_2));
+        assert_eq!(base_future.await.get(), 2);
+        assert_eq!(future_1.await.get(), 2);
+        assert_eq!(future_2.await.get(), 2);
+    }
+}
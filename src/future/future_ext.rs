@@ -1,37 +1,83 @@
+use crate::future::abortable::Abortable;
 use crate::future::and_then::AndThen;
 use crate::future::and_then_async::AndThenAsync;
+use crate::future::and_then_async_once::AndThenAsyncOnce;
+use crate::future::and_then_once::AndThenOnce;
+use crate::future::cancel_on::CancelOn;
 use crate::future::err_into::ErrInto;
 use crate::future::flatten::Flatten;
 use crate::future::flatten_async_iterator::FlattenAsyncIterator;
+use crate::future::fuse::Fuse;
 use crate::future::inspect::Inspect;
 use crate::future::inspect_err::InspectErr;
 use crate::future::inspect_ok::InspectOk;
+use crate::future::inspect_once::InspectOnce;
 use crate::future::into_try_future::IntoTryFuture;
 use crate::future::map::Map;
 use crate::future::map_async::MapAsync;
+use crate::future::map_async_once::MapAsyncOnce;
 use crate::future::map_err::MapErr;
 use crate::future::map_err_async::MapErrAsync;
+use crate::future::map_err_once::MapErrOnce;
 use crate::future::map_into::MapInto;
 use crate::future::map_ok::MapOk;
 use crate::future::map_ok_async::MapOkAsync;
+use crate::future::map_ok_once::MapOkOnce;
 use crate::future::map_ok_or_else::MapOkOrElse;
 use crate::future::map_ok_or_else_async::MapOkOrElseAsync;
+use crate::future::map_once::MapOnce;
 use crate::future::ok_into::OkInto;
 use crate::future::or_else::OrElse;
 use crate::future::or_else_async::OrElseAsync;
+use crate::future::or_else_once::OrElseOnce;
 use crate::future::raw_map_ok_or_else_async::RawMapOkOrElseAsync;
+use crate::future::timeout::Timeout;
+use crate::future::try_cancel_on::TryCancelOn;
 use crate::future::try_flatten::TryFlatten;
 use crate::future::try_flatten_err::TryFlattenErr;
 use crate::future::unwrap_or_else::UnwrapOrElse;
 use crate::future::unwrap_or_else_async::UnwrapOrElseAsync;
+use crate::future::unwrap_or_else_once::UnwrapOrElseOnce;
 use crate::support::{self, FromResidual, IntoAsyncIterator, Never, Residual, ResultFuture, Try};
+use crate::task::{AbortHandle, AbortRegistration, Aborted, Cancelled};
+use crate::time::{TimedOut, Timer};
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
 use core::future::{Future, IntoFuture};
+use core::ops::Deref;
+use core::time::Duration;
 
 pub trait FutureExt: Future {
     fn by_ref(&mut self) -> &mut Self {
         self
     }
 
+    fn slim_abortable<R>(self, registration: R) -> (Abortable<Self, R>, AbortHandle<R>)
+    where
+        Self: Sized,
+        R: Deref<Target = AbortRegistration> + Clone,
+    {
+        let handle = AbortHandle::new(registration.clone());
+
+        (
+            support::assert_future::<_, Result<Self::Output, Aborted>>(Abortable::new(self, registration)),
+            handle,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn slim_abortable_arc(
+        self,
+    ) -> (
+        Abortable<Self, Arc<AbortRegistration>>,
+        AbortHandle<Arc<AbortRegistration>>,
+    )
+    where
+        Self: Sized,
+    {
+        self.slim_abortable(Arc::new(AbortRegistration::new()))
+    }
+
     fn slim_and_then<F, R>(self, f: F) -> AndThen<Self, F>
     where
         Self: Sized,
@@ -53,6 +99,35 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Fut::Output>(AndThenAsync::new(self, f))
     }
 
+    fn slim_and_then_async_once<F, Fut>(self, f: F) -> AndThenAsyncOnce<Self, F>
+    where
+        Self: Sized,
+        Self::Output: Try,
+        F: FnOnce(<Self::Output as Try>::Output) -> Fut,
+        Fut: IntoFuture,
+        Fut::Output: FromResidual<<Self::Output as Try>::Residual>,
+    {
+        support::assert_future::<_, Fut::Output>(AndThenAsyncOnce::new(self, f))
+    }
+
+    fn slim_and_then_once<F, R>(self, f: F) -> AndThenOnce<Self, F>
+    where
+        Self: Sized,
+        Self::Output: Try,
+        F: FnOnce(<Self::Output as Try>::Output) -> R,
+        R: FromResidual<<Self::Output as Try>::Residual>,
+    {
+        support::assert_future::<_, R>(AndThenOnce::new(self, f))
+    }
+
+    fn slim_cancel_on<S>(self, signal: S) -> CancelOn<Self, S::IntoFuture>
+    where
+        Self: Sized,
+        S: IntoFuture<Output = ()>,
+    {
+        support::assert_future::<_, Option<Self::Output>>(CancelOn::new(self, signal.into_future()))
+    }
+
     fn slim_err_into<T>(self) -> ErrInto<Self, T>
     where
         Self: ResultFuture + Sized,
@@ -77,6 +152,13 @@ pub trait FutureExt: Future {
         support::assert_async_iter::<_, <Self::Output as IntoAsyncIterator>::Item>(FlattenAsyncIterator::new(self))
     }
 
+    fn slim_fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        support::assert_future::<_, Self::Output>(Fuse::new(self))
+    }
+
     fn slim_inspect<F>(self, f: F) -> Inspect<Self, F>
     where
         Self: Sized,
@@ -101,6 +183,14 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Self::Output>(InspectOk::new(self, f))
     }
 
+    fn slim_inspect_once<F>(self, f: F) -> InspectOnce<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Output),
+    {
+        support::assert_future::<_, Self::Output>(InspectOnce::new(self, f))
+    }
+
     fn slim_into_option_future(self) -> IntoTryFuture<Self, Option<Self::Output>>
     where
         Self: Sized,
@@ -140,6 +230,15 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Fut::Output>(MapAsync::new(self, f))
     }
 
+    fn slim_map_async_once<F, Fut>(self, f: F) -> MapAsyncOnce<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Output) -> Fut,
+        Fut: IntoFuture,
+    {
+        support::assert_future::<_, Fut::Output>(MapAsyncOnce::new(self, f))
+    }
+
     fn slim_map_err<F, T>(self, f: F) -> MapErr<Self, F>
     where
         Self: ResultFuture + Sized,
@@ -157,6 +256,14 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Result<Self::Ok, Fut::Output>>(MapErrAsync::new(self, f))
     }
 
+    fn slim_map_err_once<F, T>(self, f: F) -> MapErrOnce<Self, F>
+    where
+        Self: ResultFuture + Sized,
+        F: FnOnce(Self::Error) -> T,
+    {
+        support::assert_future::<_, Result<Self::Ok, T>>(MapErrOnce::new(self, f))
+    }
+
     fn slim_map_into<T>(self) -> MapInto<Self, T>
     where
         Self: Sized,
@@ -188,6 +295,16 @@ pub trait FutureExt: Future {
         )
     }
 
+    fn slim_map_ok_once<F, T>(self, f: F) -> MapOkOnce<Self, F>
+    where
+        Self: Sized,
+        Self::Output: Try,
+        <Self::Output as Try>::Residual: Residual<T>,
+        F: FnOnce(<Self::Output as Try>::Output) -> T,
+    {
+        support::assert_future::<_, <<Self::Output as Try>::Residual as Residual<T>>::TryType>(MapOkOnce::new(self, f))
+    }
+
     fn slim_map_ok_or_else<D, F, T>(self, default: D, f: F) -> MapOkOrElse<Self, D, F>
     where
         Self: ResultFuture + Sized,
@@ -208,6 +325,14 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Fut1::Output>(MapOkOrElseAsync::new(self, default, f))
     }
 
+    fn slim_map_once<F, T>(self, f: F) -> MapOnce<Self, F>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Output) -> T,
+    {
+        support::assert_future::<_, T>(MapOnce::new(self, f))
+    }
+
     fn slim_never_error(self) -> IntoTryFuture<Self, Result<Self::Output, Never>>
     where
         Self: Sized,
@@ -244,6 +369,15 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Fut::Output>(OrElseAsync::new(self, f))
     }
 
+    fn slim_or_else_once<F, R>(self, f: F) -> OrElseOnce<Self, F>
+    where
+        Self: ResultFuture + Sized,
+        F: FnOnce(Self::Error) -> R,
+        R: Try<Output = Self::Ok>,
+    {
+        support::assert_future::<_, R>(OrElseOnce::new(self, f))
+    }
+
     fn slim_raw_map_ok_or_else_async<D, F, Fut>(self, default: D, f: F) -> RawMapOkOrElseAsync<Self, D, F>
     where
         Self: ResultFuture + Sized,
@@ -254,6 +388,22 @@ pub trait FutureExt: Future {
         support::assert_future::<_, Fut::Output>(RawMapOkOrElseAsync::new(self, default, f))
     }
 
+    fn slim_timeout<T>(self, timer: T, duration: Duration) -> Timeout<Self, T::Sleep>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        support::assert_future::<_, Result<Self::Output, TimedOut>>(Timeout::new(self, timer.sleep(duration)))
+    }
+
+    fn slim_try_cancel_on<S>(self, signal: S) -> TryCancelOn<Self, S::IntoFuture>
+    where
+        Self: Sized,
+        S: IntoFuture<Output = ()>,
+    {
+        support::assert_future::<_, Result<Self::Output, Cancelled>>(TryCancelOn::new(self, signal.into_future()))
+    }
+
     fn slim_try_flatten(self) -> TryFlatten<Self>
     where
         Self: Sized,
@@ -296,6 +446,14 @@ pub trait FutureExt: Future {
     {
         support::assert_future::<_, Self::Ok>(UnwrapOrElseAsync::new(self, f))
     }
+
+    fn slim_unwrap_or_else_once<F>(self, f: F) -> UnwrapOrElseOnce<Self, F>
+    where
+        Self: ResultFuture + Sized,
+        F: FnOnce(Self::Error) -> Self::Ok,
+    {
+        support::assert_future::<_, Self::Ok>(UnwrapOrElseOnce::new(self, f))
+    }
 }
 
 impl<Fut> FutureExt for Fut where Fut: Future + ?Sized {}
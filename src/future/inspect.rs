@@ -1,5 +1,6 @@
 use crate::future::map::Map;
 use crate::support::fns::InspectFn;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -18,13 +19,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> Inspect<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Map::new(fut, InspectFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for Inspect<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Inspect").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for Inspect<Fut, F>
 where
     Fut: Future,
@@ -53,7 +63,6 @@ mod tests {
     use crate::future::ready;
     use futures_core::FusedFuture;
     use futures_util::{future, FutureExt as _};
-    use std::mem;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[tokio::test]
@@ -88,7 +97,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_inspect_fused_future() {
-        let mut future = future::ready(()).slim_inspect(|_| {});
+        let mut future = future::ready(()).slim_inspect(|()| {});
 
         assert!(!future.is_terminated());
 
@@ -104,8 +113,8 @@ mod tests {
         let future_1 = make_base_future().slim_inspect(|_| {});
         let future_2 = make_base_future().inspect(|_| {});
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, 2);
         assert_eq!(future_1.await, 2);
         assert_eq!(future_2.await, 2);
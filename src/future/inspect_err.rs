@@ -1,6 +1,7 @@
 use crate::future::inspect::Inspect;
 use crate::support::fns::InspectErrFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> InspectErr<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Inspect::new(fut, InspectErrFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for InspectErr<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("InspectErr").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for InspectErr<Fut, F>
 where
     Fut: ResultFuture,
@@ -54,7 +64,6 @@ mod tests {
     use crate::future::future_ext::FutureExt;
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[tokio::test]
@@ -96,7 +105,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_inspect_err_fused_future() {
-        let mut future = future::err::<(), ()>(()).slim_inspect_err(|_| {});
+        let mut future = future::err::<(), ()>(()).slim_inspect_err(|()| {});
 
         assert!(!future.is_terminated());
         assert_eq!(future.by_ref().await, Err(()));
@@ -110,8 +119,8 @@ mod tests {
         let future_1 = make_base_future().slim_inspect_err(|_| {});
         let future_2 = make_base_future().inspect_err(|_| {});
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Err(2));
         assert_eq!(future_1.await, Err(2));
         assert_eq!(future_2.await, Err(2));
@@ -1,6 +1,7 @@
 use crate::future::inspect::Inspect;
 use crate::support::fns::InspectOkFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> InspectOk<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Inspect::new(fut, InspectOkFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for InspectOk<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("InspectOk").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for InspectOk<Fut, F>
 where
     Fut: ResultFuture,
@@ -54,7 +64,6 @@ mod tests {
     use crate::future::ok;
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[tokio::test]
@@ -96,7 +105,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_inspect_ok_fused_future() {
-        let mut future = future::ok::<(), ()>(()).slim_inspect_ok(|_| {});
+        let mut future = future::ok::<(), ()>(()).slim_inspect_ok(|()| {});
 
         assert!(!future.is_terminated());
         assert_eq!(future.by_ref().await, Ok(()));
@@ -110,8 +119,8 @@ mod tests {
         let future_1 = make_base_future().slim_inspect_ok(|_| {});
         let future_2 = make_base_future().inspect_ok(|_| {});
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Ok(2));
         assert_eq!(future_1.await, Ok(2));
         assert_eq!(future_2.await, Ok(2));
@@ -0,0 +1,113 @@
+use crate::future::map_once::MapOnce;
+use crate::support::fns::InspectFn;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct InspectOnce<Fut, F> {
+        #[pin]
+        inner: MapOnce<Fut, InspectFn<F>>,
+    }
+}
+
+impl<Fut, F> InspectOnce<Fut, F> {
+    pub const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: MapOnce::new(fut, InspectFn::new(f)),
+        }
+    }
+}
+
+impl<Fut, F> Debug for InspectOnce<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("InspectOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for InspectOnce<Fut, F>
+where
+    Fut: Future,
+    F: for<'a> FnOnce<(&'a Fut::Output,), Output = ()>,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for InspectOnce<Fut, F>
+where
+    Fut: Future,
+    F: for<'a> FnOnce<(&'a Fut::Output,), Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt as _};
+    use std::num::NonZeroU32;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_inspect_once() {
+        let mut items = Vec::new();
+        let suffix = String::from("abc");
+
+        let future = future::ready(3).slim_inspect_once(|&value| {
+            items.push((value, suffix));
+        });
+
+        assert_eq!(future.await, 3);
+        assert_eq!(items, [(3, String::from("abc"))]);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_once_clone() {
+        let future = future::ready(3).slim_inspect_once(|_| {});
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, 3);
+        assert_eq!(future_2.await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_once_fused_future() {
+        let mut future = future::ready(()).slim_inspect_once(|()| {});
+
+        assert!(!future.is_terminated());
+
+        future.by_ref().await;
+
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_inspect_once_is_slim() {
+        let make_base_future = || ready::ready_by_copy(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future_1 = make_base_future().slim_inspect_once(|_| {});
+        let future_2 = make_base_future().inspect(|_| {});
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) <= size_of_val(&future_2));
+        assert_eq!(base_future.await.get(), 2);
+        assert_eq!(future_1.await.get(), 2);
+        assert_eq!(future_2.await.get(), 2);
+    }
+}
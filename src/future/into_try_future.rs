@@ -1,6 +1,7 @@
 use crate::future::map::Map;
 use crate::support::fns::TryFromOutputFn;
 use crate::support::Try;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -32,6 +33,15 @@ where
     }
 }
 
+impl<Fut, T> Debug for IntoTryFuture<Fut, T>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("IntoTryFuture").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, T> Future for IntoTryFuture<Fut, T>
 where
     Fut: Future,
@@ -62,7 +72,6 @@ mod tests {
     use crate::support::Never;
     use futures_core::FusedFuture;
     use futures_util::{future, FutureExt as _};
-    use std::mem;
     use std::string::String;
 
     #[tokio::test]
@@ -117,8 +126,8 @@ mod tests {
         let future_1 = make_base_future().slim_unit_error();
         let future_2 = make_base_future().unit_error();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, 2);
         assert_eq!(future_1.await, Ok(2));
         assert_eq!(future_2.await, Ok(2));
@@ -0,0 +1,361 @@
+#![allow(clippy::struct_field_names, reason = "Fields are named after the futures they hold.")]
+#![allow(unsafe_code, reason = "Branch slots share a single state field.")]
+
+use crate::support;
+use crate::support::states::RawMaybeDone;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+const GONE: u8 = 1 << 7;
+
+macro_rules! define_join {
+    ($name:ident, $fn_name:ident, $($fut:ident: $field:ident = $index:literal),+) => {
+        pub struct $name<$($fut),+>
+        where
+            $($fut: Future,)+
+        {
+            $($field: RawMaybeDone<$fut>,)+
+            // Bit `i` is set once branch `i` has completed, and `GONE` is set once the outputs have been taken.
+            state: u8,
+        }
+
+        impl<$($fut),+> $name<$($fut),+>
+        where
+            $($fut: Future,)+
+        {
+            const ALL_DONE: u8 = 0 $(| 1 << $index)+;
+
+            pub(crate) const fn new($($field: $fut),+) -> Self {
+                Self {
+                    $($field: RawMaybeDone::new($field),)+
+                    state: 0,
+                }
+            }
+
+            const fn is_done(&self, index: u8) -> bool {
+                self.state & (1 << index) != 0
+            }
+
+            const fn is_gone(&self) -> bool {
+                self.state == GONE
+            }
+        }
+
+        impl<$($fut),+> Clone for $name<$($fut),+>
+        where
+            $(
+                $fut: Future + Clone,
+                $fut::Output: Clone,
+            )+
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    // SAFETY: `state` describes the active field of each slot.
+                    $($field: unsafe { self.$field.get(self.is_done($index), self.is_gone()) }.to_owned(),)+
+                    state: self.state,
+                }
+            }
+        }
+
+        impl<$($fut),+> Debug for $name<$($fut),+>
+        where
+            $(
+                $fut: Future + Debug,
+                $fut::Output: Debug,
+            )+
+        {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(
+                        stringify!($field),
+                        // SAFETY: `state` describes the active field of each slot.
+                        &unsafe { self.$field.get(self.is_done($index), self.is_gone()) },
+                    ))+
+                    .finish()
+            }
+        }
+
+        impl<$($fut),+> Drop for $name<$($fut),+>
+        where
+            $($fut: Future,)+
+        {
+            fn drop(&mut self) {
+                let is_gone = self.is_gone();
+
+                $(
+                    // SAFETY: `state` describes the active field of each slot, and the slots are not used afterwards.
+                    unsafe { self.$field.drop_in_place(self.is_done($index), is_gone) };
+                )+
+            }
+        }
+
+        impl<$($fut),+> Future for $name<$($fut),+>
+        where
+            $($fut: Future,)+
+        {
+            type Output = ($($fut::Output,)+);
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+                // SAFETY: The slots are pinned along with `self` and never moved.
+                let this = unsafe { self.get_unchecked_mut() };
+
+                if this.is_gone() {
+                    return Poll::Pending;
+                }
+
+                $(
+                    if !this.is_done($index) {
+                        let state = &mut this.state;
+
+                        // SAFETY: The slot holds the future, and it is pinned along with `self`.
+                        _ = unsafe { Pin::new_unchecked(&mut this.$field).poll_fut(cx, || *state |= 1 << $index) };
+                    }
+                )+
+
+                if this.state == Self::ALL_DONE {
+                    this.state = GONE;
+
+                    // SAFETY: Every slot holds its output, and the outputs are not structurally pinned.
+                    Poll::Ready(($(unsafe { Pin::new_unchecked(&mut this.$field).take_output() },)+))
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+
+        impl<$($fut),+> FusedFuture for $name<$($fut),+>
+        where
+            $($fut: Future,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.is_gone()
+            }
+        }
+
+        pub fn $fn_name<$($fut),+>($($field: $fut),+) -> $name<$($fut::IntoFuture),+>
+        where
+            $($fut: IntoFuture,)+
+        {
+            support::assert_future::<_, ($($fut::Output,)+)>($name::new($($field.into_future()),+))
+        }
+    };
+}
+
+define_join!(Join, join, Fut1: fut_1 = 0, Fut2: fut_2 = 1);
+define_join!(Join3, join3, Fut1: fut_1 = 0, Fut2: fut_2 = 1, Fut3: fut_3 = 2);
+define_join!(Join4, join4, Fut1: fut_1 = 0, Fut2: fut_2 = 1, Fut3: fut_3 = 2, Fut4: fut_4 = 3);
+define_join!(Join5, join5, Fut1: fut_1 = 0, Fut2: fut_2 = 1, Fut3: fut_3 = 2, Fut4: fut_4 = 3, Fut5: fut_5 = 4);
+define_join!(
+    Join6,
+    join6,
+    Fut1: fut_1 = 0,
+    Fut2: fut_2 = 1,
+    Fut3: fut_3 = 2,
+    Fut4: fut_4 = 3,
+    Fut5: fut_5 = 4,
+    Fut6: fut_6 = 5
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use core::cell::Cell;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[tokio::test]
+    async fn test_join() {
+        assert_eq!(
+            super::join(ready::ready_by_copy(2), ready::ready_by_copy(3)).await,
+            (2, 3),
+        );
+
+        assert_eq!(
+            super::join(
+                test_utilities::delayed(ready::ready_by_copy(2)),
+                ready::ready_by_copy(3)
+            )
+            .await,
+            (2, 3),
+        );
+
+        assert_eq!(
+            super::join(
+                ready::ready_by_copy(2),
+                test_utilities::delayed(ready::ready_by_copy(3))
+            )
+            .await,
+            (2, 3),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_tuples() {
+        let delayed = |value: u32| test_utilities::delayed(ready::ready_by_copy(value));
+
+        assert_eq!(
+            super::join3(delayed(2), ready::ready_by_copy(3), delayed(5)).await,
+            (2, 3, 5)
+        );
+
+        assert_eq!(
+            super::join4(delayed(2), ready::ready_by_copy(3), delayed(5), ready::ready_by_copy(7)).await,
+            (2, 3, 5, 7),
+        );
+
+        assert_eq!(
+            super::join5(
+                delayed(2),
+                ready::ready_by_copy(3),
+                delayed(5),
+                ready::ready_by_copy(7),
+                delayed(11)
+            )
+            .await,
+            (2, 3, 5, 7, 11),
+        );
+
+        assert_eq!(
+            super::join6(
+                delayed(2),
+                ready::ready_by_copy(3),
+                delayed(5),
+                ready::ready_by_copy(7),
+                delayed(11),
+                ready::ready_by_copy(13),
+            )
+            .await,
+            (2, 3, 5, 7, 11, 13),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_clone() {
+        let future = super::join(ready::ready_by_copy(2), ready::ready_by_copy(3));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, (2, 3));
+        assert_eq!(future_2.await, (2, 3));
+    }
+
+    #[tokio::test]
+    async fn test_join_fused_future() {
+        let mut future = super::join(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some((2, 3)));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_join_poll_after_completion() {
+        let mut future = super::join(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert_eq!((&mut future).now_or_never(), Some((2, 3)));
+        assert_eq!((&mut future).now_or_never(), None);
+    }
+
+    #[tokio::test]
+    async fn test_join_drops_each_value_once() {
+        struct DropCounter<'a>(&'a Cell<u32>);
+
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drop_count = Cell::new(0);
+        let make_base_future = || future::lazy(|_| DropCounter(&drop_count));
+        let mut future = super::join(make_base_future(), test_utilities::delayed(make_base_future()));
+
+        assert!((&mut future).now_or_never().is_none());
+        assert_eq!(drop_count.get(), 0);
+
+        drop(future);
+
+        assert_eq!(drop_count.get(), 1);
+
+        let mut future = super::join(make_base_future(), make_base_future());
+
+        drop((&mut future).now_or_never());
+
+        assert_eq!(drop_count.get(), 3);
+
+        drop(future);
+
+        assert_eq!(drop_count.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_join_future_drop_panics() {
+        struct DropCounter<'a>(&'a Cell<u32>);
+
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        struct PanicOnDrop<'a> {
+            drop_count: &'a Cell<u32>,
+            output_drop_count: &'a Cell<u32>,
+        }
+
+        impl<'a> Future for PanicOnDrop<'a> {
+            type Output = DropCounter<'a>;
+
+            fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Self::Output> {
+                Poll::Ready(DropCounter(self.output_drop_count))
+            }
+        }
+
+        impl Drop for PanicOnDrop<'_> {
+            #[allow(clippy::panic, reason = "Testing a panicking destructor.")]
+            fn drop(&mut self) {
+                self.drop_count.set(self.drop_count.get() + 1);
+
+                panic!("dropping the future");
+            }
+        }
+
+        let future_drop_count = Cell::new(0);
+        let output_drop_count = Cell::new(0);
+
+        let fut_1 = PanicOnDrop {
+            drop_count: &future_drop_count,
+            output_drop_count: &output_drop_count,
+        };
+
+        let mut future = super::join(fut_1, future::pending::<()>());
+
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| (&mut future).now_or_never())).is_err());
+        assert_eq!(future_drop_count.get(), 1);
+        assert_eq!(output_drop_count.get(), 0);
+
+        drop(future);
+
+        assert_eq!(future_drop_count.get(), 1);
+        assert_eq!(output_drop_count.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_join_is_slim() {
+        let make_base_future_1 = || ready::ready_by_copy(2_u32);
+        let make_base_future_2 = || ready::ready_by_copy(3_u64);
+        let future_1 = super::join(make_base_future_1(), make_base_future_2());
+        let future_2 = future::join(make_base_future_1(), make_base_future_2());
+
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
+        assert_eq!(future_1.await, (2, 3));
+        assert_eq!(future_2.await, (2, 3));
+    }
+}
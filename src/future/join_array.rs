@@ -0,0 +1,158 @@
+#![allow(unsafe_code, reason = "`pin-project-lite` does not support const generics.")]
+
+use crate::support;
+use crate::support::pin_array;
+use crate::support::states::MaybeDone;
+use core::array;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+pub struct JoinArray<Fut, const N: usize>
+where
+    Fut: Future,
+{
+    futs: [MaybeDone<Fut>; N],
+}
+
+impl<Fut, const N: usize> JoinArray<Fut, N>
+where
+    Fut: Future,
+{
+    pub(crate) fn new(futs: [Fut; N]) -> Self {
+        Self {
+            futs: futs.map(MaybeDone::new),
+        }
+    }
+
+    fn project_futs(self: Pin<&mut Self>) -> Pin<&mut [MaybeDone<Fut>; N]> {
+        // SAFETY: `futs` is structurally pinned: it is never moved out, and `JoinArray` has no `Drop` implementation.
+        unsafe { self.map_unchecked_mut(|this| &mut this.futs) }
+    }
+}
+
+impl<Fut, const N: usize> Clone for JoinArray<Fut, N>
+where
+    Fut: Future + Clone,
+    Fut::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            futs: self.futs.clone(),
+        }
+    }
+}
+
+impl<Fut, const N: usize> Debug for JoinArray<Fut, N>
+where
+    Fut: Future + Debug,
+    Fut::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("JoinArray").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for JoinArray<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = [Fut::Output; N];
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut futs = self.project_futs();
+        let mut is_ready = true;
+
+        for fut in pin_array::iter_pin_mut(futs.as_mut()) {
+            is_ready &= fut.poll(cx).is_ready();
+        }
+
+        if is_ready {
+            let mut futs = pin_array::iter_pin_mut(futs);
+
+            Poll::Ready(array::from_fn(|_| futs.next().unwrap().take_output().unwrap()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl<Fut, const N: usize> FusedFuture for JoinArray<Fut, N>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.iter().all(MaybeDone::is_terminated)
+    }
+}
+
+pub fn join_array<Fut, const N: usize>(futs: [Fut; N]) -> JoinArray<Fut::IntoFuture, N>
+where
+    Fut: IntoFuture,
+{
+    support::assert_future::<_, [Fut::Output; N]>(JoinArray::new(futs.map(IntoFuture::into_future)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::FutureExt;
+
+    #[tokio::test]
+    async fn test_join_array() {
+        assert_eq!(super::join_array([2, 3, 5].map(ready::ready_by_copy)).await, [2, 3, 5]);
+
+        assert_eq!(
+            super::join_array([2, 3, 5].map(|x| test_utilities::delayed(ready::ready_by_copy(x)))).await,
+            [2, 3, 5],
+        );
+
+        assert_eq!(
+            super::join_array([
+                ready::ready_by_copy(2).left_future(),
+                test_utilities::delayed(ready::ready_by_copy(3)).right_future(),
+                ready::ready_by_copy(5).left_future(),
+            ])
+            .await,
+            [2, 3, 5],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_array_empty() {
+        assert_eq!(
+            super::join_array::<ready::Ready<fn_traits::fns::CopyFn, u32>, 0>([]).await,
+            [0_u32; 0]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_array_clone() {
+        let future = super::join_array([2, 3, 5].map(ready::ready_by_copy));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, [2, 3, 5]);
+        assert_eq!(future_2.await, [2, 3, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_join_array_fused_future() {
+        let mut future = super::join_array([2, 3].map(ready::ready_by_copy));
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some([2, 3]));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_join_array_poll_after_completion() {
+        let mut future = super::join_array([2, 3].map(ready::ready_by_copy));
+
+        assert_eq!((&mut future).now_or_never(), Some([2, 3]));
+        assert_eq!((&mut future).now_or_never(), None);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::support;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops;
 use core::pin::Pin;
@@ -13,6 +14,15 @@ where
     f: F,
 }
 
+impl<F> Debug for Lazy<F>
+where
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Lazy").finish_non_exhaustive()
+    }
+}
+
 impl<F> Unpin for Lazy<F> where F: ?Sized {}
 
 impl<F, T> Future for Lazy<F>
@@ -26,7 +36,7 @@ where
     }
 }
 
-pub fn lazy<F, T>(f: F) -> Lazy<F>
+pub const fn lazy<F, T>(f: F) -> Lazy<F>
 where
     F: ops::FnMut(&mut Context) -> T,
 {
@@ -35,7 +45,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::mem;
+    use super::Lazy;
     use std::task::Context;
 
     fn lazy_fn(_: &mut Context) -> u32 {
@@ -47,6 +57,13 @@ mod tests {
         assert_eq!(super::lazy(lazy_fn).await, 2);
     }
 
+    #[tokio::test]
+    async fn test_lazy_in_const() {
+        const FUTURE: Lazy<fn(&mut Context) -> u32> = super::lazy(lazy_fn);
+
+        assert_eq!(FUTURE.await, 2);
+    }
+
     #[tokio::test]
     async fn test_lazy_clone() {
         let future = super::lazy(lazy_fn);
@@ -61,8 +78,8 @@ mod tests {
         let future_1 = super::lazy(lazy_fn);
         let future_2 = futures_util::future::lazy(lazy_fn);
 
-        assert_eq!(mem::size_of_val(&lazy_fn), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&lazy_fn), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(future_1.await, 2);
         assert_eq!(future_2.await, 2);
     }
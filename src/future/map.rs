@@ -1,3 +1,4 @@
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{self, Context, Poll};
@@ -17,7 +18,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> Map<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self { fut, f }
     }
 
@@ -26,6 +27,16 @@ impl<Fut, F> Map<Fut, F> {
     }
 }
 
+impl<Fut, F> Debug for Map<Fut, F>
+where
+    Fut: Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Map").field("fut", &self.fut).finish_non_exhaustive()
+    }
+}
+
 impl<Fut, F> Future for Map<Fut, F>
 where
     Fut: Future,
@@ -56,7 +67,6 @@ mod tests {
     use crate::future::ready;
     use futures_core::FusedFuture;
     use futures_util::{future, FutureExt as _};
-    use std::mem;
 
     fn plus_3(value: u32) -> u32 {
         value + 3
@@ -92,8 +102,8 @@ mod tests {
         let future_1 = make_base_future().slim_map(plus_3);
         let future_2 = make_base_future().map(plus_3);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, 2);
         assert_eq!(future_1.await, 5);
         assert_eq!(future_2.await, 5);
@@ -1,5 +1,6 @@
 use crate::future::flatten::Flatten;
 use crate::future::map::Map;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -24,7 +25,7 @@ where
     F: FnMut<(Fut::Output,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Flatten::new(Map::new(fut, f)),
         }
@@ -45,6 +46,18 @@ where
     }
 }
 
+impl<Fut, F> Debug for MapAsync<Fut, F>
+where
+    Fut: Future + Debug,
+    F: FnMut<(Fut::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for MapAsync<Fut, F>
 where
     Fut: Future,
@@ -77,7 +90,6 @@ mod tests {
     use crate::future::ready;
     use futures_core::FusedFuture;
     use futures_util::FutureExt as _;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -87,6 +99,13 @@ mod tests {
         assert_eq!(future.await, 9);
     }
 
+    #[tokio::test]
+    async fn test_map_async_with_async_closure() {
+        let future = ready::ready_by_copy(7).slim_map_async(async |value| value + 2);
+
+        assert_eq!(future.await, 9);
+    }
+
     #[tokio::test]
     async fn test_map_async_clone() {
         let future = ready::ready_by_copy(7).slim_map_async(|value| future::lazy(move |_| value + 2));
@@ -112,7 +131,7 @@ mod tests {
         let future_1 = make_base_future().slim_map_async(ready::ready_by_copy);
         let future_2 = make_base_future().then(ready::ready_by_copy);
 
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert!(matches!(future_1.await, ()));
         assert!(matches!(future_2.await, ()));
     }
@@ -0,0 +1,160 @@
+use crate::future::flatten::Flatten;
+use crate::future::map_once::MapOnce;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    pub struct MapAsyncOnce<Fut, F>
+    where
+        Fut: Future,
+        F: FnOnce<(Fut::Output,)>,
+        F::Output: IntoFuture,
+    {
+        #[pin]
+        inner: Flatten<MapOnce<Fut, F>>
+    }
+}
+
+impl<Fut, F> MapAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce<(Fut::Output,)>,
+    F::Output: IntoFuture,
+{
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: Flatten::new(MapOnce::new(fut, f)),
+        }
+    }
+}
+
+impl<Fut, F> Clone for MapAsyncOnce<Fut, F>
+where
+    Fut: Future + Clone,
+    F: FnOnce<(Fut::Output,)> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut, F> Debug for MapAsyncOnce<Fut, F>
+where
+    Fut: Future + Debug,
+    F: FnOnce<(Fut::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapAsyncOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for MapAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce<(Fut::Output,)>,
+    F::Output: IntoFuture,
+{
+    type Output = <F::Output as IntoFuture>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for MapAsyncOnce<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce<(Fut::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future;
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use crate::test_utilities::Yield;
+    use futures_core::FusedFuture;
+    use futures_util::FutureExt as _;
+    use std::num::NonZeroU32;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_map_async_once() {
+        let suffix = String::from("abc");
+
+        let future =
+            ready::ready_by_copy(7).slim_map_async_once(move |value| futures_util::future::ready((value, suffix)));
+
+        assert_eq!(future.await, (7, String::from("abc")));
+    }
+
+    #[tokio::test]
+    async fn test_map_async_once_with_async_closure() {
+        let suffix = String::from("abc");
+
+        let future = ready::ready_by_copy(7).slim_map_async_once(async move |value| (value, suffix));
+
+        assert_eq!(future.await, (7, String::from("abc")));
+    }
+
+    #[tokio::test]
+    async fn test_map_async_once_with_borrowing_async_closure() {
+        let suffix = String::from("abc");
+
+        let future = ready::ready_by_copy(7).slim_map_async_once(async move |value| {
+            let suffix = suffix.as_str();
+
+            Yield::new(1).await;
+
+            (value, suffix.len())
+        });
+
+        assert_eq!(future.await, (7, 3));
+    }
+
+    #[tokio::test]
+    async fn test_map_async_once_clone() {
+        let future = ready::ready_by_copy(7).slim_map_async_once(|value| future::lazy(move |_| value + 2));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, 9);
+        assert_eq!(future_2.await, 9);
+    }
+
+    #[tokio::test]
+    async fn test_map_async_once_fused_future() {
+        let mut future =
+            futures_util::future::ready(7).slim_map_async_once(|value| futures_util::future::lazy(move |_| value + 2));
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.by_ref().await, 9);
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_map_async_once_is_slim() {
+        let make_base_future = || ready::ready_by_copy(NonZeroU32::new(2).unwrap()).slim_map(drop);
+        let future_1 = make_base_future().slim_map_async_once(ready::ready_by_copy);
+        let future_2 = make_base_future().then(ready::ready_by_copy);
+
+        assert!(size_of_val(&future_1) <= size_of_val(&future_2));
+        assert!(matches!(future_1.await, ()));
+        assert!(matches!(future_2.await, ()));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::future::map::Map;
 use crate::support::fns::MapErrFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> MapErr<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Map::new(fut, MapErrFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for MapErr<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapErr").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for MapErr<Fut, F>
 where
     Fut: ResultFuture,
@@ -54,7 +64,6 @@ mod tests {
     use crate::future::future_ext::FutureExt;
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
 
     fn plus_3(value: u32) -> u32 {
         value + 3
@@ -91,8 +100,8 @@ mod tests {
         let future_1 = make_base_future().slim_map_err(plus_3);
         let future_2 = make_base_future().map_err(plus_3);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Err(2));
         assert_eq!(future_1.await, Err(5));
         assert_eq!(future_2.await, Err(5));
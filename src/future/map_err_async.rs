@@ -1,6 +1,7 @@
 use crate::future::or_else_async::OrElseAsync;
 use crate::support::fns::MapErrAsyncFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -25,7 +26,7 @@ where
     F: FnMut<(Fut::Error,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: OrElseAsync::new(fut, MapErrAsyncFn::new(f)),
         }
@@ -46,6 +47,18 @@ where
     }
 }
 
+impl<Fut, F> Debug for MapErrAsync<Fut, F>
+where
+    Fut: ResultFuture + Debug,
+    F: FnMut<(Fut::Error,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapErrAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for MapErrAsync<Fut, F>
 where
     Fut: ResultFuture,
@@ -76,7 +89,6 @@ mod tests {
     use crate::future::{err, ready};
     use futures_core::FusedFuture;
     use futures_util::future;
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn plus_3(value: u32) -> impl FusedFuture<Output = u32> + Clone {
@@ -113,7 +125,7 @@ mod tests {
         let base_future = make_base_future();
         let future = make_base_future().slim_map_err_async(ready::ready_by_copy);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await, Err(()));
         assert_eq!(future.await, Err(()));
     }
@@ -1,6 +1,7 @@
 use crate::future::map::Map;
 use crate::support::fns::MapOkFn;
 use crate::support::{Residual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> MapOk<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Map::new(fut, MapOkFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for MapOk<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOk").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for MapOk<Fut, F>
 where
     Fut: Future,
@@ -54,11 +64,11 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::fns::{CopyFn, FnMut};
     use crate::future::future_ext::FutureExt;
-    use crate::future::ok;
+    use crate::future::{ok, MapOk};
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
 
     fn plus_3(value: u32) -> u32 {
         value + 3
@@ -75,6 +85,29 @@ mod tests {
         assert_eq!(future::ready(Some(2)).slim_map_ok(plus_3).await, Some(5));
     }
 
+    #[tokio::test]
+    async fn test_map_ok_with_named_function() {
+        struct AddOne;
+
+        impl FnMut<(u32,)> for AddOne {
+            type Output = u32;
+
+            fn call_mut(&mut self, args: (u32,)) -> Self::Output {
+                args.0 + 1
+            }
+        }
+
+        struct Pipeline {
+            future: MapOk<ok::Ok<CopyFn, u32, u32>, AddOne>,
+        }
+
+        let pipeline = Pipeline {
+            future: MapOk::new(ok::ok_by_copy(2), AddOne),
+        };
+
+        assert_eq!(pipeline.future.await, Ok(3));
+    }
+
     #[tokio::test]
     async fn test_map_ok_clone() {
         let future = future::ok::<_, u32>(2).slim_map_ok(plus_3);
@@ -100,8 +133,8 @@ mod tests {
         let future_1 = make_base_future().slim_map_ok(plus_3);
         let future_2 = make_base_future().map_ok(plus_3);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Ok(2));
         assert_eq!(future_1.await, Ok(5));
         assert_eq!(future_2.await, Ok(5));
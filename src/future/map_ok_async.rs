@@ -1,6 +1,7 @@
 use crate::future::and_then_async::AndThenAsync;
 use crate::support::fns::MapOkAsyncFn;
 use crate::support::{Residual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -29,7 +30,7 @@ where
     F: FnMut<(<Fut::Output as Try>::Output,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: AndThenAsync::new(fut, MapOkAsyncFn::new(f)),
         }
@@ -52,6 +53,20 @@ where
     }
 }
 
+impl<Fut, F> Debug for MapOkAsync<Fut, F>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<<F::Output as IntoFuture>::Output>,
+    F: FnMut<(<Fut::Output as Try>::Output,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOkAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for MapOkAsync<Fut, F>
 where
     Fut: Future,
@@ -87,7 +102,6 @@ mod tests {
     use crate::future::{ok, ready};
     use futures_core::FusedFuture;
     use futures_util::future;
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn plus_3(value: u32) -> impl FusedFuture<Output = u32> + Clone {
@@ -129,7 +143,7 @@ mod tests {
         let base_future = make_base_future();
         let future = make_base_future().slim_map_ok_async(ready::ready_by_copy);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await, Ok(()));
         assert_eq!(future.await, Ok(()));
     }
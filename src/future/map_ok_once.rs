@@ -0,0 +1,128 @@
+use crate::future::map_once::MapOnce;
+use crate::support::fns::MapOkFn;
+use crate::support::{Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct MapOkOnce<Fut, F> {
+        #[pin]
+        inner: MapOnce<Fut, MapOkFn<F>>,
+    }
+}
+
+impl<Fut, F> MapOkOnce<Fut, F> {
+    pub const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: MapOnce::new(fut, MapOkFn::new(f)),
+        }
+    }
+}
+
+impl<Fut, F> Debug for MapOkOnce<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOkOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for MapOkOnce<Fut, F>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<F::Output>,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+{
+    type Output = <<Fut::Output as Try>::Residual as Residual<F::Output>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for MapOkOnce<Fut, F>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<F::Output>,
+    F: FnOnce<(<Fut::Output as Try>::Output,)>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ok;
+    use futures_core::FusedFuture;
+    use futures_util::future;
+    use std::num::NonZeroU32;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_map_ok_once() {
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            future::ok::<_, u32>(2)
+                .slim_map_ok_once(move |value| (value, suffix))
+                .await,
+            Ok((2, String::from("abc"))),
+        );
+
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            future::err::<u32, u32>(2)
+                .slim_map_ok_once(move |value| (value, suffix))
+                .await,
+            Err(2),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_map_ok_once_with_option() {
+        assert_eq!(
+            future::ready(Some(2)).slim_map_ok_once(|value| value + 3).await,
+            Some(5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_map_ok_once_clone() {
+        let future = future::ok::<_, u32>(2).slim_map_ok_once(|value| value + 3);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(5));
+        assert_eq!(future_2.await, Ok(5));
+    }
+
+    #[tokio::test]
+    async fn test_map_ok_once_fused_future() {
+        let mut future = future::ok::<_, u32>(2).slim_map_ok_once(|value| value + 3);
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.by_ref().await, Ok(5));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_map_ok_once_is_slim() {
+        let make_base_future = || ok::ok_by_copy::<_, u32>(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future = make_base_future().slim_map_ok_once(|value| value.get() + 3);
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
+        assert_eq!(base_future.await.map(NonZeroU32::get), Ok(2));
+        assert_eq!(future.await, Ok(5));
+    }
+}
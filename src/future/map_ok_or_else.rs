@@ -1,6 +1,7 @@
 use crate::future::map::Map;
 use crate::support::fns::MapOkOrElseFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, D, F> MapOkOrElse<Fut, D, F> {
-    pub(crate) fn new(fut: Fut, default: D, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, default: D, f: F) -> Self {
         Self {
             inner: Map::new(fut, MapOkOrElseFn::new(default, f)),
         }
     }
 }
 
+impl<Fut, D, F> Debug for MapOkOrElse<Fut, D, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOkOrElse").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, D, F> Future for MapOkOrElse<Fut, D, F>
 where
     Fut: ResultFuture,
@@ -56,7 +66,6 @@ mod tests {
     use crate::future::ok;
     use futures_core::FusedFuture;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
 
     fn plus_3(value: u32) -> u32 {
         value + 3
@@ -97,8 +106,8 @@ mod tests {
         let future_1 = make_base_future().slim_map_ok_or_else(plus_3, plus_4);
         let future_2 = make_base_future().map_ok_or_else(plus_3, plus_4);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Ok(2));
         assert_eq!(future_1.await, 6);
         assert_eq!(future_2.await, 6);
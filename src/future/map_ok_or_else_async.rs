@@ -1,7 +1,8 @@
-#![allow(clippy::type_complexity)]
+#![allow(clippy::type_complexity, reason = "Function object types are nested.")]
 
 use crate::future::raw_map_ok_or_else_async::RawMapOkOrElseAsync;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::marker::PhantomData;
 use core::pin::Pin;
@@ -16,7 +17,7 @@ struct MapOkOrElseAsyncLeftFn<F, R> {
 }
 
 impl<F, R> MapOkOrElseAsyncLeftFn<F, R> {
-    fn new(f: F) -> Self {
+    const fn new(f: F) -> Self {
         Self {
             f,
             phantom: PhantomData,
@@ -54,7 +55,7 @@ struct MapOkOrElseAsyncRightFn<F, L> {
 }
 
 impl<F, L> MapOkOrElseAsyncRightFn<F, L> {
-    fn new(f: F) -> Self {
+    const fn new(f: F) -> Self {
         Self {
             f,
             phantom: PhantomData,
@@ -112,7 +113,7 @@ where
     F: FnMut<(Fut::Ok,)>,
     F::Output: IntoFuture<Output = <D::Output as IntoFuture>::Output>,
 {
-    pub(crate) fn new(fut: Fut, default: D, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, default: D, f: F) -> Self {
         Self {
             inner: RawMapOkOrElseAsync::new(
                 fut,
@@ -140,6 +141,21 @@ where
     }
 }
 
+impl<Fut, D, F> Debug for MapOkOrElseAsync<Fut, D, F>
+where
+    Fut: ResultFuture + Debug,
+    D: FnMut<(Fut::Error,)>,
+    D::Output: IntoFuture,
+    <D::Output as IntoFuture>::IntoFuture: Debug,
+    F: FnMut<(Fut::Ok,)>,
+    F::Output: IntoFuture<Output = <D::Output as IntoFuture>::Output>,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MapOkOrElseAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, D, F> Future for MapOkOrElseAsync<Fut, D, F>
 where
     Fut: ResultFuture,
@@ -0,0 +1,132 @@
+use crate::support::Never;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct MapOnce<Fut, F> {
+        #[pin]
+        state: ThreeStates<Fut, F, (), (), Never, Never>,
+    }
+}
+
+impl<Fut, F> MapOnce<Fut, F> {
+    pub const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            state: ThreeStates::A {
+                pinned: fut,
+                unpinned: f,
+            },
+        }
+    }
+}
+
+impl<Fut, F> Debug for MapOnce<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let fut = match &self.state {
+            ThreeStates::A { pinned, .. } => Some(pinned),
+            ThreeStates::B { .. } => None,
+            ThreeStates::C { unpinned, .. } => match *unpinned {},
+        };
+
+        f.debug_struct("MapOnce").field("fut", &fut).finish_non_exhaustive()
+    }
+}
+
+impl<Fut, F> Future for MapOnce<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce<(Fut::Output,)>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        match self.project().state.pin_project() {
+            ThreeStatesPinProject::A(mut running_state) => {
+                let output = task::ready!(running_state.get_project().pinned.poll(cx));
+                let f = running_state.replace_state_b((), ()).1;
+
+                Poll::Ready(f.call_once((output,)))
+            }
+            ThreeStatesPinProject::B(_) => Poll::Pending,
+            ThreeStatesPinProject::C(terminated_state) => match *terminated_state.into_project().unpinned {},
+        }
+    }
+}
+
+impl<Fut, F> FusedFuture for MapOnce<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce<(Fut::Output,)>,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, ThreeStates::B { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt as _};
+    use std::num::NonZeroU32;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[tokio::test]
+    async fn test_map_once() {
+        let mut items = Vec::new();
+        let suffix = String::from("abc");
+
+        items.push(String::from("def"));
+
+        let future = future::ready(2).slim_map_once(move |value| {
+            items.push(suffix);
+
+            (value, items)
+        });
+
+        assert_eq!(future.await, (2, ["def", "abc"].map(String::from).into()));
+    }
+
+    #[tokio::test]
+    async fn test_map_once_clone() {
+        let future = future::ready(2).slim_map_once(|value| value + 3);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, 5);
+        assert_eq!(future_2.await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_map_once_fused_future() {
+        let mut future = future::ready(2).slim_map_once(|value| value + 3);
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, 5);
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_map_once_is_slim() {
+        let make_base_future = || ready::ready_by_copy(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future_1 = make_base_future().slim_map_once(NonZeroU32::get);
+        let future_2 = make_base_future().map(NonZeroU32::get);
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) <= size_of_val(&future_2));
+        assert_eq!(base_future.await.get(), 2);
+        assert_eq!(future_1.await, 2);
+        assert_eq!(future_2.await, 2);
+    }
+}
@@ -1,69 +1,125 @@
-#![allow(clippy::module_name_repetitions)] // False positive, for `IntoTryFuture`,
+#![allow(clippy::module_name_repetitions, reason = "False positive, for `IntoTryFuture`.")]
 
+pub use self::abortable::Abortable;
 pub use self::and_then::AndThen;
 pub use self::and_then_async::AndThenAsync;
+pub use self::and_then_async_once::AndThenAsyncOnce;
+pub use self::and_then_once::AndThenOnce;
+pub use self::cancel_on::CancelOn;
 pub use self::err::{err_by, err_by_clone, err_by_copy, err_by_take, Err};
 pub use self::err_into::ErrInto;
 pub use self::flatten::Flatten;
 pub use self::flatten_async_iterator::FlattenAsyncIterator;
+pub use self::fuse::Fuse;
 pub use self::future_ext::FutureExt;
 pub use self::inspect::Inspect;
 pub use self::inspect_err::InspectErr;
 pub use self::inspect_ok::InspectOk;
+pub use self::inspect_once::InspectOnce;
 pub use self::into_try_future::IntoTryFuture;
+pub use self::join::{join, join3, join4, join5, join6, Join, Join3, Join4, Join5, Join6};
+pub use self::join_array::{join_array, JoinArray};
 pub use self::lazy::{lazy, Lazy};
 pub use self::map::Map;
 pub use self::map_async::MapAsync;
+pub use self::map_async_once::MapAsyncOnce;
 pub use self::map_err::MapErr;
+pub use self::map_err_async::MapErrAsync;
+pub use self::map_err_once::MapErrOnce;
 pub use self::map_into::MapInto;
 pub use self::map_ok::MapOk;
 pub use self::map_ok_async::MapOkAsync;
+pub use self::map_ok_once::MapOkOnce;
 pub use self::map_ok_or_else::MapOkOrElse;
 pub use self::map_ok_or_else_async::MapOkOrElseAsync;
+pub use self::map_once::MapOnce;
 pub use self::ok::{ok_by, ok_by_clone, ok_by_copy, ok_by_take, Ok};
 pub use self::ok_into::OkInto;
+pub use self::or_else::OrElse;
 pub use self::or_else_async::OrElseAsync;
+pub use self::or_else_once::OrElseOnce;
 pub use self::raw_map_ok_or_else_async::RawMapOkOrElseAsync;
-pub use self::raw_select::{raw_select, RawSelect};
+pub use self::raw_select::{raw_select, raw_select_fair, RawSelect};
 pub use self::ready::{ready_by, ready_by_clone, ready_by_copy, ready_by_take, Ready};
-pub use self::select_either::{select_either, SelectEither};
+pub use self::retry::{retry, Retry};
+pub use self::retry_policy::{ExponentialBackoff, FixedBackoff, JitteredBackoff, RetryPolicy};
+pub use self::select_array::{select_array, SelectArray};
+pub use self::select_either::{select_either, select_either_fair, SelectEither};
+pub use self::select_keep::{select_keep, SelectKeep};
+pub use self::select_ok_array::{select_ok_array, SelectOkArray};
+pub use self::select_slice::{select_slice, SelectSlice};
+pub use self::select_strategy::{Biased, Fair, SelectStrategy};
+pub use self::timeout::Timeout;
+pub use self::try_cancel_on::TryCancelOn;
 pub use self::try_flatten::TryFlatten;
 pub use self::try_flatten_err::TryFlattenErr;
-pub use self::try_select_either::{try_select_either, TrySelectEither};
+pub use self::try_join::{
+    try_join, try_join3, try_join4, try_join5, try_join6, TryJoin, TryJoin3, TryJoin4, TryJoin5, TryJoin6,
+};
+pub use self::try_join_array::{try_join_array, TryJoinArray};
+pub use self::try_select_either::{try_select_either, try_select_either_fair, TrySelectEither};
+pub use self::try_select_keep::{try_select_keep, TrySelectKeep};
 pub use self::unwrap_or_else::UnwrapOrElse;
 pub use self::unwrap_or_else_async::UnwrapOrElseAsync;
+pub use self::unwrap_or_else_once::UnwrapOrElseOnce;
 
+mod abortable;
 mod and_then;
 mod and_then_async;
+mod and_then_async_once;
+mod and_then_once;
+mod cancel_on;
 mod err;
 mod err_into;
 mod flatten;
 mod flatten_async_iterator;
+mod fuse;
 mod future_ext;
 mod inspect;
 mod inspect_err;
 mod inspect_ok;
+mod inspect_once;
 mod into_try_future;
+mod join;
+mod join_array;
 mod lazy;
 mod map;
 mod map_async;
+mod map_async_once;
 mod map_err;
 mod map_err_async;
+mod map_err_once;
 mod map_into;
 mod map_ok;
 mod map_ok_async;
+mod map_ok_once;
 mod map_ok_or_else;
 mod map_ok_or_else_async;
+mod map_once;
 mod ok;
 mod ok_into;
 mod or_else;
 mod or_else_async;
+mod or_else_once;
 mod raw_map_ok_or_else_async;
 mod raw_select;
 mod ready;
+mod retry;
+mod retry_policy;
+mod select_array;
 mod select_either;
+mod select_keep;
+mod select_ok_array;
+mod select_slice;
+mod select_strategy;
+mod timeout;
+mod try_cancel_on;
 mod try_flatten;
 mod try_flatten_err;
+mod try_join;
+mod try_join_array;
 mod try_select_either;
+mod try_select_keep;
 mod unwrap_or_else;
 mod unwrap_or_else_async;
+mod unwrap_or_else_once;
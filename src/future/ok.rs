@@ -1,11 +1,13 @@
 use crate::future::map::Map;
 use crate::future::ready::Ready;
 use crate::support;
+use crate::support::fns::ResultOkFn;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use fn_traits::fns::{CloneFn, CopyFn, MemTakeFn, ResultOkFn};
+use fn_traits::fns::{CloneFn, CopyFn, MemTakeFn};
 use fn_traits::FnMut;
 
 pin_project_lite::pin_project! {
@@ -16,9 +18,9 @@ pin_project_lite::pin_project! {
 }
 
 impl<G, T, E> Ok<G, T, E> {
-    fn new(getter: G, value: T) -> Self {
+    const fn new(getter: G, value: T) -> Self {
         Self {
-            inner: Map::new(Ready::new(getter, value), ResultOkFn::default()),
+            inner: Map::new(Ready::new(getter, value), ResultOkFn::new()),
         }
     }
 }
@@ -35,6 +37,15 @@ where
     }
 }
 
+impl<G, T, E> Debug for Ok<G, T, E>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Ok").field("inner", &self.inner).finish()
+    }
+}
+
 impl<G, T, E> Future for Ok<G, T, E>
 where
     G: for<'a> FnMut<(&'a mut T,), Output = T>,
@@ -46,7 +57,7 @@ where
     }
 }
 
-pub fn ok_by<G, T, E>(getter: G, value: T) -> Ok<G, T, E>
+pub const fn ok_by<G, T, E>(getter: G, value: T) -> Ok<G, T, E>
 where
     G: ops::FnMut(&mut T) -> T,
 {
@@ -77,13 +88,23 @@ where
 #[cfg(test)]
 mod tests {
     use crate::future::ok;
-    use std::mem;
 
     #[tokio::test]
     async fn test_ok() {
         assert_eq!(ok::ok_by_copy::<u32, u32>(2).await, Ok(2));
     }
 
+    #[tokio::test]
+    async fn test_ok_by_in_const() {
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: ok::Ok<fn(&mut u32) -> u32, u32, u32> = ok::ok_by(get, 2);
+
+        assert_eq!(FUTURE.await, Ok(2));
+    }
+
     #[tokio::test]
     async fn test_ok_clone() {
         let future = ok::ok_by_copy::<u32, u32>(2);
@@ -99,8 +120,8 @@ mod tests {
         let future_1 = ok::ok_by_copy::<_, u32>(value);
         let future_2 = futures_util::future::ok::<_, u32>(value);
 
-        assert_eq!(mem::size_of_val(&value), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&value), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(future_1.await, Ok(value));
         assert_eq!(future_2.await, Ok(value));
     }
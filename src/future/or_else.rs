@@ -1,6 +1,7 @@
 use crate::future::map::Map;
 use crate::support::fns::OrElseFn;
 use crate::support::{ResultFuture, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -19,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> OrElse<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: Map::new(fut, OrElseFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for OrElse<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OrElse").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for OrElse<Fut, F>
 where
     Fut: ResultFuture,
@@ -56,14 +66,13 @@ mod tests {
     use crate::future::future_ext::FutureExt;
     use futures_core::FusedFuture;
     use futures_util::future;
-    use std::mem;
 
-    #[allow(clippy::unnecessary_wraps)]
+    #[allow(clippy::unnecessary_wraps, reason = "Test function needs to return a `Try` type.")]
     fn ok_plus_3(value: u32) -> Result<u32, u32> {
         Ok(value + 3)
     }
 
-    #[allow(clippy::unnecessary_wraps)]
+    #[allow(clippy::unnecessary_wraps, reason = "Test function needs to return a `Try` type.")]
     fn err_plus_3(value: u32) -> Result<u32, u32> {
         Err(value + 3)
     }
@@ -105,7 +114,7 @@ mod tests {
         let base_future = make_base_future();
         let future = make_base_future().slim_or_else(err_plus_3);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await, Err(2));
         assert_eq!(future.await, Err(5));
     }
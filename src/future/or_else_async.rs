@@ -1,6 +1,7 @@
 use crate::future::map_err::MapErr;
 use crate::future::try_flatten_err::TryFlattenErr;
 use crate::support::{ResultFuture, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -25,7 +26,7 @@ where
     F: FnMut<(Fut::Error,)>,
     F::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, f: F) -> Self {
         Self {
             inner: TryFlattenErr::new(MapErr::new(fut, f)),
         }
@@ -46,6 +47,18 @@ where
     }
 }
 
+impl<Fut, F> Debug for OrElseAsync<Fut, F>
+where
+    Fut: ResultFuture + Debug,
+    F: FnMut<(Fut::Error,)>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OrElseAsync").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for OrElseAsync<Fut, F>
 where
     Fut: ResultFuture,
@@ -80,7 +93,6 @@ mod tests {
     use futures_core::FusedFuture;
     use futures_util::future::Ready;
     use futures_util::{future, TryFutureExt};
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn ok_plus_3(value: u32) -> Ready<Result<u32, u32>> {
@@ -134,8 +146,8 @@ mod tests {
         let future_1 = make_base_future().slim_or_else_async(err::err_by_copy);
         let future_2 = make_base_future().or_else(err::err_by_copy);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await, Err(()));
         assert_eq!(future_1.await, Err(()));
         assert_eq!(future_2.await, Err(()));
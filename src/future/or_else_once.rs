@@ -0,0 +1,126 @@
+use crate::future::map_once::MapOnce;
+use crate::support::fns::OrElseFn;
+use crate::support::{ResultFuture, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct OrElseOnce<Fut, F> {
+        #[pin]
+        inner: MapOnce<Fut, OrElseFn<F>>,
+    }
+}
+
+impl<Fut, F> OrElseOnce<Fut, F> {
+    pub const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: MapOnce::new(fut, OrElseFn::new(f)),
+        }
+    }
+}
+
+impl<Fut, F> Debug for OrElseOnce<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("OrElseOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for OrElseOnce<Fut, F>
+where
+    Fut: ResultFuture,
+    F: FnOnce<(Fut::Error,)>,
+    F::Output: Try<Output = Fut::Ok>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for OrElseOnce<Fut, F>
+where
+    Fut: ResultFuture,
+    F: FnOnce<(Fut::Error,)>,
+    F::Output: Try<Output = Fut::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::err;
+    use crate::future::future_ext::FutureExt;
+    use futures_core::FusedFuture;
+    use futures_util::future;
+    use std::num::NonZeroU32;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_or_else_once() {
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            future::ok::<u32, u32>(2)
+                .slim_or_else_once(move |value| Err((value, suffix)))
+                .await,
+            Ok(2),
+        );
+
+        let suffix = String::from("abc");
+
+        assert_eq!(
+            future::err::<u32, u32>(2)
+                .slim_or_else_once(move |value| Err((value, suffix)))
+                .await,
+            Err((2, String::from("abc"))),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_or_else_once_with_option() {
+        assert_eq!(
+            future::err::<u32, _>(2).slim_or_else_once(|x| Some(x + 3)).await,
+            Some(5)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_or_else_once_clone() {
+        let future = future::err::<u32, u32>(2).slim_or_else_once(|value| Err(value + 3));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Err(5));
+        assert_eq!(future_2.await, Err(5));
+    }
+
+    #[tokio::test]
+    async fn test_or_else_once_fused_future() {
+        let mut future = future::err::<u32, u32>(2).slim_or_else_once(|value| Err(value + 3));
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.by_ref().await, Err(5));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_or_else_once_is_slim() {
+        let make_base_future = || err::err_by_copy::<u32, _>(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future = make_base_future().slim_or_else_once(|value| Err::<u32, _>(value.get()));
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
+        assert_eq!(base_future.await.map_err(NonZeroU32::get), Err(2));
+        assert_eq!(future.await, Err(2));
+    }
+}
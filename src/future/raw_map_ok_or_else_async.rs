@@ -1,6 +1,7 @@
 use crate::future::map_async::MapAsync;
 use crate::support::fns::MapOkOrElseFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -35,6 +36,21 @@ where
     }
 }
 
+impl<Fut, D, F> Debug for RawMapOkOrElseAsync<Fut, D, F>
+where
+    Fut: ResultFuture + Debug,
+    D: FnMut<(Fut::Error,)>,
+    D::Output: IntoFuture,
+    <D::Output as IntoFuture>::IntoFuture: Debug,
+    F: FnMut<(Fut::Ok,), Output = D::Output>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("RawMapOkOrElseAsync")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl<Fut, D, F> RawMapOkOrElseAsync<Fut, D, F>
 where
     Fut: ResultFuture,
@@ -42,7 +58,7 @@ where
     D::Output: IntoFuture,
     F: FnMut<(Fut::Ok,), Output = D::Output>,
 {
-    pub(crate) fn new(fut: Fut, default: D, f: F) -> Self {
+    pub(crate) const fn new(fut: Fut, default: D, f: F) -> Self {
         Self {
             inner: MapAsync::new(fut, MapOkOrElseFn::new(default, f)),
         }
@@ -82,7 +98,6 @@ mod tests {
     use crate::future::{self, err, ok};
     use futures_core::FusedFuture;
     use futures_util::future::Ready;
-    use std::mem;
     use std::num::NonZeroU32;
 
     fn plus_3(value: u32) -> Ready<u32> {
@@ -127,7 +142,7 @@ mod tests {
         let f = |_| future::lazy(|_| 3);
         let future = make_base_future().slim_raw_map_ok_or_else_async(f, f);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await.map(NonZeroU32::get), Ok(2));
         assert_eq!(future.await, 3);
     }
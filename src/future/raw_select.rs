@@ -1,21 +1,47 @@
+use crate::future::select_strategy::{Biased, Fair, SelectStrategy};
 use crate::support;
+use crate::task::{SharedWaker, WakeMode};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use futures_core::FusedFuture;
 
 pin_project_lite::pin_project! {
-    pub struct RawSelect<Fut1, Fut2> {
+    pub struct RawSelect<Fut1, Fut2, S = Biased, W = SharedWaker> {
         #[pin]
         fut_1: Fut1,
         #[pin]
         fut_2: Fut2,
+        strategy: S,
+        wake_mode: W,
     }
 }
 
-impl<Fut1, Fut2> RawSelect<Fut1, Fut2> {
-    pub(crate) fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
-        Self { fut_1, fut_2 }
+impl<Fut1, Fut2, S, W> RawSelect<Fut1, Fut2, S, W>
+where
+    S: SelectStrategy,
+    W: WakeMode,
+{
+    pub const fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
+        Self {
+            fut_1,
+            fut_2,
+            strategy: S::INIT,
+            wake_mode: W::INIT,
+        }
+    }
+
+    pub fn with_wake_mode<W2>(self) -> RawSelect<Fut1, Fut2, S, W2>
+    where
+        W2: WakeMode,
+    {
+        RawSelect {
+            fut_1: self.fut_1,
+            fut_2: self.fut_2,
+            strategy: self.strategy,
+            wake_mode: W2::INIT,
+        }
     }
 
     #[must_use]
@@ -26,46 +52,81 @@ impl<Fut1, Fut2> RawSelect<Fut1, Fut2> {
     }
 }
 
-impl<Fut1, Fut2> Clone for RawSelect<Fut1, Fut2>
+impl<Fut1, Fut2, S, W> Clone for RawSelect<Fut1, Fut2, S, W>
 where
     Fut1: Clone,
     Fut2: Clone,
+    S: Clone,
+    W: Clone,
 {
     fn clone(&self) -> Self {
         Self {
             fut_1: self.fut_1.clone(),
             fut_2: self.fut_2.clone(),
+            strategy: self.strategy.clone(),
+            wake_mode: self.wake_mode.clone(),
         }
     }
 }
 
-impl<Fut1, Fut2> Future for RawSelect<Fut1, Fut2>
+impl<Fut1, Fut2, S, W> Debug for RawSelect<Fut1, Fut2, S, W>
+where
+    Fut1: Debug,
+    Fut2: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("RawSelect")
+            .field("fut_1", &self.fut_1)
+            .field("fut_2", &self.fut_2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Fut1, Fut2, S, W> Future for RawSelect<Fut1, Fut2, S, W>
 where
     Fut1: Future,
     Fut2: Future<Output = Fut1::Output>,
+    S: SelectStrategy,
+    W: WakeMode,
 {
     type Output = Fut1::Output;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
         let this = self.project();
+        let fut_1 = this.fut_1;
+        let fut_2 = this.fut_2;
+        let wake_mode = this.wake_mode;
+
+        wake_mode.begin(cx);
 
-        match this.fut_1.poll(cx) {
-            Poll::Ready(result) => Poll::Ready(result),
-            Poll::Pending => this.fut_2.poll(cx),
+        if this.strategy.next_is_reversed() {
+            match wake_mode.poll_branch(cx, 1, |cx| fut_2.poll(cx)) {
+                Poll::Ready(result) => Poll::Ready(result),
+                Poll::Pending => wake_mode.poll_branch(cx, 0, |cx| fut_1.poll(cx)),
+            }
+        } else {
+            match wake_mode.poll_branch(cx, 0, |cx| fut_1.poll(cx)) {
+                Poll::Ready(result) => Poll::Ready(result),
+                Poll::Pending => wake_mode.poll_branch(cx, 1, |cx| fut_2.poll(cx)),
+            }
         }
     }
 }
 
-impl<Fut1, Fut2> FusedFuture for RawSelect<Fut1, Fut2>
+impl<Fut1, Fut2, S, W> FusedFuture for RawSelect<Fut1, Fut2, S, W>
 where
     Fut1: FusedFuture,
     Fut2: FusedFuture<Output = Fut1::Output>,
+    S: SelectStrategy,
+    W: WakeMode,
 {
     fn is_terminated(&self) -> bool {
         self.fut_1.is_terminated() || self.fut_2.is_terminated()
     }
 }
 
+/// Polls `fut_1` before `fut_2` every time, so `fut_2` may starve if `fut_1` is always ready. See [`raw_select_fair`]
+/// for an unbiased alternative.
 pub fn raw_select<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> RawSelect<Fut1::IntoFuture, Fut2::IntoFuture>
 where
     Fut1: IntoFuture,
@@ -74,14 +135,24 @@ where
     support::assert_future::<_, Fut1::Output>(RawSelect::new(fut_1.into_future(), fut_2.into_future()))
 }
 
+/// Alternates which future is polled first, so neither future starves.
+pub fn raw_select_fair<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> RawSelect<Fut1::IntoFuture, Fut2::IntoFuture, Fair>
+where
+    Fut1: IntoFuture,
+    Fut2: IntoFuture<Output = Fut1::Output>,
+{
+    support::assert_future::<_, Fut1::Output>(RawSelect::new(fut_1.into_future(), fut_2.into_future()))
+}
+
 #[cfg(test)]
 mod tests {
+    use super::RawSelect;
     use crate::future::future_ext::FutureExt;
-    use crate::future::ready;
+    use crate::future::ready::{self, Ready};
+    use crate::future::select_strategy::Fair;
     use crate::{future, test_utilities};
     use futures_core::FusedFuture;
     use futures_util::FutureExt as _;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -101,6 +172,74 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_raw_select_in_const() {
+        type ConstReady = Ready<fn(&mut u32) -> u32, u32>;
+
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: RawSelect<ConstReady, ConstReady, Fair> =
+            RawSelect::new(ready::ready_by(get, 2), ready::ready_by(get, 3));
+
+        assert_eq!(FUTURE.await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_raw_select_fair() {
+        let mut future = super::raw_select_fair(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert_eq!((&mut future).now_or_never(), Some(2));
+        assert_eq!((&mut future).now_or_never(), Some(3));
+        assert_eq!((&mut future).now_or_never(), Some(2));
+
+        let mut future = super::raw_select(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert_eq!((&mut future).now_or_never(), Some(2));
+        assert_eq!((&mut future).now_or_never(), Some(2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn test_raw_select_per_branch_wakers() {
+        use crate::task::{PerBranchWakers, SharedWaker, WakeMode};
+        use core::cell::Cell;
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll};
+        use futures_util::task;
+
+        fn count_polls<W>(count: &Cell<usize>) -> Option<()>
+        where
+            W: WakeMode,
+        {
+            let fut_2 = futures_util::future::poll_fn(|_| {
+                count.set(count.get() + 1);
+
+                Poll::Pending
+            });
+
+            let mut future = pin!(super::raw_select(test_utilities::Yield::new(3), fut_2).with_wake_mode::<W>());
+            let mut cx = Context::from_waker(task::noop_waker_ref());
+
+            (0..4).find_map(|_| match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => Some(output),
+                Poll::Pending => None,
+            })
+        }
+
+        let count = Cell::new(0);
+
+        assert_eq!(count_polls::<SharedWaker>(&count), Some(()));
+        assert_eq!(count.get(), 3);
+
+        count.set(0);
+
+        assert_eq!(count_polls::<PerBranchWakers>(&count), Some(()));
+        assert_eq!(count.get(), 1);
+    }
+
     #[tokio::test]
     async fn test_raw_select_clone() {
         let future = super::raw_select(ready::ready_by_copy(2), ready::ready_by_copy(3));
@@ -136,9 +275,21 @@ mod tests {
         let base_future_2 = make_base_future_2();
         let future = super::raw_select(make_base_future_1(), make_base_future_2());
 
-        assert_eq!(mem::size_of_val(&base_future_2), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future_2), size_of_val(&future));
         assert_eq!(base_future_1.await, 2);
         assert_eq!(base_future_2.await, 3);
         assert_eq!(future.await, 2);
     }
+
+    #[tokio::test]
+    async fn test_raw_select_fair_is_slim() {
+        let make_base_future_1 = || ready::ready_by_copy(2_u8);
+        let make_base_future_2 = || ready::ready_by_copy(3_u8);
+        let future_1 = super::raw_select(make_base_future_1(), make_base_future_2());
+        let future_2 = super::raw_select_fair(make_base_future_1(), make_base_future_2());
+
+        assert!(size_of_val(&future_2) <= size_of_val(&future_1) + 1);
+        assert_eq!(future_1.await, 2);
+        assert_eq!(future_2.await, 2);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::support;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops;
 use core::pin::Pin;
@@ -13,11 +14,22 @@ pub struct Ready<G, T> {
 }
 
 impl<G, T> Ready<G, T> {
-    pub(crate) fn new(getter: G, value: T) -> Self {
+    pub const fn new(getter: G, value: T) -> Self {
         Self { getter, value }
     }
 }
 
+impl<G, T> Debug for Ready<G, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Ready")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
 impl<G, T> Unpin for Ready<G, T> {}
 
 impl<G, T> Future for Ready<G, T>
@@ -33,7 +45,7 @@ where
     }
 }
 
-pub fn ready_by<G, T>(getter: G, value: T) -> Ready<G, T>
+pub const fn ready_by<G, T>(getter: G, value: T) -> Ready<G, T>
 where
     G: ops::FnMut(&mut T) -> T,
 {
@@ -64,13 +76,24 @@ where
 #[cfg(test)]
 mod tests {
     use crate::future::ready;
-    use std::mem;
+    use crate::future::Ready;
 
     #[tokio::test]
     async fn test_ready() {
         assert_eq!(ready::ready_by_copy::<u32>(2).await, 2);
     }
 
+    #[tokio::test]
+    async fn test_ready_by_in_const() {
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: Ready<fn(&mut u32) -> u32, u32> = ready::ready_by(get, 2);
+
+        assert_eq!(FUTURE.await, 2);
+    }
+
     #[tokio::test]
     async fn test_ready_clone() {
         let future = ready::ready_by_copy::<u32>(2);
@@ -86,8 +109,8 @@ mod tests {
         let future_1 = ready::ready_by_copy(value);
         let future_2 = futures_util::future::ready(value);
 
-        assert_eq!(mem::size_of_val(&value), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&value), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(future_1.await, 2);
         assert_eq!(future_2.await, 2);
     }
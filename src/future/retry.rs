@@ -0,0 +1,250 @@
+use crate::future::retry_policy::RetryPolicy;
+use crate::support::{self, FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::{self, ControlFlow};
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    pub struct Retry<F, P>
+    where
+        F: FnMut<()>,
+        F::Output: IntoFuture,
+        <F::Output as IntoFuture>::Output: Try,
+        P: RetryPolicy<<<F::Output as IntoFuture>::Output as Try>::Residual>,
+    {
+        #[pin]
+        state: ThreeStates<<F::Output as IntoFuture>::IntoFuture, (), P::Delay, (), (), ()>,
+        attempts: usize,
+        make_future: F,
+        policy: P,
+    }
+}
+
+impl<F, P> Clone for Retry<F, P>
+where
+    F: FnMut<()> + Clone,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Clone,
+    <F::Output as IntoFuture>::Output: Try,
+    P: RetryPolicy<<<F::Output as IntoFuture>::Output as Try>::Residual> + Clone,
+    P::Delay: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            attempts: self.attempts,
+            make_future: self.make_future.clone(),
+            policy: self.policy.clone(),
+        }
+    }
+}
+
+impl<F, P> Debug for Retry<F, P>
+where
+    F: FnMut<()>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::IntoFuture: Debug,
+    <F::Output as IntoFuture>::Output: Try,
+    P: RetryPolicy<<<F::Output as IntoFuture>::Output as Try>::Residual>,
+    P::Delay: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("state", &self.state)
+            .field("attempts", &self.attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F, P> Future for Retry<F, P>
+where
+    F: FnMut<()>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try,
+    P: RetryPolicy<<<F::Output as IntoFuture>::Output as Try>::Residual>,
+{
+    type Output = <F::Output as IntoFuture>::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut state = this.state;
+
+        loop {
+            match state.as_mut().pin_project() {
+                ThreeStatesPinProject::A(mut attempt_state) => {
+                    let output = task::ready!(attempt_state.get_project().pinned.poll(cx));
+
+                    *this.attempts += 1;
+
+                    let residual = match output.branch() {
+                        ControlFlow::Continue(output) => {
+                            attempt_state.replace_state_c((), ());
+
+                            return Poll::Ready(Self::Output::from_output(output));
+                        }
+                        ControlFlow::Break(residual) => residual,
+                    };
+
+                    match this.policy.retry(&residual, *this.attempts) {
+                        ControlFlow::Continue(None) => {
+                            attempt_state.replace_state_a(this.make_future.call_mut(()).into_future(), ());
+                        }
+                        ControlFlow::Continue(Some(delay)) => {
+                            attempt_state.replace_state_b(delay, ());
+                        }
+                        ControlFlow::Break(()) => {
+                            attempt_state.replace_state_c((), ());
+
+                            return Poll::Ready(Self::Output::from_residual(residual));
+                        }
+                    }
+                }
+                ThreeStatesPinProject::B(mut delay_state) => {
+                    task::ready!(delay_state.get_project().pinned.poll(cx));
+
+                    delay_state.replace_state_a(this.make_future.call_mut(()).into_future(), ());
+                }
+                ThreeStatesPinProject::C(_) => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<F, P> FusedFuture for Retry<F, P>
+where
+    F: FnMut<()>,
+    F::Output: IntoFuture,
+    <F::Output as IntoFuture>::Output: Try,
+    P: RetryPolicy<<<F::Output as IntoFuture>::Output as Try>::Residual>,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, ThreeStates::C { .. })
+    }
+}
+
+/// Runs the future returned by `make_future`, and creates a new attempt whenever it fails and `policy` decides to
+/// retry. The first attempt is created eagerly.
+pub fn retry<F, Fut, P>(mut make_future: F, policy: P) -> Retry<F, P>
+where
+    F: ops::FnMut() -> Fut,
+    Fut: IntoFuture,
+    Fut::Output: Try,
+    P: RetryPolicy<<Fut::Output as Try>::Residual>,
+{
+    support::assert_future::<_, Fut::Output>(Retry {
+        state: ThreeStates::A {
+            pinned: make_future().into_future(),
+            unpinned: (),
+        },
+        attempts: 0,
+        make_future,
+        policy,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::retry_policy::{FixedBackoff, RetryPolicy};
+    use crate::time::ManualClock;
+    use core::convert::Infallible;
+    use core::future::{self, Future, Ready};
+    use core::ops::ControlFlow;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_core::FusedFuture;
+    use futures_util::task;
+
+    #[derive(Clone)]
+    struct Immediately {
+        max_retries: usize,
+    }
+
+    impl RetryPolicy<Result<Infallible, u32>> for Immediately {
+        type Delay = Ready<()>;
+
+        fn retry(
+            &mut self,
+            residual: &Result<Infallible, u32>,
+            attempts: usize,
+        ) -> ControlFlow<(), Option<Self::Delay>> {
+            if *residual == Err(7) || attempts > self.max_retries {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(None)
+            }
+        }
+    }
+
+    fn fail_until(success_at: u32) -> impl FnMut() -> Ready<Result<u32, u32>> + Clone {
+        let mut attempt = 0;
+
+        move || {
+            attempt += 1;
+
+            future::ready(if attempt < success_at {
+                Err(attempt)
+            } else {
+                Ok(attempt)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry() {
+        assert_eq!(super::retry(fail_until(1), Immediately { max_retries: 3 }).await, Ok(1));
+        assert_eq!(super::retry(fail_until(4), Immediately { max_retries: 3 }).await, Ok(4));
+        assert_eq!(
+            super::retry(fail_until(5), Immediately { max_retries: 3 }).await,
+            Err(4)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_sees_residual() {
+        assert_eq!(
+            super::retry(fail_until(10), Immediately { max_retries: 20 }).await,
+            Err(7)
+        );
+    }
+
+    #[test]
+    fn test_retry_with_delay() {
+        let clock = ManualClock::new();
+        let mut future = pin!(super::retry(
+            fail_until(3),
+            FixedBackoff::new(&clock, Duration::from_secs(2), 5)
+        ));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(Ok(3)));
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_retry_clone() {
+        let future = super::retry(fail_until(2), Immediately { max_retries: 3 });
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(2));
+        assert_eq!(future_2.await, Ok(2));
+    }
+}
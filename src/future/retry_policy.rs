@@ -0,0 +1,209 @@
+use crate::time::Timer;
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::time::Duration;
+use fn_traits::FnMut;
+
+pub trait RetryPolicy<R> {
+    type Delay: Future<Output = ()>;
+
+    /// Called after the `attempts`-th attempt failed with `residual`. Returns `Break(())` to give up,
+    /// `Continue(None)` to retry immediately, or `Continue(Some(delay))` to retry once `delay` completes.
+    fn retry(&mut self, residual: &R, attempts: usize) -> ControlFlow<(), Option<Self::Delay>>;
+}
+
+/// Waits the same delay before every retry.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedBackoff<T> {
+    timer: T,
+    delay: Duration,
+    max_retries: usize,
+}
+
+impl<T> FixedBackoff<T> {
+    pub const fn new(timer: T, delay: Duration, max_retries: usize) -> Self {
+        Self {
+            timer,
+            delay,
+            max_retries,
+        }
+    }
+}
+
+impl<R, T> RetryPolicy<R> for FixedBackoff<T>
+where
+    T: Timer,
+{
+    type Delay = T::Sleep;
+
+    fn retry(&mut self, _: &R, attempts: usize) -> ControlFlow<(), Option<Self::Delay>> {
+        if attempts > self.max_retries {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(Some(self.timer.sleep(self.delay)))
+        }
+    }
+}
+
+/// Doubles the delay after every retry, starting from `initial_delay` and capped at `max_delay`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialBackoff<T> {
+    timer: T,
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_retries: usize,
+}
+
+impl<T> ExponentialBackoff<T> {
+    pub const fn new(timer: T, initial_delay: Duration, max_delay: Duration, max_retries: usize) -> Self {
+        Self {
+            timer,
+            initial_delay,
+            max_delay,
+            max_retries,
+        }
+    }
+
+    fn delay(&self, attempts: usize) -> Duration {
+        u32::try_from(attempts.saturating_sub(1))
+            .ok()
+            .and_then(|exponent| 2_u32.checked_pow(exponent))
+            .and_then(|factor| self.initial_delay.checked_mul(factor))
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl<R, T> RetryPolicy<R> for ExponentialBackoff<T>
+where
+    T: Timer,
+{
+    type Delay = T::Sleep;
+
+    fn retry(&mut self, _: &R, attempts: usize) -> ControlFlow<(), Option<Self::Delay>> {
+        if attempts > self.max_retries {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(Some(self.timer.sleep(self.delay(attempts))))
+        }
+    }
+}
+
+/// Like [`ExponentialBackoff`], but scales every delay by a random factor in `[0, 1)` taken from `rng`, which should
+/// return uniformly distributed `u64` values.
+#[derive(Clone, Copy, Debug)]
+pub struct JitteredBackoff<T, G> {
+    inner: ExponentialBackoff<T>,
+    rng: G,
+}
+
+impl<T, G> JitteredBackoff<T, G> {
+    pub const fn new(timer: T, initial_delay: Duration, max_delay: Duration, max_retries: usize, rng: G) -> Self {
+        Self {
+            inner: ExponentialBackoff::new(timer, initial_delay, max_delay, max_retries),
+            rng,
+        }
+    }
+}
+
+impl<R, T, G> RetryPolicy<R> for JitteredBackoff<T, G>
+where
+    T: Timer,
+    G: FnMut<(), Output = u64>,
+{
+    type Delay = T::Sleep;
+
+    fn retry(&mut self, _: &R, attempts: usize) -> ControlFlow<(), Option<Self::Delay>> {
+        if attempts > self.inner.max_retries {
+            ControlFlow::Break(())
+        } else {
+            let nanos = self.inner.delay(attempts).as_nanos();
+            let factor = u128::from(self.rng.call_mut(()));
+
+            // Computes `nanos * factor / 2^64` in two halves so that it does not overflow.
+            let delay =
+                Duration::from_nanos_u128((nanos >> 64) * factor + (((nanos & u128::from(u64::MAX)) * factor) >> 64));
+
+            ControlFlow::Continue(Some(self.inner.timer.sleep(delay)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExponentialBackoff, FixedBackoff, JitteredBackoff, RetryPolicy};
+    use crate::time::{ManualClock, ManualSleep};
+    use core::ops::ControlFlow;
+    use core::time::Duration;
+
+    fn delays<'a, P>(mut policy: P) -> [Option<Duration>; 4]
+    where
+        P: RetryPolicy<(), Delay = ManualSleep<'a>>,
+    {
+        [1, 2, 3, 4].map(|attempts| match policy.retry(&(), attempts) {
+            ControlFlow::Continue(delay) => delay.map(|delay| delay.deadline().since_start()),
+            ControlFlow::Break(()) => None,
+        })
+    }
+
+    #[test]
+    fn test_fixed_backoff() {
+        let clock = ManualClock::new();
+
+        assert_eq!(
+            delays(FixedBackoff::new(&clock, Duration::from_secs(2), 3)),
+            [Some(2), Some(2), Some(2), None].map(|delay| delay.map(Duration::from_secs)),
+        );
+    }
+
+    #[test]
+    fn test_exponential_backoff() {
+        let clock = ManualClock::new();
+
+        assert_eq!(
+            delays(ExponentialBackoff::new(
+                &clock,
+                Duration::from_secs(1),
+                Duration::from_secs(3),
+                3
+            )),
+            [Some(1), Some(2), Some(3), None].map(|delay| delay.map(Duration::from_secs)),
+        );
+
+        assert_eq!(
+            ExponentialBackoff::new(&clock, Duration::from_secs(1), Duration::from_secs(5), 0).delay(usize::MAX),
+            Duration::from_secs(5),
+        );
+    }
+
+    #[test]
+    fn test_jittered_backoff() {
+        let clock = ManualClock::new();
+
+        assert_eq!(
+            delays(JitteredBackoff::new(
+                &clock,
+                Duration::from_secs(2),
+                Duration::from_secs(8),
+                3,
+                || 1 << 63,
+            )),
+            [Some(1), Some(2), Some(4), None].map(|delay| delay.map(Duration::from_secs)),
+        );
+    }
+
+    #[test]
+    fn test_jittered_backoff_huge_delay() {
+        let clock = ManualClock::new();
+        let half_max = Duration::from_nanos_u128(Duration::MAX.as_nanos() / 2);
+
+        assert_eq!(
+            delays(JitteredBackoff::new(&clock, Duration::MAX, Duration::MAX, 3, || 1 << 63)),
+            [Some(half_max), Some(half_max), Some(half_max), None],
+        );
+
+        assert_eq!(
+            delays(JitteredBackoff::new(&clock, Duration::MAX, Duration::MAX, 3, || 0)),
+            [Some(Duration::ZERO), Some(Duration::ZERO), Some(Duration::ZERO), None],
+        );
+    }
+}
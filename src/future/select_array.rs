@@ -0,0 +1,136 @@
+#![allow(unsafe_code, reason = "`pin-project-lite` does not support const generics.")]
+
+use crate::support;
+use crate::support::pin_array;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+#[derive(Clone)]
+pub struct SelectArray<Fut, const N: usize> {
+    futs: [Fut; N],
+}
+
+impl<Fut, const N: usize> SelectArray<Fut, N> {
+    pub(crate) const fn new(futs: [Fut; N]) -> Self {
+        const { assert!(N != 0, "`select_array` requires at least one future") };
+
+        Self { futs }
+    }
+
+    fn project_futs(self: Pin<&mut Self>) -> Pin<&mut [Fut; N]> {
+        // SAFETY: `futs` is structurally pinned: it is never moved out, and `SelectArray` has no `Drop` implementation.
+        unsafe { self.map_unchecked_mut(|this| &mut this.futs) }
+    }
+
+    pub fn get_inner_pinned(self: Pin<&mut Self>) -> impl Iterator<Item = Pin<&mut Fut>> {
+        pin_array::iter_pin_mut(self.project_futs())
+    }
+}
+
+impl<Fut, const N: usize> Debug for SelectArray<Fut, N>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SelectArray").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for SelectArray<Fut, N>
+where
+    Fut: Future,
+{
+    type Output = (Fut::Output, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        for (index, fut) in pin_array::iter_pin_mut(self.project_futs()).enumerate() {
+            if let Poll::Ready(output) = fut.poll(cx) {
+                return Poll::Ready((output, index));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Fut, const N: usize> FusedFuture for SelectArray<Fut, N>
+where
+    Fut: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.iter().any(FusedFuture::is_terminated)
+    }
+}
+
+/// Resolves to the output of the first ready future along with its index. An empty array is rejected at compile time,
+/// since it would never resolve:
+///
+/// ```compile_fail
+/// let _ = slim_futures::future::select_array::<core::future::Ready<u32>, 0>([]);
+/// ```
+pub fn select_array<Fut, const N: usize>(futs: [Fut; N]) -> SelectArray<Fut::IntoFuture, N>
+where
+    Fut: IntoFuture,
+{
+    support::assert_future::<_, (Fut::Output, usize)>(SelectArray::new(futs.map(IntoFuture::into_future)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+
+    #[tokio::test]
+    async fn test_select_array() {
+        assert_eq!(super::select_array([2, 3, 5].map(ready::ready_by_copy)).await, (2, 0));
+
+        assert_eq!(
+            super::select_array([
+                future::pending().left_future(),
+                test_utilities::delayed(ready::ready_by_copy(3)).right_future(),
+                future::pending().left_future(),
+            ])
+            .await,
+            (3, 1),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_array_clone() {
+        let future = super::select_array([2, 3].map(ready::ready_by_copy));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, (2, 0));
+        assert_eq!(future_2.await, (2, 0));
+    }
+
+    #[tokio::test]
+    async fn test_select_array_fused_future() {
+        let pending = || future::ready(());
+
+        let terminated = || {
+            let mut result = pending();
+
+            (&mut result).now_or_never().unwrap();
+
+            result
+        };
+
+        assert!(!super::select_array([pending(), pending()]).is_terminated());
+        assert!(super::select_array([pending(), terminated()]).is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_select_array_is_slim() {
+        let futs = [2, 3, 5].map(ready::ready_by_copy);
+        let future = super::select_array(futs.clone());
+
+        assert_eq!(size_of_val(&futs), size_of_val(&future));
+        assert_eq!(future.await, (2, 0));
+    }
+}
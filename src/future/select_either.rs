@@ -1,7 +1,9 @@
 use crate::future::map::Map;
 use crate::future::raw_select::RawSelect;
+use crate::future::select_strategy::{Biased, Fair, SelectStrategy};
 use crate::support;
 use crate::support::fns::{EitherLeftFn, EitherRightFn};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::pin::Pin;
 use core::task::{Context, Poll};
@@ -13,26 +15,27 @@ type RightFuture<A, B> = Map<B, EitherRightFn<<A as Future>::Output>>;
 
 pin_project_lite::pin_project! {
     #[derive(Clone)]
-    pub struct SelectEither<Fut1, Fut2>
+    pub struct SelectEither<Fut1, Fut2, S = Biased>
     where
         Fut1: Future,
         Fut2: Future,
     {
         #[pin]
-        inner: RawSelect<LeftFuture<Fut1, Fut2>, RightFuture<Fut1, Fut2>>
+        inner: RawSelect<LeftFuture<Fut1, Fut2>, RightFuture<Fut1, Fut2>, S>
     }
 }
 
-impl<Fut1, Fut2> SelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> SelectEither<Fut1, Fut2, S>
 where
     Fut1: Future,
     Fut2: Future,
+    S: SelectStrategy,
 {
-    pub(crate) fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
+    pub const fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
         Self {
             inner: RawSelect::new(
-                Map::new(fut_1, EitherLeftFn::default()),
-                Map::new(fut_2, EitherRightFn::default()),
+                Map::new(fut_1, EitherLeftFn::new()),
+                Map::new(fut_2, EitherRightFn::new()),
             ),
         }
     }
@@ -45,10 +48,21 @@ where
     }
 }
 
-impl<Fut1, Fut2> Future for SelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> Debug for SelectEither<Fut1, Fut2, S>
+where
+    Fut1: Future + Debug,
+    Fut2: Future + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SelectEither").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut1, Fut2, S> Future for SelectEither<Fut1, Fut2, S>
 where
     Fut1: Future,
     Fut2: Future,
+    S: SelectStrategy,
 {
     type Output = Either<Fut1::Output, Fut2::Output>;
 
@@ -57,16 +71,19 @@ where
     }
 }
 
-impl<Fut1, Fut2> FusedFuture for SelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> FusedFuture for SelectEither<Fut1, Fut2, S>
 where
     Fut1: FusedFuture,
     Fut2: FusedFuture,
+    S: SelectStrategy,
 {
     fn is_terminated(&self) -> bool {
         self.inner.is_terminated()
     }
 }
 
+/// Polls `fut_1` before `fut_2` every time, so `fut_2` may starve if `fut_1` is always ready. See
+/// [`select_either_fair`] for an unbiased alternative.
 pub fn select_either<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> SelectEither<Fut1::IntoFuture, Fut2::IntoFuture>
 where
     Fut1: IntoFuture,
@@ -78,15 +95,31 @@ where
     ))
 }
 
+/// Alternates which future is polled first, so neither future starves.
+pub fn select_either_fair<Fut1, Fut2>(
+    fut_1: Fut1,
+    fut_2: Fut2,
+) -> SelectEither<Fut1::IntoFuture, Fut2::IntoFuture, Fair>
+where
+    Fut1: IntoFuture,
+    Fut2: IntoFuture,
+{
+    support::assert_future::<_, Either<Fut1::Output, Fut2::Output>>(SelectEither::new(
+        fut_1.into_future(),
+        fut_2.into_future(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
+    use super::SelectEither;
     use crate::future::future_ext::FutureExt;
-    use crate::future::ready;
+    use crate::future::ready::{self, Ready};
+    use crate::future::select_strategy::Fair;
     use crate::{future, test_utilities};
     use futures_core::FusedFuture;
     use futures_util::future::Either;
     use futures_util::FutureExt as _;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -106,6 +139,29 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_select_either_in_const() {
+        type ConstReady = Ready<fn(&mut u32) -> u32, u32>;
+
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: SelectEither<ConstReady, ConstReady, Fair> =
+            SelectEither::new(ready::ready_by(get, 2), ready::ready_by(get, 3));
+
+        assert!(matches!(FUTURE.await, Either::Left(2)));
+    }
+
+    #[tokio::test]
+    async fn test_select_either_fair() {
+        let mut future = super::select_either_fair(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert!(matches!((&mut future).now_or_never(), Some(Either::Left(2))));
+        assert!(matches!((&mut future).now_or_never(), Some(Either::Right(3))));
+        assert!(matches!((&mut future).now_or_never(), Some(Either::Left(2))));
+    }
+
     #[tokio::test]
     async fn test_select_either_clone() {
         let future = super::select_either(ready::ready_by_copy(2), ready::ready_by_copy(3));
@@ -141,9 +197,21 @@ mod tests {
         let base_future_2 = make_base_future_2();
         let future = super::select_either(make_base_future_1(), make_base_future_2());
 
-        assert_eq!(mem::size_of_val(&base_future_2), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future_2), size_of_val(&future));
         assert_eq!(base_future_1.await, 2);
         assert_eq!(base_future_2.await, 3);
         assert!(matches!(future.await, Either::Left(2)));
     }
+
+    #[tokio::test]
+    async fn test_select_either_fair_is_slim() {
+        let make_base_future_1 = || ready::ready_by_copy(2_u8);
+        let make_base_future_2 = || ready::ready_by_copy(3_u8);
+        let future_1 = super::select_either(make_base_future_1(), make_base_future_2());
+        let future_2 = super::select_either_fair(make_base_future_1(), make_base_future_2());
+
+        assert!(size_of_val(&future_2) <= size_of_val(&future_1) + 1);
+        assert!(matches!(future_1.await, Either::Left(2)));
+        assert!(matches!(future_2.await, Either::Left(2)));
+    }
 }
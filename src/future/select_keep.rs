@@ -0,0 +1,138 @@
+use crate::support;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+use futures_util::future::Either;
+
+#[derive(Clone)]
+pub struct SelectKeep<Fut1, Fut2> {
+    futs: Option<(Fut1, Fut2)>,
+}
+
+impl<Fut1, Fut2> SelectKeep<Fut1, Fut2> {
+    pub(crate) const fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
+        Self {
+            futs: Some((fut_1, fut_2)),
+        }
+    }
+}
+
+impl<Fut1, Fut2> Debug for SelectKeep<Fut1, Fut2>
+where
+    Fut1: Debug,
+    Fut2: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SelectKeep").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut1, Fut2> Future for SelectKeep<Fut1, Fut2>
+where
+    Fut1: Future + Unpin,
+    Fut2: Future + Unpin,
+{
+    type Output = Either<(Fut1::Output, Fut2), (Fut2::Output, Fut1)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let futs = &mut self.get_mut().futs;
+
+        if let Some((fut_1, fut_2)) = futs {
+            if let Poll::Ready(output) = Pin::new(fut_1).poll(cx) {
+                if let Some((_, fut_2)) = futs.take() {
+                    return Poll::Ready(Either::Left((output, fut_2)));
+                }
+            } else if let Poll::Ready(output) = Pin::new(fut_2).poll(cx) {
+                if let Some((fut_1, _)) = futs.take() {
+                    return Poll::Ready(Either::Right((output, fut_1)));
+                }
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Fut1, Fut2> FusedFuture for SelectKeep<Fut1, Fut2>
+where
+    Fut1: Future + Unpin,
+    Fut2: Future + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.is_none()
+    }
+}
+
+pub const fn select_keep<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> SelectKeep<Fut1, Fut2>
+where
+    Fut1: Future + Unpin,
+    Fut2: Future + Unpin,
+{
+    support::assert_future::<_, Either<(Fut1::Output, Fut2), (Fut2::Output, Fut1)>>(SelectKeep::new(fut_1, fut_2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use core::pin::pin;
+    use futures_core::FusedFuture;
+    use futures_util::future::{self, Either};
+    use futures_util::FutureExt;
+
+    #[tokio::test]
+    async fn test_select_keep() {
+        let result = super::select_keep(ready::ready_by_copy(2), ready::ready_by_copy(3)).await;
+
+        assert!(matches!(result, Either::Left((2, _))));
+
+        if let Either::Left((_, fut_2)) = result {
+            assert_eq!(fut_2.await, 3);
+        }
+
+        let result = super::select_keep(
+            test_utilities::delayed(ready::ready_by_copy(2)),
+            ready::ready_by_copy(3),
+        )
+        .await;
+
+        assert!(matches!(result, Either::Right((3, _))));
+
+        if let Either::Right((_, fut_1)) = result {
+            assert_eq!(fut_1.await, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_select_keep_pinned() {
+        let mut fut_1 = pin!(future::pending::<u32>());
+        let mut fut_2 = pin!(test_utilities::delayed(ready::ready_by_copy(3)));
+
+        assert!(matches!(
+            super::select_keep(fut_1.as_mut(), fut_2.as_mut()).await,
+            Either::Right((3, _)),
+        ));
+
+        assert_eq!(fut_1.now_or_never(), None);
+    }
+
+    #[tokio::test]
+    async fn test_select_keep_clone() {
+        let future = super::select_keep(ready::ready_by_copy(2), ready::ready_by_copy(3));
+        let future_2 = future.clone();
+
+        assert!(matches!(future.await, Either::Left((2, _))));
+        assert!(matches!(future_2.await, Either::Left((2, _))));
+    }
+
+    #[tokio::test]
+    async fn test_select_keep_fused_future() {
+        let mut future = super::select_keep(ready::ready_by_copy(2), ready::ready_by_copy(3));
+
+        assert!(!future.is_terminated());
+        assert!(matches!((&mut future).await, Either::Left((2, _))));
+        assert!(future.is_terminated());
+    }
+}
@@ -0,0 +1,265 @@
+#![allow(unsafe_code, reason = "`pin-project-lite` does not support const generics.")]
+
+use crate::support::{self, pin_array, ResultFuture};
+use core::array;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use futures_core::FusedFuture;
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    struct State<Fut>
+    where
+        Fut: ResultFuture,
+    {
+        #[pin]
+        inner: ThreeStates<Fut, (), (), Fut::Error, (), ()>,
+    }
+}
+
+impl<Fut> State<Fut>
+where
+    Fut: ResultFuture,
+{
+    const fn new(fut: Fut) -> Self {
+        Self {
+            inner: ThreeStates::A {
+                pinned: fut,
+                unpinned: (),
+            },
+        }
+    }
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Fut::Ok>> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(mut project) => match task::ready!(project.get_project().pinned.poll(cx)) {
+                Ok(value) => return Poll::Ready(Some(value)),
+                Err(error) => drop(project.replace_state_b((), error)),
+            },
+            ThreeStatesPinProject::B(_) => {}
+            ThreeStatesPinProject::C(_) => return Poll::Pending,
+        }
+
+        Poll::Ready(None)
+    }
+
+    fn take_error(self: Pin<&mut Self>) -> Option<Fut::Error> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::B(project) => Some(project.replace_state_c((), ()).1),
+            _ => None,
+        }
+    }
+
+    fn clear(self: Pin<&mut Self>) {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(project) => drop(project.replace_state_c((), ())),
+            ThreeStatesPinProject::B(project) => drop(project.replace_state_c((), ())),
+            ThreeStatesPinProject::C(_) => {}
+        }
+    }
+
+    fn is_terminated(&self) -> bool {
+        matches!(self.inner, ThreeStates::C { .. })
+    }
+}
+
+impl<Fut> Clone for State<Fut>
+where
+    Fut: ResultFuture + Clone,
+    Fut::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut> Debug for State<Fut>
+where
+    Fut: ResultFuture + Debug,
+    Fut::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { pinned, .. } => f.debug_tuple("Future").field(pinned).finish(),
+            ThreeStates::B { unpinned, .. } => f.debug_tuple("Failed").field(unpinned).finish(),
+            ThreeStates::C { .. } => f.write_str("Gone"),
+        }
+    }
+}
+
+pub struct SelectOkArray<Fut, const N: usize>
+where
+    Fut: ResultFuture,
+{
+    futs: [State<Fut>; N],
+}
+
+impl<Fut, const N: usize> SelectOkArray<Fut, N>
+where
+    Fut: ResultFuture,
+{
+    pub(crate) fn new(futs: [Fut; N]) -> Self {
+        Self {
+            futs: futs.map(State::new),
+        }
+    }
+
+    fn project_futs(self: Pin<&mut Self>) -> Pin<&mut [State<Fut>; N]> {
+        // SAFETY: `futs` is structurally pinned: it is never moved out, and `SelectOkArray` has no `Drop`
+        // implementation.
+        unsafe { self.map_unchecked_mut(|this| &mut this.futs) }
+    }
+}
+
+impl<Fut, const N: usize> Clone for SelectOkArray<Fut, N>
+where
+    Fut: ResultFuture + Clone,
+    Fut::Error: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            futs: self.futs.clone(),
+        }
+    }
+}
+
+impl<Fut, const N: usize> Debug for SelectOkArray<Fut, N>
+where
+    Fut: ResultFuture + Debug,
+    Fut::Error: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SelectOkArray").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for SelectOkArray<Fut, N>
+where
+    Fut: ResultFuture,
+{
+    type Output = Result<(Fut::Ok, usize), [Fut::Error; N]>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut futs = self.project_futs();
+
+        let value = 'value: {
+            let mut is_ready = true;
+
+            for (index, fut) in pin_array::iter_pin_mut(futs.as_mut()).enumerate() {
+                match fut.poll(cx) {
+                    Poll::Ready(None) => {}
+                    Poll::Ready(Some(value)) => break 'value (value, index),
+                    Poll::Pending => is_ready = false,
+                }
+            }
+
+            return if is_ready {
+                let mut futs = pin_array::iter_pin_mut(futs);
+
+                Poll::Ready(Err(array::from_fn(|_| futs.next().unwrap().take_error().unwrap())))
+            } else {
+                Poll::Pending
+            };
+        };
+
+        pin_array::iter_pin_mut(futs).for_each(State::clear);
+
+        Poll::Ready(Ok(value))
+    }
+}
+
+impl<Fut, const N: usize> FusedFuture for SelectOkArray<Fut, N>
+where
+    Fut: ResultFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.iter().all(State::is_terminated)
+    }
+}
+
+pub fn select_ok_array<Fut, const N: usize>(futs: [Fut; N]) -> SelectOkArray<Fut::IntoFuture, N>
+where
+    Fut: IntoFuture,
+    Fut::IntoFuture: ResultFuture,
+{
+    support::assert_future::<
+        _,
+        Result<(<Fut::IntoFuture as ResultFuture>::Ok, usize), [<Fut::IntoFuture as ResultFuture>::Error; N]>,
+    >(SelectOkArray::new(futs.map(IntoFuture::into_future)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+
+    #[tokio::test]
+    async fn test_select_ok_array() {
+        assert_eq!(
+            super::select_ok_array([Err::<u32, u32>(2), Ok(3), Ok(5)].map(ready::ready_by_copy)).await,
+            Ok((3, 1)),
+        );
+
+        assert_eq!(
+            super::select_ok_array([
+                ready::ready_by_copy(Err::<u32, u32>(2)).left_future(),
+                test_utilities::delayed(ready::ready_by_copy(Ok(3))).right_future(),
+                ready::ready_by_copy(Err(5)).left_future(),
+            ])
+            .await,
+            Ok((3, 1)),
+        );
+
+        assert_eq!(
+            super::select_ok_array([
+                ready::ready_by_copy(Err::<u32, u32>(2)).left_future(),
+                future::pending().right_future(),
+            ])
+            .now_or_never(),
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_ok_array_all_errors() {
+        assert_eq!(
+            super::select_ok_array([
+                ready::ready_by_copy(Err::<u32, u32>(2)).left_future(),
+                test_utilities::delayed(ready::ready_by_copy(Err(3))).right_future(),
+            ])
+            .await,
+            Err([2, 3]),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_select_ok_array_clone() {
+        let future = super::select_ok_array([Err::<u32, u32>(2), Ok(3)].map(ready::ready_by_copy));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok((3, 1)));
+        assert_eq!(future_2.await, Ok((3, 1)));
+    }
+
+    #[tokio::test]
+    async fn test_select_ok_array_fused_future() {
+        let mut future = super::select_ok_array([Err::<u32, u32>(2), Ok(3)].map(ready::ready_by_copy));
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some(Ok((3, 1))));
+        assert!(future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), None);
+
+        let mut future = super::select_ok_array([Err::<u32, u32>(2), Err(3)].map(ready::ready_by_copy));
+
+        assert_eq!((&mut future).now_or_never(), Some(Err([2, 3])));
+        assert!(future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), None);
+    }
+}
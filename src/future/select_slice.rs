@@ -0,0 +1,105 @@
+use crate::support;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+pub struct SelectSlice<'a, 'b, Fut> {
+    futs: &'a mut [Pin<&'b mut Fut>],
+}
+
+impl<'a, 'b, Fut> SelectSlice<'a, 'b, Fut> {
+    pub(crate) const fn new(futs: &'a mut [Pin<&'b mut Fut>]) -> Self {
+        assert!(!futs.is_empty(), "`select_slice` requires at least one future");
+
+        Self { futs }
+    }
+}
+
+impl<Fut> Debug for SelectSlice<'_, '_, Fut>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SelectSlice").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut> Future for SelectSlice<'_, '_, Fut>
+where
+    Fut: Future,
+{
+    type Output = (Fut::Output, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        for (index, fut) in self.futs.iter_mut().enumerate() {
+            if let Poll::Ready(output) = fut.as_mut().poll(cx) {
+                return Poll::Ready((output, index));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Fut> FusedFuture for SelectSlice<'_, '_, Fut>
+where
+    Fut: FusedFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.iter().any(FusedFuture::is_terminated)
+    }
+}
+
+/// Resolves to the output of the first ready future along with its index.
+///
+/// # Panics
+///
+/// Panics if `futs` is empty, since the future would never resolve.
+pub const fn select_slice<'a, 'b, Fut>(futs: &'a mut [Pin<&'b mut Fut>]) -> SelectSlice<'a, 'b, Fut>
+where
+    Fut: Future,
+{
+    support::assert_future::<_, (Fut::Output, usize)>(SelectSlice::new(futs))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use core::pin::{pin, Pin};
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+
+    #[tokio::test]
+    async fn test_select_slice() {
+        let mut fut_1 = pin!(future::pending().left_future());
+        let mut fut_2 = pin!(test_utilities::delayed(ready::ready_by_copy(3)).right_future());
+        let mut fut_3 = pin!(future::pending().left_future());
+        let mut futs = [fut_1.as_mut(), fut_2.as_mut(), fut_3.as_mut()];
+
+        assert_eq!(super::select_slice(&mut futs).await, (3, 1));
+    }
+
+    #[tokio::test]
+    async fn test_select_slice_reuse() {
+        let mut fut_1 = pin!(ready::ready_by_copy(2).fuse());
+        let mut fut_2 = pin!(ready::ready_by_copy(3).fuse());
+        let mut futs = [fut_1.as_mut(), fut_2.as_mut()];
+        let mut future = super::select_slice(&mut futs);
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, (2, 0));
+        assert!(future.is_terminated());
+        assert_eq!(super::select_slice(&mut futs[1..]).await, (3, 0));
+    }
+
+    #[tokio::test]
+    #[should_panic = "`select_slice` requires at least one future"]
+    async fn test_select_slice_empty() {
+        let mut futs: [Pin<&mut future::Pending<u32>>; 0] = [];
+
+        super::select_slice(&mut futs).await;
+    }
+}
@@ -0,0 +1,59 @@
+pub trait SelectStrategy {
+    const INIT: Self;
+
+    fn next_is_reversed(&mut self) -> bool;
+}
+
+/// Always polls the first future first.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Biased;
+
+impl SelectStrategy for Biased {
+    const INIT: Self = Self;
+
+    fn next_is_reversed(&mut self) -> bool {
+        false
+    }
+}
+
+/// Alternates which future is polled first on every poll.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Fair {
+    reversed: bool,
+}
+
+impl SelectStrategy for Fair {
+    const INIT: Self = Self { reversed: false };
+
+    fn next_is_reversed(&mut self) -> bool {
+        let reversed = self.reversed;
+
+        self.reversed = !reversed;
+
+        reversed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Biased, Fair, SelectStrategy};
+
+    #[test]
+    fn test_biased() {
+        let mut strategy = Biased::INIT;
+
+        assert!(!strategy.next_is_reversed());
+        assert!(!strategy.next_is_reversed());
+        assert_eq!(size_of_val(&strategy), 0);
+    }
+
+    #[test]
+    fn test_fair() {
+        let mut strategy = Fair::INIT;
+
+        assert!(!strategy.next_is_reversed());
+        assert!(strategy.next_is_reversed());
+        assert!(!strategy.next_is_reversed());
+        assert_eq!(size_of_val(&strategy), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::future::map::Map;
+use crate::future::select_either::SelectEither;
+use crate::time::TimedOut;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+use futures_util::future::Either;
+
+#[derive(Clone)]
+struct TimeoutFn;
+
+impl<T> FnMut<(Either<T, ()>,)> for TimeoutFn {
+    type Output = Result<T, TimedOut>;
+
+    fn call_mut(&mut self, args: (Either<T, ()>,)) -> Self::Output {
+        match args.0 {
+            Either::Left(output) => Ok(output),
+            Either::Right(()) => Err(TimedOut),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct Timeout<Fut, S>
+    where
+        Fut: Future,
+        S: Future,
+    {
+        #[pin]
+        inner: Map<SelectEither<Fut, S>, TimeoutFn>,
+    }
+}
+
+impl<Fut, S> Timeout<Fut, S>
+where
+    Fut: Future,
+    S: Future,
+{
+    pub(crate) fn new(fut: Fut, sleep: S) -> Self {
+        Self {
+            inner: Map::new(SelectEither::new(fut, sleep), TimeoutFn),
+        }
+    }
+}
+
+impl<Fut, S> Clone for Timeout<Fut, S>
+where
+    Fut: Future + Clone,
+    S: Future + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut, S> Debug for Timeout<Fut, S>
+where
+    Fut: Future + Debug,
+    S: Future + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Timeout").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, S> Future for Timeout<Fut, S>
+where
+    Fut: Future,
+    S: Future<Output = ()>,
+{
+    type Output = Result<Fut::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, S> FusedFuture for Timeout<Fut, S>
+where
+    Fut: FusedFuture,
+    S: FusedFuture<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use crate::time::{ManualClock, TimedOut};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_util::{future, task};
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let clock = ManualClock::new();
+
+        assert_eq!(
+            ready::ready_by_copy(2)
+                .slim_timeout(&clock, Duration::from_secs(1))
+                .await,
+            Ok(2)
+        );
+
+        assert_eq!(
+            ready::ready_by_copy(2).slim_timeout(&clock, Duration::ZERO).await,
+            Ok(2)
+        );
+
+        assert_eq!(
+            future::pending::<u32>().slim_timeout(&clock, Duration::ZERO).await,
+            Err(TimedOut)
+        );
+    }
+
+    #[test]
+    fn test_timeout_manual_clock() {
+        let clock = ManualClock::new();
+        let mut future = pin!(future::pending::<u32>().slim_timeout(&clock, Duration::from_secs(3)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(2));
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(Err(TimedOut)));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_clone() {
+        let clock = ManualClock::new();
+        let future = ready::ready_by_copy(2).slim_timeout(&clock, Duration::from_secs(1));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(2));
+        assert_eq!(future_2.await, Ok(2));
+    }
+}
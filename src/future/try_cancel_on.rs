@@ -0,0 +1,152 @@
+use crate::future::map::Map;
+use crate::future::select_either::SelectEither;
+use crate::task::Cancelled;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnMut;
+use futures_core::FusedFuture;
+use futures_util::future::Either;
+
+#[derive(Clone)]
+struct TryCancelOnFn;
+
+impl<T> FnMut<(Either<T, ()>,)> for TryCancelOnFn {
+    type Output = Result<T, Cancelled>;
+
+    fn call_mut(&mut self, args: (Either<T, ()>,)) -> Self::Output {
+        match args.0 {
+            Either::Left(output) => Ok(output),
+            Either::Right(()) => Err(Cancelled),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct TryCancelOn<Fut, S>
+    where
+        Fut: Future,
+        S: Future,
+    {
+        #[pin]
+        inner: Map<SelectEither<Fut, S>, TryCancelOnFn>,
+    }
+}
+
+impl<Fut, S> TryCancelOn<Fut, S>
+where
+    Fut: Future,
+    S: Future,
+{
+    pub(crate) fn new(fut: Fut, signal: S) -> Self {
+        Self {
+            inner: Map::new(SelectEither::new(fut, signal), TryCancelOnFn),
+        }
+    }
+}
+
+impl<Fut, S> Clone for TryCancelOn<Fut, S>
+where
+    Fut: Future + Clone,
+    S: Future + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut, S> Debug for TryCancelOn<Fut, S>
+where
+    Fut: Future + Debug,
+    S: Future + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryCancelOn").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, S> Future for TryCancelOn<Fut, S>
+where
+    Fut: Future,
+    S: Future<Output = ()>,
+{
+    type Output = Result<Fut::Output, Cancelled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, S> FusedFuture for TryCancelOn<Fut, S>
+where
+    Fut: FusedFuture,
+    S: FusedFuture<Output = ()>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::future_ext::FutureExt;
+    use crate::future::ready;
+    use crate::task::Cancelled;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt as _};
+
+    #[tokio::test]
+    async fn test_try_cancel_on() {
+        assert_eq!(
+            ready::ready_by_copy(2).slim_try_cancel_on(future::pending()).await,
+            Ok(2)
+        );
+        assert_eq!(
+            future::pending::<u32>()
+                .slim_try_cancel_on(ready::ready_by_copy(()))
+                .await,
+            Err(Cancelled)
+        );
+
+        assert_eq!(
+            test_utilities::delayed(ready::ready_by_copy(2))
+                .slim_try_cancel_on(ready::ready_by_copy(()))
+                .await,
+            Err(Cancelled),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_cancel_on_prefers_output() {
+        assert_eq!(
+            ready::ready_by_copy(2)
+                .slim_try_cancel_on(ready::ready_by_copy(()))
+                .await,
+            Ok(2),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_cancel_on_clone() {
+        let future = ready::ready_by_copy(2).slim_try_cancel_on(future::pending());
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok(2));
+        assert_eq!(future_2.await, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn test_try_cancel_on_fused_future() {
+        let mut future = future::pending::<u32>()
+            .fuse()
+            .slim_try_cancel_on(future::ready(()).fuse());
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).await, Err(Cancelled));
+        assert!(future.is_terminated());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::support::states::TwoPhases;
 use crate::support::{FromResidual, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -24,7 +25,7 @@ where
     Fut::Output: Try,
     <Fut::Output as Try>::Output: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut) -> Self {
+    pub(crate) const fn new(fut: Fut) -> Self {
         Self {
             inner: TwoPhases::new(fut),
         }
@@ -45,6 +46,18 @@ where
     }
 }
 
+impl<Fut> Debug for TryFlatten<Fut>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Output: IntoFuture,
+    <<Fut::Output as Try>::Output as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFlatten").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut> Future for TryFlatten<Fut>
 where
     Fut: Future,
@@ -96,7 +109,6 @@ mod tests {
     use futures_core::FusedFuture;
     use futures_util::future::Ready;
     use futures_util::TryFutureExt;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -169,8 +181,8 @@ mod tests {
         let future_1 = make_base_future().slim_try_flatten();
         let future_2 = make_base_future().try_flatten();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future_1));
-        assert!(mem::size_of_val(&future_1) < mem::size_of_val(&future_2));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future_1));
+        assert!(size_of_val(&future_1) < size_of_val(&future_2));
         assert_eq!(base_future.await.unwrap().await, Ok(()));
         assert_eq!(future_1.await, Ok(()));
         assert_eq!(future_2.await, Ok(()));
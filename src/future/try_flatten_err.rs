@@ -1,5 +1,6 @@
 use crate::support::states::TwoPhases;
 use crate::support::{ResultFuture, Try};
+use core::fmt::{self, Debug, Formatter};
 use core::future::{Future, IntoFuture};
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -22,7 +23,7 @@ where
     Fut: ResultFuture,
     Fut::Error: IntoFuture,
 {
-    pub(crate) fn new(fut: Fut) -> Self {
+    pub(crate) const fn new(fut: Fut) -> Self {
         Self {
             inner: TwoPhases::new(fut),
         }
@@ -42,6 +43,17 @@ where
     }
 }
 
+impl<Fut> Debug for TryFlattenErr<Fut>
+where
+    Fut: ResultFuture + Debug,
+    Fut::Error: IntoFuture,
+    <Fut::Error as IntoFuture>::IntoFuture: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryFlattenErr").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut> Future for TryFlattenErr<Fut>
 where
     Fut: ResultFuture,
@@ -87,7 +99,6 @@ mod tests {
     use crate::test_utilities::Yield;
     use futures_core::FusedFuture;
     use futures_util::future::Ready;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -144,7 +155,7 @@ mod tests {
         let base_future = make_base_future();
         let future = make_base_future().slim_try_flatten_err();
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await.unwrap_err().await, Err(()));
         assert_eq!(future.await, Err(()));
     }
@@ -0,0 +1,336 @@
+#![allow(clippy::struct_field_names, reason = "Fields are named after the futures they hold.")]
+
+use crate::support;
+use crate::support::states::TryMaybeDone;
+use crate::support::{FromResidual, Residual, Try};
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+macro_rules! define_try_join {
+    ($name:ident, $fn_name:ident, $first_fut:ident: $first_field:ident, $($fut:ident: $field:ident),+) => {
+        pin_project_lite::pin_project! {
+            pub struct $name<$first_fut, $($fut),+>
+            where
+                $first_fut: Future,
+                $first_fut::Output: Try,
+                $(
+                    $fut: Future,
+                    $fut::Output: Try,
+                )+
+            {
+                #[pin]
+                $first_field: TryMaybeDone<$first_fut>,
+                $(
+                    #[pin]
+                    $field: TryMaybeDone<$fut>,
+                )+
+            }
+        }
+
+        impl<$first_fut, $($fut),+> $name<$first_fut, $($fut),+>
+        where
+            $first_fut: Future,
+            $first_fut::Output: Try,
+            $(
+                $fut: Future,
+                $fut::Output: Try,
+            )+
+        {
+            pub(crate) const fn new($first_field: $first_fut, $($field: $fut),+) -> Self {
+                Self {
+                    $first_field: TryMaybeDone::new($first_field),
+                    $($field: TryMaybeDone::new($field),)+
+                }
+            }
+        }
+
+        impl<$first_fut, $($fut),+> Clone for $name<$first_fut, $($fut),+>
+        where
+            $first_fut: Future + Clone,
+            $first_fut::Output: Try,
+            <$first_fut::Output as Try>::Output: Clone,
+            $(
+                $fut: Future + Clone,
+                $fut::Output: Try,
+                <$fut::Output as Try>::Output: Clone,
+            )+
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    $first_field: self.$first_field.clone(),
+                    $($field: self.$field.clone(),)+
+                }
+            }
+        }
+
+        impl<$first_fut, $($fut),+> Debug for $name<$first_fut, $($fut),+>
+        where
+            $first_fut: Future + Debug,
+            $first_fut::Output: Try,
+            <$first_fut::Output as Try>::Output: Debug,
+            $(
+                $fut: Future + Debug,
+                $fut::Output: Try,
+                <$fut::Output as Try>::Output: Debug,
+            )+
+        {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field(stringify!($first_field), &self.$first_field)
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+
+        impl<$first_fut, $($fut),+> Future for $name<$first_fut, $($fut),+>
+        where
+            $first_fut: Future,
+            $first_fut::Output: Try,
+            $(
+                $fut: Future,
+                $fut::Output: Try<Residual = <$first_fut::Output as Try>::Residual>,
+            )+
+            <$first_fut::Output as Try>::Residual:
+                Residual<(<$first_fut::Output as Try>::Output, $(<$fut::Output as Try>::Output,)+)>,
+        {
+            type Output = <<$first_fut::Output as Try>::Residual as Residual<(
+                <$first_fut::Output as Try>::Output,
+                $(<$fut::Output as Try>::Output,)+
+            )>>::TryType;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+                let mut this = self.project();
+
+                let residual = 'residual: {
+                    let mut is_ready = true;
+
+                    match this.$first_field.as_mut().poll(cx) {
+                        Poll::Ready(ControlFlow::Continue(())) => {}
+                        Poll::Ready(ControlFlow::Break(residual)) => break 'residual residual,
+                        Poll::Pending => is_ready = false,
+                    }
+
+                    $(
+                        match this.$field.as_mut().poll(cx) {
+                            Poll::Ready(ControlFlow::Continue(())) => {}
+                            Poll::Ready(ControlFlow::Break(residual)) => break 'residual residual,
+                            Poll::Pending => is_ready = false,
+                        }
+                    )+
+
+                    return if is_ready {
+                        Poll::Ready(Self::Output::from_output((
+                            this.$first_field.take_output().unwrap(),
+                            $(this.$field.take_output().unwrap(),)+
+                        )))
+                    } else {
+                        Poll::Pending
+                    };
+                };
+
+                this.$first_field.clear();
+
+                $(
+                    this.$field.clear();
+                )+
+
+                Poll::Ready(Self::Output::from_residual(residual))
+            }
+        }
+
+        impl<$first_fut, $($fut),+> FusedFuture for $name<$first_fut, $($fut),+>
+        where
+            $first_fut: Future,
+            $first_fut::Output: Try,
+            $(
+                $fut: Future,
+                $fut::Output: Try<Residual = <$first_fut::Output as Try>::Residual>,
+            )+
+            <$first_fut::Output as Try>::Residual:
+                Residual<(<$first_fut::Output as Try>::Output, $(<$fut::Output as Try>::Output,)+)>,
+        {
+            fn is_terminated(&self) -> bool {
+                self.$first_field.is_terminated() $(&& self.$field.is_terminated())+
+            }
+        }
+
+        pub fn $fn_name<$first_fut, $($fut),+>(
+            $first_field: $first_fut,
+            $($field: $fut),+
+        ) -> $name<$first_fut::IntoFuture, $($fut::IntoFuture),+>
+        where
+            $first_fut: IntoFuture,
+            $first_fut::Output: Try,
+            $(
+                $fut: IntoFuture,
+                $fut::Output: Try<Residual = <$first_fut::Output as Try>::Residual>,
+            )+
+            <$first_fut::Output as Try>::Residual:
+                Residual<(<$first_fut::Output as Try>::Output, $(<$fut::Output as Try>::Output,)+)>,
+        {
+            support::assert_future::<
+                _,
+                <<$first_fut::Output as Try>::Residual as Residual<(
+                    <$first_fut::Output as Try>::Output,
+                    $(<$fut::Output as Try>::Output,)+
+                )>>::TryType,
+            >($name::new($first_field.into_future(), $($field.into_future()),+))
+        }
+    };
+}
+
+define_try_join!(TryJoin, try_join, Fut1: fut_1, Fut2: fut_2);
+define_try_join!(TryJoin3, try_join3, Fut1: fut_1, Fut2: fut_2, Fut3: fut_3);
+define_try_join!(TryJoin4, try_join4, Fut1: fut_1, Fut2: fut_2, Fut3: fut_3, Fut4: fut_4);
+define_try_join!(TryJoin5, try_join5, Fut1: fut_1, Fut2: fut_2, Fut3: fut_3, Fut4: fut_4, Fut5: fut_5);
+define_try_join!(TryJoin6, try_join6, Fut1: fut_1, Fut2: fut_2, Fut3: fut_3, Fut4: fut_4, Fut5: fut_5, Fut6: fut_6);
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use core::ops::ControlFlow;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+
+    #[tokio::test]
+    async fn test_try_join() {
+        assert_eq!(
+            super::try_join(ready::ready_by_copy(Ok::<u32, u32>(2)), ready::ready_by_copy(Ok(3))).await,
+            Ok((2, 3)),
+        );
+
+        assert_eq!(
+            super::try_join(
+                test_utilities::delayed(ready::ready_by_copy(Ok::<u32, u32>(2))),
+                ready::ready_by_copy(Ok(3)),
+            )
+            .await,
+            Ok((2, 3)),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_error() {
+        assert_eq!(
+            super::try_join(
+                future::pending::<Result<u32, u32>>(),
+                ready::ready_by_copy(Err::<u32, u32>(3))
+            )
+            .await,
+            Err(3),
+        );
+
+        assert_eq!(
+            super::try_join(
+                ready::ready_by_copy(Err::<u32, u32>(2)),
+                future::pending::<Result<u32, u32>>()
+            )
+            .await,
+            Err(2),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_with_option() {
+        assert_eq!(
+            super::try_join(ready::ready_by_copy(Some(2)), ready::ready_by_copy(Some(3))).await,
+            Some((2, 3)),
+        );
+
+        assert_eq!(
+            super::try_join(future::pending::<Option<u32>>(), ready::ready_by_copy(None::<u32>)).await,
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_with_control_flow() {
+        assert_eq!(
+            super::try_join(
+                ready::ready_by_copy(ControlFlow::<u32, u32>::Continue(2)),
+                ready::ready_by_copy(ControlFlow::Continue(3)),
+            )
+            .await,
+            ControlFlow::Continue((2, 3)),
+        );
+
+        assert_eq!(
+            super::try_join(
+                future::pending::<ControlFlow<u32, u32>>(),
+                ready::ready_by_copy(ControlFlow::<u32, u32>::Break(3)),
+            )
+            .await,
+            ControlFlow::Break(3),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_tuples() {
+        let ok = |value: u32| ready::ready_by_copy(Ok::<u32, u32>(value));
+        let delayed_ok = |value: u32| test_utilities::delayed(ready::ready_by_copy(Ok::<u32, u32>(value)));
+
+        assert_eq!(
+            super::try_join3(delayed_ok(2), ok(3), delayed_ok(5)).await,
+            Ok((2, 3, 5))
+        );
+        assert_eq!(
+            super::try_join4(delayed_ok(2), ok(3), delayed_ok(5), ok(7)).await,
+            Ok((2, 3, 5, 7))
+        );
+
+        assert_eq!(
+            super::try_join5(delayed_ok(2), ok(3), delayed_ok(5), ok(7), delayed_ok(11)).await,
+            Ok((2, 3, 5, 7, 11)),
+        );
+
+        assert_eq!(
+            super::try_join6(delayed_ok(2), ok(3), delayed_ok(5), ok(7), delayed_ok(11), ok(13)).await,
+            Ok((2, 3, 5, 7, 11, 13)),
+        );
+
+        assert_eq!(
+            super::try_join6(
+                delayed_ok(2),
+                ok(3),
+                delayed_ok(5),
+                ready::ready_by_copy(Err::<u32, u32>(7)),
+                delayed_ok(11),
+                ok(13),
+            )
+            .await,
+            Err(7),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_clone() {
+        let future = super::try_join(ready::ready_by_copy(Ok::<u32, u32>(2)), ready::ready_by_copy(Ok(3)));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok((2, 3)));
+        assert_eq!(future_2.await, Ok((2, 3)));
+    }
+
+    #[tokio::test]
+    async fn test_try_join_fused_future() {
+        let mut future = super::try_join(ready::ready_by_copy(Ok::<u32, u32>(2)), ready::ready_by_copy(Ok(3)));
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some(Ok((2, 3))));
+        assert!(future.is_terminated());
+
+        let mut future = super::try_join(
+            future::pending::<Result<u32, u32>>(),
+            ready::ready_by_copy(Err::<u32, u32>(3)),
+        );
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some(Err(3)));
+        assert!(future.is_terminated());
+    }
+}
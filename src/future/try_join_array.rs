@@ -0,0 +1,190 @@
+#![allow(unsafe_code, reason = "`pin-project-lite` does not support const generics.")]
+
+use crate::support;
+use crate::support::pin_array;
+use crate::support::states::TryMaybeDone;
+use crate::support::{FromResidual, Residual, Try};
+use core::array;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{Future, IntoFuture};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+
+pub struct TryJoinArray<Fut, const N: usize>
+where
+    Fut: Future,
+    Fut::Output: Try,
+{
+    futs: [TryMaybeDone<Fut>; N],
+}
+
+impl<Fut, const N: usize> TryJoinArray<Fut, N>
+where
+    Fut: Future,
+    Fut::Output: Try,
+{
+    pub(crate) fn new(futs: [Fut; N]) -> Self {
+        Self {
+            futs: futs.map(TryMaybeDone::new),
+        }
+    }
+
+    fn project_futs(self: Pin<&mut Self>) -> Pin<&mut [TryMaybeDone<Fut>; N]> {
+        // SAFETY: `futs` is structurally pinned: it is never moved out, and `TryJoinArray` has no `Drop`
+        // implementation.
+        unsafe { self.map_unchecked_mut(|this| &mut this.futs) }
+    }
+}
+
+impl<Fut, const N: usize> Clone for TryJoinArray<Fut, N>
+where
+    Fut: Future + Clone,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            futs: self.futs.clone(),
+        }
+    }
+}
+
+impl<Fut, const N: usize> Debug for TryJoinArray<Fut, N>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TryJoinArray").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for TryJoinArray<Fut, N>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<[<Fut::Output as Try>::Output; N]>,
+{
+    type Output = <<Fut::Output as Try>::Residual as Residual<[<Fut::Output as Try>::Output; N]>>::TryType;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut futs = self.project_futs();
+
+        let residual = 'residual: {
+            let mut is_ready = true;
+
+            for fut in pin_array::iter_pin_mut(futs.as_mut()) {
+                match fut.poll(cx) {
+                    Poll::Ready(ControlFlow::Continue(())) => {}
+                    Poll::Ready(ControlFlow::Break(residual)) => break 'residual residual,
+                    Poll::Pending => is_ready = false,
+                }
+            }
+
+            return if is_ready {
+                let mut futs = pin_array::iter_pin_mut(futs);
+
+                Poll::Ready(Self::Output::from_output(array::from_fn(|_| {
+                    futs.next().unwrap().take_output().unwrap()
+                })))
+            } else {
+                Poll::Pending
+            };
+        };
+
+        pin_array::iter_pin_mut(futs).for_each(TryMaybeDone::clear);
+
+        Poll::Ready(Self::Output::from_residual(residual))
+    }
+}
+
+impl<Fut, const N: usize> FusedFuture for TryJoinArray<Fut, N>
+where
+    Fut: Future,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<[<Fut::Output as Try>::Output; N]>,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.iter().all(TryMaybeDone::is_terminated)
+    }
+}
+
+pub fn try_join_array<Fut, const N: usize>(futs: [Fut; N]) -> TryJoinArray<Fut::IntoFuture, N>
+where
+    Fut: IntoFuture,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Residual: Residual<[<Fut::Output as Try>::Output; N]>,
+{
+    support::assert_future::<_, <<Fut::Output as Try>::Residual as Residual<[<Fut::Output as Try>::Output; N]>>::TryType>(
+        TryJoinArray::new(futs.map(IntoFuture::into_future)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::ready;
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::{future, FutureExt};
+
+    #[tokio::test]
+    async fn test_try_join_array() {
+        assert_eq!(
+            super::try_join_array([2, 3, 5].map(|x| ready::ready_by_copy(Ok::<u32, u32>(x)))).await,
+            Ok([2, 3, 5]),
+        );
+
+        assert_eq!(
+            super::try_join_array([2, 3, 5].map(|x| test_utilities::delayed(ready::ready_by_copy(Ok::<u32, u32>(x)))))
+                .await,
+            Ok([2, 3, 5]),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_array_error() {
+        assert_eq!(
+            super::try_join_array([
+                future::pending().left_future(),
+                ready::ready_by_copy(Err::<u32, u32>(3)).right_future(),
+                future::pending().left_future(),
+            ])
+            .await,
+            Err(3),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_array_with_option() {
+        assert_eq!(
+            super::try_join_array([Some(2), Some(3)].map(ready::ready_by_copy)).await,
+            Some([2, 3]),
+        );
+
+        assert_eq!(
+            super::try_join_array([Some(2), None].map(ready::ready_by_copy)).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_try_join_array_clone() {
+        let future = super::try_join_array([2, 3].map(|x| ready::ready_by_copy(Ok::<u32, u32>(x))));
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, Ok([2, 3]));
+        assert_eq!(future_2.await, Ok([2, 3]));
+    }
+
+    #[tokio::test]
+    async fn test_try_join_array_fused_future() {
+        let mut future = super::try_join_array([Ok::<u32, u32>(2), Err(3)].map(ready::ready_by_copy));
+
+        assert!(!future.is_terminated());
+        assert_eq!((&mut future).now_or_never(), Some(Err(3)));
+        assert!(future.is_terminated());
+    }
+}
@@ -1,11 +1,12 @@
 use crate::future::map_ok_or_else::MapOkOrElse;
 use crate::future::raw_select::RawSelect;
-use crate::support::fns::{EitherLeftFn, EitherRightFn};
+use crate::future::select_strategy::{Biased, Fair, SelectStrategy};
+use crate::support::fns::{ComposeFn, EitherLeftFn, EitherRightFn, ResultErrFn, ResultOkFn};
 use crate::support::{self, IntoResultFuture, ResultFuture};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use fn_traits::fns::{ComposeFn, ResultErrFn, ResultOkFn};
 use futures_core::FusedFuture;
 use futures_util::future::Either;
 
@@ -30,35 +31,55 @@ type RightFuture<Fut1, Fut2> = MapOkOrElse<Fut2, ErrorRightFn<Fut1, Fut2>, OkRig
 
 pin_project_lite::pin_project! {
     #[derive(Clone)]
-    pub struct TrySelectEither<Fut1, Fut2>
+    pub struct TrySelectEither<Fut1, Fut2, S = Biased>
     where
         Fut1: ResultFuture,
         Fut2: ResultFuture,
     {
         #[pin]
-        inner: RawSelect<LeftFuture<Fut1, Fut2>, RightFuture<Fut1, Fut2>>
+        inner: RawSelect<LeftFuture<Fut1, Fut2>, RightFuture<Fut1, Fut2>, S>
     }
 }
 
-impl<Fut1, Fut2> TrySelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> TrySelectEither<Fut1, Fut2, S>
 where
     Fut1: ResultFuture,
     Fut2: ResultFuture,
+    S: SelectStrategy,
 {
-    pub(crate) fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
+    pub const fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
         Self {
             inner: RawSelect::new(
-                MapOkOrElse::new(fut_1, ComposeFn::default(), ComposeFn::default()),
-                MapOkOrElse::new(fut_2, ComposeFn::default(), ComposeFn::default()),
+                MapOkOrElse::new(
+                    fut_1,
+                    ComposeFn::new(EitherLeftFn::new(), ResultErrFn::new()),
+                    ComposeFn::new(EitherLeftFn::new(), ResultOkFn::new()),
+                ),
+                MapOkOrElse::new(
+                    fut_2,
+                    ComposeFn::new(EitherRightFn::new(), ResultErrFn::new()),
+                    ComposeFn::new(EitherRightFn::new(), ResultOkFn::new()),
+                ),
             ),
         }
     }
 }
 
-impl<Fut1, Fut2> Future for TrySelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> Debug for TrySelectEither<Fut1, Fut2, S>
+where
+    Fut1: ResultFuture + Debug,
+    Fut2: ResultFuture + Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrySelectEither").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut1, Fut2, S> Future for TrySelectEither<Fut1, Fut2, S>
 where
     Fut1: ResultFuture,
     Fut2: ResultFuture,
+    S: SelectStrategy,
 {
     type Output = Result<Either<Fut1::Ok, Fut2::Ok>, Either<Fut1::Error, Fut2::Error>>;
 
@@ -67,16 +88,19 @@ where
     }
 }
 
-impl<Fut1, Fut2> FusedFuture for TrySelectEither<Fut1, Fut2>
+impl<Fut1, Fut2, S> FusedFuture for TrySelectEither<Fut1, Fut2, S>
 where
     Fut1: ResultFuture + FusedFuture,
     Fut2: ResultFuture + FusedFuture,
+    S: SelectStrategy,
 {
     fn is_terminated(&self) -> bool {
         self.inner.is_terminated()
     }
 }
 
+/// Polls `fut_1` before `fut_2` every time, so `fut_2` may starve if `fut_1` is always ready. See
+/// [`try_select_either_fair`] for an unbiased alternative.
 pub fn try_select_either<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> TrySelectEither<Fut1::IntoFuture, Fut2::IntoFuture>
 where
     Fut1: IntoResultFuture,
@@ -87,15 +111,29 @@ where
     )
 }
 
+/// Alternates which future is polled first, so neither future starves.
+pub fn try_select_either_fair<Fut1, Fut2>(
+    fut_1: Fut1,
+    fut_2: Fut2,
+) -> TrySelectEither<Fut1::IntoFuture, Fut2::IntoFuture, Fair>
+where
+    Fut1: IntoResultFuture,
+    Fut2: IntoResultFuture,
+{
+    support::assert_future::<_, Result<Either<Fut1::Ok, Fut2::Ok>, Either<Fut1::Error, Fut2::Error>>>(
+        TrySelectEither::new(fut_1.into_future(), fut_2.into_future()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use super::TrySelectEither;
     use crate::future::future_ext::FutureExt;
     use crate::future::{self, err, ok};
     use crate::test_utilities;
     use futures_core::FusedFuture;
     use futures_util::future::Either;
     use futures_util::FutureExt as _;
-    use std::mem;
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -146,6 +184,29 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_try_select_either_in_const() {
+        type ConstOk = future::Ok<fn(&mut u32) -> u32, u32, u32>;
+        type ConstErr = future::Err<fn(&mut u32) -> u32, u32, u32>;
+
+        fn get(value: &mut u32) -> u32 {
+            *value
+        }
+
+        const FUTURE: TrySelectEither<ConstOk, ConstErr> = TrySelectEither::new(ok::ok_by(get, 2), err::err_by(get, 3));
+
+        assert!(matches!(FUTURE.await, Ok(Either::Left(2))));
+    }
+
+    #[tokio::test]
+    async fn test_try_select_either_fair() {
+        let mut future = super::try_select_either_fair(ok::ok_by_copy::<u32, u32>(2), err::err_by_copy::<u32, u32>(3));
+
+        assert!(matches!((&mut future).now_or_never(), Some(Ok(Either::Left(2)))));
+        assert!(matches!((&mut future).now_or_never(), Some(Err(Either::Right(3)))));
+        assert!(matches!((&mut future).now_or_never(), Some(Ok(Either::Left(2)))));
+    }
+
     #[tokio::test]
     async fn test_try_select_either_clone() {
         let future = super::try_select_either(ok::ok_by_copy::<u32, u32>(2), ok::ok_by_copy::<u32, u32>(3));
@@ -181,9 +242,21 @@ mod tests {
         let base_future_2 = make_base_future_2();
         let future = super::try_select_either(make_base_future_1(), make_base_future_2());
 
-        assert_eq!(mem::size_of_val(&base_future_2), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future_2), size_of_val(&future));
         assert_eq!(base_future_1.await, Ok(2));
         assert_eq!(base_future_2.await, Ok(3));
         assert!(matches!(future.await, Ok(Either::Left(2))));
     }
+
+    #[tokio::test]
+    async fn test_try_select_either_fair_is_slim() {
+        let make_base_future_1 = || ok::ok_by_copy::<_, u8>(2_u8);
+        let make_base_future_2 = || ok::ok_by_copy::<_, u8>(3_u8);
+        let future_1 = super::try_select_either(make_base_future_1(), make_base_future_2());
+        let future_2 = super::try_select_either_fair(make_base_future_1(), make_base_future_2());
+
+        assert!(size_of_val(&future_2) <= size_of_val(&future_1) + 1);
+        assert!(matches!(future_1.await, Ok(Either::Left(2))));
+        assert!(matches!(future_2.await, Ok(Either::Left(2))));
+    }
 }
@@ -0,0 +1,151 @@
+use crate::support::{self, ResultFuture};
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::FusedFuture;
+use futures_util::future::Either;
+
+type KeepOutput<Fut1, Fut2> = Result<
+    Either<(<Fut1 as ResultFuture>::Ok, Fut2), (<Fut2 as ResultFuture>::Ok, Fut1)>,
+    Either<(<Fut1 as ResultFuture>::Error, Fut2), (<Fut2 as ResultFuture>::Error, Fut1)>,
+>;
+
+#[derive(Clone)]
+pub struct TrySelectKeep<Fut1, Fut2> {
+    futs: Option<(Fut1, Fut2)>,
+}
+
+impl<Fut1, Fut2> TrySelectKeep<Fut1, Fut2> {
+    pub(crate) const fn new(fut_1: Fut1, fut_2: Fut2) -> Self {
+        Self {
+            futs: Some((fut_1, fut_2)),
+        }
+    }
+}
+
+impl<Fut1, Fut2> Debug for TrySelectKeep<Fut1, Fut2>
+where
+    Fut1: Debug,
+    Fut2: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("TrySelectKeep").field("futs", &self.futs).finish()
+    }
+}
+
+impl<Fut1, Fut2> Future for TrySelectKeep<Fut1, Fut2>
+where
+    Fut1: ResultFuture + Unpin,
+    Fut2: ResultFuture + Unpin,
+{
+    type Output = KeepOutput<Fut1, Fut2>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let futs = &mut self.get_mut().futs;
+
+        if let Some((fut_1, fut_2)) = futs {
+            if let Poll::Ready(result) = Pin::new(fut_1).poll(cx) {
+                if let Some((_, fut_2)) = futs.take() {
+                    return Poll::Ready(match result {
+                        Ok(value) => Ok(Either::Left((value, fut_2))),
+                        Err(error) => Err(Either::Left((error, fut_2))),
+                    });
+                }
+            } else if let Poll::Ready(result) = Pin::new(fut_2).poll(cx) {
+                if let Some((fut_1, _)) = futs.take() {
+                    return Poll::Ready(match result {
+                        Ok(value) => Ok(Either::Right((value, fut_1))),
+                        Err(error) => Err(Either::Right((error, fut_1))),
+                    });
+                }
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Fut1, Fut2> FusedFuture for TrySelectKeep<Fut1, Fut2>
+where
+    Fut1: ResultFuture + Unpin,
+    Fut2: ResultFuture + Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.futs.is_none()
+    }
+}
+
+pub const fn try_select_keep<Fut1, Fut2>(fut_1: Fut1, fut_2: Fut2) -> TrySelectKeep<Fut1, Fut2>
+where
+    Fut1: ResultFuture + Unpin,
+    Fut2: ResultFuture + Unpin,
+{
+    support::assert_future::<_, KeepOutput<Fut1, Fut2>>(TrySelectKeep::new(fut_1, fut_2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::{err, ok};
+    use crate::test_utilities;
+    use futures_core::FusedFuture;
+    use futures_util::future::Either;
+
+    #[tokio::test]
+    async fn test_try_select_keep() {
+        let ok_2 = || ok::ok_by_copy::<u32, u32>(2);
+        let ok_3 = || ok::ok_by_copy::<u32, u32>(3);
+        let err_2 = || err::err_by_copy::<u32, u32>(2);
+        let err_3 = || err::err_by_copy::<u32, u32>(3);
+
+        let result = super::try_select_keep(ok_2(), err_3()).await;
+
+        assert!(matches!(result, Ok(Either::Left((2, _)))));
+
+        if let Ok(Either::Left((_, fut_2))) = result {
+            assert_eq!(fut_2.await, Err(3));
+        }
+
+        let result = super::try_select_keep(err_2(), ok_3()).await;
+
+        assert!(matches!(result, Err(Either::Left((2, _)))));
+
+        if let Err(Either::Left((_, fut_2))) = result {
+            assert_eq!(fut_2.await, Ok(3));
+        }
+
+        let result = super::try_select_keep(test_utilities::delayed(err_2()), ok_3()).await;
+
+        assert!(matches!(result, Ok(Either::Right((3, _)))));
+
+        if let Ok(Either::Right((_, fut_1))) = result {
+            assert_eq!(fut_1.await, Err(2));
+        }
+
+        let result = super::try_select_keep(test_utilities::delayed(ok_2()), err_3()).await;
+
+        assert!(matches!(result, Err(Either::Right((3, _)))));
+
+        if let Err(Either::Right((_, fut_1))) = result {
+            assert_eq!(fut_1.await, Ok(2));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_try_select_keep_clone() {
+        let future = super::try_select_keep(ok::ok_by_copy::<u32, u32>(2), ok::ok_by_copy::<u32, u32>(3));
+        let future_2 = future.clone();
+
+        assert!(matches!(future.await, Ok(Either::Left((2, _)))));
+        assert!(matches!(future_2.await, Ok(Either::Left((2, _)))));
+    }
+
+    #[tokio::test]
+    async fn test_try_select_keep_fused_future() {
+        let mut future = super::try_select_keep(ok::ok_by_copy::<u32, u32>(2), ok::ok_by_copy::<u32, u32>(3));
+
+        assert!(!future.is_terminated());
+        assert!(matches!((&mut future).await, Ok(Either::Left((2, _)))));
+        assert!(future.is_terminated());
+    }
+}
@@ -1,30 +1,13 @@
 use crate::future::map::Map;
+use crate::support::fns::UnwrapOrElseFn;
 use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 use fn_traits::FnMut;
 use futures_core::FusedFuture;
 
-#[derive(Clone)]
-struct UnwrapOrElseFn<F>
-where
-    F: ?Sized,
-{
-    f: F,
-}
-
-impl<T, E, F> FnMut<(Result<T, E>,)> for UnwrapOrElseFn<F>
-where
-    F: FnMut<(E,), Output = T> + ?Sized,
-{
-    type Output = T;
-
-    fn call_mut(&mut self, args: (Result<T, E>,)) -> Self::Output {
-        args.0.unwrap_or_else(|error| self.f.call_mut((error,)))
-    }
-}
-
 pin_project_lite::pin_project! {
     #[derive(Clone)]
     pub struct UnwrapOrElse<Fut, F>
@@ -37,13 +20,22 @@ pin_project_lite::pin_project! {
 }
 
 impl<Fut, F> UnwrapOrElse<Fut, F> {
-    pub(crate) fn new(fut: Fut, f: F) -> Self {
+    pub const fn new(fut: Fut, f: F) -> Self {
         Self {
-            inner: Map::new(fut, UnwrapOrElseFn { f }),
+            inner: Map::new(fut, UnwrapOrElseFn::new(f)),
         }
     }
 }
 
+impl<Fut, F> Debug for UnwrapOrElse<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("UnwrapOrElse").field("inner", &self.inner).finish()
+    }
+}
+
 impl<Fut, F> Future for UnwrapOrElse<Fut, F>
 where
     Fut: ResultFuture,
@@ -72,7 +64,6 @@ mod tests {
     use crate::future::future_ext::FutureExt;
     use futures_core::FusedFuture;
     use futures_util::future;
-    use std::mem;
 
     fn plus_3(value: u32) -> u32 {
         value + 3
@@ -108,7 +99,7 @@ mod tests {
         let base_future = make_base_future();
         let future = make_base_future().slim_or_else(Err);
 
-        assert_eq!(mem::size_of_val(&base_future), mem::size_of_val(&future));
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
         assert_eq!(base_future.await, Err(2));
         assert_eq!(future.await, Err(2));
     }
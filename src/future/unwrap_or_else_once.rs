@@ -0,0 +1,116 @@
+use crate::future::map_once::MapOnce;
+use crate::support::fns::UnwrapOrElseFn;
+use crate::support::ResultFuture;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use fn_traits::FnOnce;
+use futures_core::FusedFuture;
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct UnwrapOrElseOnce<Fut, F> {
+        #[pin]
+        inner: MapOnce<Fut, UnwrapOrElseFn<F>>,
+    }
+}
+
+impl<Fut, F> UnwrapOrElseOnce<Fut, F> {
+    pub const fn new(fut: Fut, f: F) -> Self {
+        Self {
+            inner: MapOnce::new(fut, UnwrapOrElseFn::new(f)),
+        }
+    }
+}
+
+impl<Fut, F> Debug for UnwrapOrElseOnce<Fut, F>
+where
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("UnwrapOrElseOnce").field("inner", &self.inner).finish()
+    }
+}
+
+impl<Fut, F> Future for UnwrapOrElseOnce<Fut, F>
+where
+    Fut: ResultFuture,
+    F: FnOnce<(Fut::Error,), Output = Fut::Ok>,
+{
+    type Output = Fut::Ok;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+impl<Fut, F> FusedFuture for UnwrapOrElseOnce<Fut, F>
+where
+    Fut: ResultFuture,
+    F: FnOnce<(Fut::Error,), Output = Fut::Ok>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::err;
+    use crate::future::future_ext::FutureExt;
+    use futures_core::FusedFuture;
+    use futures_util::future;
+    use std::num::NonZeroU32;
+    use std::string::String;
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_once() {
+        let fallback = String::from("abc");
+
+        assert_eq!(
+            future::ok::<_, u32>(String::from("def"))
+                .slim_unwrap_or_else_once(move |_| fallback)
+                .await,
+            "def",
+        );
+
+        let fallback = String::from("abc");
+
+        assert_eq!(
+            future::err::<String, _>(2)
+                .slim_unwrap_or_else_once(move |_| fallback)
+                .await,
+            "abc",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_once_clone() {
+        let future = future::err::<u32, _>(2).slim_unwrap_or_else_once(|value| value + 3);
+        let future_2 = future.clone();
+
+        assert_eq!(future.await, 5);
+        assert_eq!(future_2.await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_once_fused_future() {
+        let mut future = future::err::<u32, _>(2).slim_unwrap_or_else_once(|value| value + 3);
+
+        assert!(!future.is_terminated());
+        assert_eq!(future.by_ref().await, 5);
+        assert!(future.is_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_unwrap_or_else_once_is_slim() {
+        let make_base_future = || err::err_by_copy::<u32, _>(NonZeroU32::new(2).unwrap());
+        let base_future = make_base_future();
+        let future = make_base_future().slim_unwrap_or_else_once(|value| value.get() + 3);
+
+        assert_eq!(size_of_val(&base_future), size_of_val(&future));
+        assert_eq!(base_future.await.map_err(NonZeroU32::get), Err(2));
+        assert_eq!(future.await, 5);
+    }
+}
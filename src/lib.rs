@@ -7,7 +7,6 @@
     missing_abi,
     missing_docs,
     noop_method_call,
-    pointer_structural_match,
     single_use_lifetimes,
     trivial_casts,
     trivial_numeric_casts,
@@ -19,7 +18,6 @@
     unused_lifetimes,
     unused_macro_rules,
     unused_qualifications,
-    unused_tuple_struct_fields,
     variant_size_differences,
     clippy::alloc_instead_of_core,
     clippy::allow_attributes_without_reason,
@@ -79,7 +77,6 @@
     clippy::self_named_module_files,
     clippy::significant_drop_in_scrutinee,
     clippy::string_lit_as_bytes,
-    clippy::string_to_string,
     clippy::suboptimal_flops,
     clippy::suspicious_operation_groupings,
     clippy::todo,
@@ -103,17 +100,31 @@
     clippy::verbose_file_reads,
     clippy::wildcard_dependencies
 )]
-#![allow(missing_docs)]
+#![allow(missing_docs, reason = "Documentation is not written yet.")]
 #![no_std]
+#![cfg_attr(feature = "nightly", feature(async_iterator, try_trait_v2, try_trait_v2_residual))]
 
 #[cfg(test)]
 use criterion as _;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(test)]
 extern crate std;
 
 pub mod async_iter;
+#[cfg(test)]
+mod debug_tests;
+pub mod fns;
+#[cfg(test)]
+mod fused_tests;
 pub mod future;
+pub mod ops;
+#[cfg(test)]
+mod size_hint_tests;
 mod support;
+pub mod task;
 #[cfg(test)]
 mod test_utilities;
+pub mod time;
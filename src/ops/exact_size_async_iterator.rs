@@ -0,0 +1,44 @@
+use crate::support::AsyncIterator;
+use futures_util::stream::Iter;
+
+/// An async iterator that knows its exact remaining length, like [`ExactSizeIterator`] for iterators.
+///
+/// Implementations must return `(len, Some(len))` from [`size_hint`](AsyncIterator::size_hint), where `len` is the
+/// number of items that are yet to be produced.
+pub trait ExactSizeAsyncIterator: AsyncIterator {
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+
+        debug_assert_eq!(upper, Some(lower));
+
+        lower
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<I> ExactSizeAsyncIterator for &mut I where I: ExactSizeAsyncIterator + Unpin + ?Sized {}
+
+impl<I> ExactSizeAsyncIterator for Iter<I> where I: ExactSizeIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::ExactSizeAsyncIterator;
+    use futures_util::{stream, StreamExt};
+
+    #[tokio::test]
+    async fn test_exact_size_async_iterator() {
+        let mut iter = stream::iter([2, 3, 5]);
+
+        assert_eq!(iter.len(), 3);
+        assert!(!iter.is_empty());
+        assert_eq!(iter.next().await, Some(2));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next().await, Some(3));
+        assert_eq!(iter.next().await, Some(5));
+        assert_eq!(iter.len(), 0);
+        assert!(iter.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::support::AsyncIterator;
 
+/// Conversion into an async iterator, like [`IntoIterator`] for iterators. Every async iterator converts into
+/// itself, and other types may implement this to be accepted by combinators such as `slim_flatten`.
 pub trait IntoAsyncIterator {
     type Item;
     type IntoAsyncIter: AsyncIterator<Item = Self::Item>;
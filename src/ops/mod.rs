@@ -0,0 +1,21 @@
+//! Traits that describe the shapes of values accepted by fallible combinators.
+//!
+//! [`Try`], [`FromResidual`] and [`Residual`] mirror the unstable `core::ops` traits of the same names, so that
+//! combinators like `slim_and_then` and `slim_try_fold` work with [`Result`], [`Option`], [`ControlFlow`] and
+//! user-defined status types on stable Rust.
+//!
+//! [`ControlFlow`]: core::ops::ControlFlow
+
+pub use self::exact_size_async_iterator::ExactSizeAsyncIterator;
+pub use self::into_async_iterator::IntoAsyncIterator;
+pub use self::result_async_iterator::ResultAsyncIterator;
+pub use self::result_future::ResultFuture;
+#[cfg(feature = "nightly")]
+pub use self::try_::CoreTry;
+pub use self::try_::{FromResidual, Residual, Try};
+
+mod exact_size_async_iterator;
+mod into_async_iterator;
+mod result_async_iterator;
+mod result_future;
+mod try_;
@@ -1,5 +1,6 @@
 use crate::support::AsyncIterator;
 
+/// An async iterator that yields [`Result`]s. Implemented automatically for every such async iterator.
 pub trait ResultAsyncIterator: AsyncIterator<Item = Result<Self::Ok, Self::Error>> {
     type Ok;
     type Error;
@@ -1,5 +1,6 @@
 use core::future::Future;
 
+/// A future that outputs a [`Result`]. Implemented automatically for every such future.
 pub trait ResultFuture: Future<Output = Result<Self::Ok, Self::Error>> {
     type Ok;
     type Error;
@@ -0,0 +1,364 @@
+use core::convert::Infallible;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+/// Constructs `Self` from the residual of a short-circuited [`Try`] value.
+///
+/// Implement `FromResidual<R>` for every residual type `R` that should be convertible into `Self`, for example
+/// `Result<T, E>` implements `FromResidual<Result<Infallible, F>>` for every `F: Into<E>`.
+pub trait FromResidual<R = <Self as Try>::Residual> {
+    fn from_residual(residual: R) -> Self;
+}
+
+/// A type that either continues with an [`Output`](Try::Output) or short-circuits with a
+/// [`Residual`](Try::Residual), like `core::ops::Try`.
+///
+/// Implementations must round-trip: `from_output(x).branch()` returns `Continue(x)`, and
+/// `from_residual(r).branch()` returns `Break(r)`.
+///
+/// Types that only implement `core::ops::Try` do not implement this trait, even with the `nightly` feature, because a
+/// blanket implementation would conflict with user implementations. With the `nightly` feature, such types can be
+/// wrapped in `CoreTry` instead.
+pub trait Try: FromResidual<Self::Residual> {
+    type Output;
+    type Residual;
+
+    fn from_output(output: Self::Output) -> Self;
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+/// Maps a residual type back to a [`Try`] type with output `O`, so combinators can change the output type while
+/// preserving the residual, for example `Result<Infallible, E>` maps to `Result<O, E>`.
+pub trait Residual<O> {
+    type TryType: Try<Output = O, Residual = Self>;
+}
+
+fn infallible_into<T>(value: Infallible) -> T {
+    match value {}
+}
+
+// `ControlFlow<B, C>`.
+
+impl<B, C> FromResidual for ControlFlow<B, C> {
+    fn from_residual(residual: <Self as Try>::Residual) -> Self {
+        match residual {
+            ControlFlow::Continue(never) => infallible_into(never),
+            ControlFlow::Break(residual) => Self::Break(residual),
+        }
+    }
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Self::Continue(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Self::Continue(output) => ControlFlow::Continue(output),
+            Self::Break(residual) => ControlFlow::Break(ControlFlow::Break(residual)),
+        }
+    }
+}
+
+impl<B, C> Residual<C> for ControlFlow<B, Infallible> {
+    type TryType = ControlFlow<B, C>;
+}
+
+// `Option<T>`.
+
+impl<T> FromResidual<<Self as Try>::Residual> for Option<T> {
+    fn from_residual(residual: <Self as Try>::Residual) -> Self {
+        residual.map(infallible_into)
+    }
+}
+
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Some(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        self.map_or(ControlFlow::Break(None), ControlFlow::Continue)
+    }
+}
+
+impl<T> Residual<T> for Option<Infallible> {
+    type TryType = Option<T>;
+}
+
+// `Result<T, E>`.
+
+impl<T, F, E> FromResidual<Result<Infallible, F>> for Result<T, E>
+where
+    E: From<F>,
+{
+    fn from_residual(residual: Result<Infallible, F>) -> Self {
+        residual.map_or_else(|residual| Err(residual.into()), infallible_into)
+    }
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Ok(output)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Ok(output) => ControlFlow::Continue(output),
+            Err(residual) => ControlFlow::Break(Err(residual)),
+        }
+    }
+}
+
+impl<T, E> Residual<T> for Result<Infallible, E> {
+    type TryType = Result<T, E>;
+}
+
+// `Poll<Option<Result<T, E>>>`.
+
+impl<T, F, E> FromResidual<Result<Infallible, F>> for Poll<Option<Result<T, E>>>
+where
+    E: From<F>,
+{
+    fn from_residual(residual: Result<Infallible, F>) -> Self {
+        residual.map_or_else(|residual| Self::Ready(Some(Err(residual.into()))), infallible_into)
+    }
+}
+
+impl<T, E> Try for Poll<Option<Result<T, E>>> {
+    type Output = Poll<Option<T>>;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        output.map(|output| output.map(Ok))
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Self::Ready(None) => ControlFlow::Continue(Poll::Ready(None)),
+            Self::Ready(Some(Ok(output))) => ControlFlow::Continue(Poll::Ready(Some(output))),
+            Self::Ready(Some(Err(residual))) => ControlFlow::Break(Err(residual)),
+            Self::Pending => ControlFlow::Continue(Poll::Pending),
+        }
+    }
+}
+
+// `Poll<Result<T, F>>`.
+
+impl<T, F, E> FromResidual<Result<Infallible, F>> for Poll<Result<T, E>>
+where
+    E: From<F>,
+{
+    fn from_residual(residual: Result<Infallible, F>) -> Self {
+        residual.map_or_else(|residual| Self::Ready(Err(residual.into())), infallible_into)
+    }
+}
+
+impl<T, E> Try for Poll<Result<T, E>> {
+    type Output = Poll<T>;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        output.map(Ok)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Self::Ready(Ok(output)) => ControlFlow::Continue(Poll::Ready(output)),
+            Self::Ready(Err(residual)) => ControlFlow::Break(Err(residual)),
+            Self::Pending => ControlFlow::Continue(Poll::Pending),
+        }
+    }
+}
+
+// Every `core::ops::Try` type, through `CoreTry`.
+
+/// Wraps a `core::ops::Try` type so it can be used with the fallible combinators, which only accept [`Try`] types.
+/// The wrapper is needed because implementing [`Try`] for every `core::ops::Try` type would conflict with
+/// user-defined [`Try`] implementations.
+#[cfg(feature = "nightly")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoreTry<T>(pub T);
+
+#[cfg(feature = "nightly")]
+impl<T, R> FromResidual<CoreTry<R>> for CoreTry<T>
+where
+    T: core::ops::FromResidual<R>,
+{
+    fn from_residual(residual: CoreTry<R>) -> Self {
+        Self(core::ops::FromResidual::from_residual(residual.0))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T> Try for CoreTry<T>
+where
+    T: core::ops::Try,
+{
+    type Output = T::Output;
+    type Residual = CoreTry<T::Residual>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Self(core::ops::Try::from_output(output))
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match core::ops::Try::branch(self.0) {
+            ControlFlow::Continue(output) => ControlFlow::Continue(output),
+            ControlFlow::Break(residual) => ControlFlow::Break(CoreTry(residual)),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<R, O> Residual<O> for CoreTry<R>
+where
+    R: core::ops::Residual<O>,
+{
+    type TryType = CoreTry<R::TryType>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromResidual, Residual, Try};
+    use crate::future::FutureExt;
+    use core::ops::ControlFlow;
+    use futures_util::future;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Status<T> {
+        Success(T),
+        Failure(u16),
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct StatusResidual(u16);
+
+    impl<T> FromResidual for Status<T> {
+        fn from_residual(residual: StatusResidual) -> Self {
+            Self::Failure(residual.0)
+        }
+    }
+
+    impl<T> Try for Status<T> {
+        type Output = T;
+        type Residual = StatusResidual;
+
+        fn from_output(output: Self::Output) -> Self {
+            Self::Success(output)
+        }
+
+        fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+            match self {
+                Self::Success(output) => ControlFlow::Continue(output),
+                Self::Failure(code) => ControlFlow::Break(StatusResidual(code)),
+            }
+        }
+    }
+
+    impl<T> Residual<T> for StatusResidual {
+        type TryType = Status<T>;
+    }
+
+    fn plus_one(value: u32) -> Status<u32> {
+        Status::Success(value + 1)
+    }
+
+    #[tokio::test]
+    async fn test_user_defined_try() {
+        assert_eq!(
+            future::ready(Status::Success(2)).slim_and_then(plus_one).await,
+            Status::Success(3)
+        );
+
+        assert_eq!(
+            future::ready(Status::Failure(404)).slim_and_then(plus_one).await,
+            Status::Failure(404)
+        );
+
+        assert_eq!(
+            future::ready(Status::Success(2)).slim_map_ok(|value| value * 2).await,
+            Status::Success(4)
+        );
+    }
+}
+
+#[cfg(all(test, feature = "nightly"))]
+mod nightly_tests {
+    use crate::future::FutureExt;
+    use crate::ops::CoreTry;
+    use core::convert::Infallible;
+    use core::ops::{ControlFlow, FromResidual, Residual, Try};
+    use futures_util::future;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Status<T> {
+        Success(T),
+        Failure(u16),
+    }
+
+    impl<T> FromResidual<Status<Infallible>> for Status<T> {
+        fn from_residual(residual: Status<Infallible>) -> Self {
+            match residual {
+                Status::Success(never) => match never {},
+                Status::Failure(code) => Self::Failure(code),
+            }
+        }
+    }
+
+    impl<T> Try for Status<T> {
+        type Output = T;
+        type Residual = Status<Infallible>;
+
+        fn from_output(output: Self::Output) -> Self {
+            Self::Success(output)
+        }
+
+        fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+            match self {
+                Self::Success(output) => ControlFlow::Continue(output),
+                Self::Failure(code) => ControlFlow::Break(Status::Failure(code)),
+            }
+        }
+    }
+
+    impl<T> Residual<T> for Status<Infallible> {
+        type TryType = Status<T>;
+    }
+
+    fn plus_one(value: u32) -> CoreTry<Status<u32>> {
+        CoreTry(Status::Success(value + 1))
+    }
+
+    #[tokio::test]
+    async fn test_core_try() {
+        assert_eq!(
+            future::ready(CoreTry(Status::Success(2))).slim_and_then(plus_one).await,
+            CoreTry(Status::Success(3))
+        );
+
+        assert_eq!(
+            future::ready(CoreTry(Status::Failure(404)))
+                .slim_and_then(plus_one)
+                .await,
+            CoreTry(Status::Failure(404))
+        );
+
+        assert_eq!(
+            future::ready(CoreTry(Status::Success(2)))
+                .slim_map_ok(|value| value * 2)
+                .await,
+            CoreTry(Status::Success(4))
+        );
+    }
+}
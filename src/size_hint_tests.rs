@@ -0,0 +1,495 @@
+//! Checks the `size_hint` of every async iterator adapter against the number of items it actually produces.
+
+use crate::async_iter::AsyncIteratorExt;
+use crate::future::FutureExt as _;
+use crate::task::AbortRegistration;
+use crate::test_utilities::{self, assert_exact_size, assert_size_hint};
+use crate::time::ManualClock;
+use core::future::Future;
+use core::time::Duration;
+use futures_util::future;
+use futures_util::stream::{self, Iter};
+use std::array;
+
+fn iter<T, const N: usize>(items: [T; N]) -> Iter<array::IntoIter<T, N>> {
+    stream::iter(items)
+}
+
+fn results() -> Iter<array::IntoIter<Result<u32, u32>, 4>> {
+    iter([Ok(2), Err(3), Ok(5), Err(7)])
+}
+
+fn delayed<T>(value: T) -> impl Future<Output = T> {
+    test_utilities::delayed(future::ready(value))
+}
+
+fn plus_3(value: u32) -> u32 {
+    value + 3
+}
+
+fn plus_3_async(value: u32) -> impl Future<Output = u32> {
+    delayed(value + 3)
+}
+
+// Adapters that preserve the exact length.
+
+#[tokio::test]
+async fn test_and_then_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_and_then(|x| Ok::<_, u32>(x + 3))).await,
+        [Ok(5), Err(3), Ok(8), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_and_then_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_and_then_async(|x| delayed(Ok::<_, u32>(x + 3)))).await,
+        [Ok(5), Err(3), Ok(8), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_err_into_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_err_into::<u64>()).await,
+        [Ok(2), Err(3), Ok(5), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_fuse_size_hint() {
+    assert_eq!(assert_exact_size(iter([2, 3, 5]).slim_fuse()).await, [2, 3, 5]);
+}
+
+#[tokio::test]
+async fn test_inspect_size_hint() {
+    assert_eq!(assert_exact_size(iter([2, 3, 5]).slim_inspect(|_| {})).await, [2, 3, 5]);
+}
+
+#[tokio::test]
+async fn test_inspect_err_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_inspect_err(|_| {})).await,
+        [Ok(2), Err(3), Ok(5), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_inspect_ok_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_inspect_ok(|_| {})).await,
+        [Ok(2), Err(3), Ok(5), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_size_hint() {
+    assert_eq!(assert_exact_size(iter([2, 3, 5]).slim_map(plus_3)).await, [5, 6, 8]);
+}
+
+#[tokio::test]
+async fn test_map_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(iter([2, 3, 5]).slim_map_async(plus_3_async)).await,
+        [5, 6, 8]
+    );
+}
+
+#[tokio::test]
+async fn test_map_async_fn_size_hint() {
+    assert_eq!(
+        assert_exact_size(iter([2_u32, 3, 5]).slim_map_async_fn(async |x: u32| delayed(x + 3).await)).await,
+        [5_u32, 6, 8]
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_map_err(plus_3)).await,
+        [Ok(2), Err(6), Ok(5), Err(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_err_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_map_err_async(plus_3_async)).await,
+        [Ok(2), Err(6), Ok(5), Err(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_map_ok(plus_3)).await,
+        [Ok(5), Err(3), Ok(8), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_ok_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_map_ok_async(plus_3_async)).await,
+        [Ok(5), Err(3), Ok(8), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_ok_into_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_ok_into::<u64>()).await,
+        [Ok(2), Err(3), Ok(5), Err(7)]
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_or_else(|x| Ok::<_, u32>(x + 3))).await,
+        [Ok(2), Ok(6), Ok(5), Ok(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_or_else_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_or_else_async(|x| delayed(Ok::<_, u32>(x + 3)))).await,
+        [Ok(2), Ok(6), Ok(5), Ok(10)]
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_unwrap_or_else(plus_3)).await,
+        [2, 6, 5, 10]
+    );
+}
+
+#[tokio::test]
+async fn test_unwrap_or_else_async_size_hint() {
+    assert_eq!(
+        assert_exact_size(results().slim_unwrap_or_else_async(plus_3_async)).await,
+        [2, 6, 5, 10]
+    );
+}
+
+#[tokio::test]
+async fn test_zip_size_hint() {
+    assert_eq!(
+        assert_exact_size(iter([2, 3, 5]).slim_zip(iter([7, 11]))).await,
+        [(2, 7), (3, 11)]
+    );
+
+    assert_eq!(
+        assert_exact_size(iter([2, 3]).slim_zip(iter([5, 7, 11]).slim_map_async(delayed))).await,
+        [(2, 5), (3, 7)]
+    );
+}
+
+// Other adapters.
+
+#[tokio::test]
+async fn test_abortable_size_hint() {
+    let registration = AbortRegistration::new();
+
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_abortable(&registration).0).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_debounce_size_hint() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_debounce(&clock, Duration::from_secs(1))).await,
+        [5]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2_u32, 3, 4]).slim_filter(|x| x.is_multiple_of(2))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2_u32, 3, 4]).slim_filter_async(|x| delayed(x.is_multiple_of(2)))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_async_fn_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2_u32, 3, 4]).slim_filter_async_fn(async |x: &u32| delayed(x.is_multiple_of(2)).await))
+            .await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_err_size_hint() {
+    assert_eq!(assert_size_hint(results().slim_filter_err()).await, [3, 7]);
+}
+
+#[tokio::test]
+async fn test_filter_map_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2_u32, 3, 4]).slim_filter_map(|x| x.is_multiple_of(2).then_some(x))).await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_map_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2_u32, 3, 4]).slim_filter_map_async(|x| delayed(x.is_multiple_of(2).then_some(x))))
+            .await,
+        [2, 4]
+    );
+}
+
+#[tokio::test]
+async fn test_filter_ok_size_hint() {
+    assert_eq!(assert_size_hint(results().slim_filter_ok()).await, [2, 5]);
+}
+
+#[tokio::test]
+async fn test_flat_map_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3]).slim_flat_map(|x| iter([x, x]))).await,
+        [2, 2, 3, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_flat_map_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3]).slim_flat_map_async(|x| delayed(iter([x, x])))).await,
+        [2, 2, 3, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([iter([2, 3]), iter([5, 7])]).slim_flatten()).await,
+        [2, 3, 5, 7]
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_async_iterator_size_hint() {
+    assert_eq!(
+        assert_size_hint(delayed(iter([2, 3])).slim_flatten_async_iterator()).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_flatten_ok_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([Ok::<_, u32>(iter([2, 3])), Err(5)]).slim_flatten_ok()).await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_map_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_map_while(|x| (x < 5).then_some(x))).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_map_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_map_while_async(|x| delayed((x < 5).then_some(x)))).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_sample_size_hint() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_sample(&clock, Duration::from_secs(1))).await,
+        [5]
+    );
+}
+
+#[tokio::test]
+async fn test_scan_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_scan(0, |acc, x| {
+            *acc += x;
+
+            (*acc < 10).then_some(*acc)
+        }))
+        .await,
+        [2, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_scan_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_scan_async(0, |acc, x| {
+            *acc += x;
+
+            delayed((*acc < 10).then_some(*acc))
+        }))
+        .await,
+        [2, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_skip_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_skip_while(|&x| x < 3)).await,
+        [3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_skip_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_skip_while_async(|&x| delayed(x < 3))).await,
+        [3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_take_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_take_while(|&x| x < 5)).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_take_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_take_while_async(|&x| delayed(x < 5))).await,
+        [2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_throttle_size_hint() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_throttle(&clock, Duration::ZERO)).await,
+        [2, 3, 5]
+    );
+}
+
+#[tokio::test]
+async fn test_timeout_size_hint() {
+    let clock = ManualClock::new();
+
+    assert_eq!(
+        assert_size_hint(iter([2, 3]).slim_timeout(&clock, Duration::from_secs(1))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_flatten_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([Ok::<_, u32>(iter([Ok::<u32, u32>(2), Ok(3)])), Err(5)]).slim_try_flatten()).await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_map_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5, 7]).slim_try_map_while(|x| if x < 5 { Ok(Some(x)) } else { Err(x) })).await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_map_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5, 7]).slim_try_map_while_async(|x| delayed(if x < 5 {
+            Ok(Some(x))
+        } else {
+            Err(x)
+        })))
+        .await,
+        [Ok(2), Ok(3), Err(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_scan_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_scan(0, |acc, x| {
+            *acc += x;
+
+            Ok::<_, u32>((*acc < 10).then_some(*acc))
+        }))
+        .await,
+        [Ok(2), Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_scan_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_scan_async(0, |acc, x| {
+            *acc += x;
+
+            delayed(Ok::<_, u32>((*acc < 10).then_some(*acc)))
+        }))
+        .await,
+        [Ok(2), Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_skip_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_skip_while(|&x| Ok::<_, u32>(x < 3))).await,
+        [Ok(3), Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_skip_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_skip_while_async(|&x| delayed(Ok::<_, u32>(x < 3)))).await,
+        [Ok(3), Ok(5)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_take_while_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_take_while(|&x| Ok::<_, u32>(x < 5))).await,
+        [Ok(2), Ok(3)]
+    );
+}
+
+#[tokio::test]
+async fn test_try_take_while_async_size_hint() {
+    assert_eq!(
+        assert_size_hint(iter([2, 3, 5]).slim_try_take_while_async(|&x| delayed(Ok::<_, u32>(x < 5)))).await,
+        [Ok(2), Ok(3)]
+    );
+}
@@ -1,8 +1,8 @@
-use crate::support::try_::{FromResidual, Try};
+use crate::ops::{FromResidual, Try};
 use core::ops::ControlFlow;
-use fn_traits::FnMut;
+use fn_traits::{FnMut, FnOnce};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct AndThenFn<F>
 where
     F: ?Sized,
@@ -11,7 +11,7 @@ where
 }
 
 impl<F> AndThenFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -31,3 +31,19 @@ where
         }
     }
 }
+
+impl<T, F> FnOnce<(T,)> for AndThenFn<F>
+where
+    T: Try,
+    F: FnOnce<(T::Output,)>,
+    F::Output: FromResidual<T::Residual>,
+{
+    type Output = F::Output;
+
+    fn call_once(self, args: (T,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(output) => self.f.call_once((output,)),
+            ControlFlow::Break(residual) => Self::Output::from_residual(residual),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use fn_traits::FnMut;
+
+#[derive(Clone, Default)]
+pub struct ComposeFn<F, G> {
+    first: F,
+    second: G,
+}
+
+impl<F, G> ComposeFn<F, G> {
+    pub const fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Args, F, G> FnMut<Args> for ComposeFn<F, G>
+where
+    F: FnMut<Args>,
+    G: FnMut<(F::Output,)>,
+{
+    type Output = G::Output;
+
+    fn call_mut(&mut self, args: Args) -> Self::Output {
+        self.second.call_mut((self.first.call_mut(args),))
+    }
+}
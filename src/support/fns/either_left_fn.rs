@@ -6,9 +6,15 @@ pub struct EitherLeftFn<B> {
     phantom: PhantomData<B>,
 }
 
+impl<B> EitherLeftFn<B> {
+    pub const fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
 impl<B> Default for EitherLeftFn<B> {
     fn default() -> Self {
-        Self { phantom: PhantomData }
+        Self::new()
     }
 }
 
@@ -6,9 +6,15 @@ pub struct EitherRightFn<A> {
     phantom: PhantomData<A>,
 }
 
+impl<A> EitherRightFn<A> {
+    pub const fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
 impl<A> Default for EitherRightFn<A> {
     fn default() -> Self {
-        Self { phantom: PhantomData }
+        Self::new()
     }
 }
 
@@ -9,7 +9,7 @@ where
 }
 
 impl<F> ForEachFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -1,6 +1,6 @@
 use fn_traits::FnMut;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct InspectErrFn<F>
 where
     F: ?Sized,
@@ -9,7 +9,7 @@ where
 }
 
 impl<F> InspectErrFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
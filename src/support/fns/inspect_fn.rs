@@ -1,6 +1,6 @@
-use fn_traits::FnMut;
+use fn_traits::{FnMut, FnOnce};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct InspectFn<F>
 where
     F: ?Sized,
@@ -9,7 +9,7 @@ where
 }
 
 impl<F> InspectFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -26,3 +26,16 @@ where
         args.0
     }
 }
+
+impl<T, F> FnOnce<(T,)> for InspectFn<F>
+where
+    F: for<'a> FnOnce<(&'a T,), Output = ()>,
+{
+    type Output = T;
+
+    fn call_once(self, args: (T,)) -> Self::Output {
+        self.f.call_once((&args.0,));
+
+        args.0
+    }
+}
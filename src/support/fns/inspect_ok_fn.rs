@@ -1,6 +1,6 @@
 use fn_traits::FnMut;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct InspectOkFn<F>
 where
     F: ?Sized,
@@ -9,7 +9,7 @@ where
 }
 
 impl<F> InspectOkFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -13,7 +13,7 @@ where
 }
 
 impl<F, T> MapErrAsyncFn<F, T> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self {
             phantom: PhantomData,
             f,
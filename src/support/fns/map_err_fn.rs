@@ -1,6 +1,6 @@
-use fn_traits::FnMut;
+use fn_traits::{FnMut, FnOnce};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct MapErrFn<F>
 where
     F: ?Sized,
@@ -9,7 +9,7 @@ where
 }
 
 impl<F> MapErrFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -24,3 +24,14 @@ where
         args.0.map_err(|value| self.f.call_mut((value,)))
     }
 }
+
+impl<T, E, F> FnOnce<(Result<T, E>,)> for MapErrFn<F>
+where
+    F: FnOnce<(E,)>,
+{
+    type Output = Result<T, F::Output>;
+
+    fn call_once(self, args: (Result<T, E>,)) -> Self::Output {
+        args.0.map_err(|value| self.f.call_once((value,)))
+    }
+}
@@ -1,6 +1,6 @@
 use crate::future::Map;
+use crate::ops::Residual;
 use crate::support::fns::try_from_output_fn::TryFromOutputFn;
-use crate::support::try_::Residual;
 use core::future::IntoFuture;
 use core::marker::PhantomData;
 use fn_traits::FnMut;
@@ -14,7 +14,7 @@ where
 }
 
 impl<F, R> MapOkAsyncFn<F, R> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self {
             phantom: PhantomData,
             f,
@@ -1,8 +1,8 @@
-use crate::support::try_::{FromResidual, Residual, Try};
+use crate::ops::{FromResidual, Residual, Try};
 use core::ops::ControlFlow;
-use fn_traits::FnMut;
+use fn_traits::{FnMut, FnOnce};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct MapOkFn<F>
 where
     F: ?Sized,
@@ -11,7 +11,7 @@ where
 }
 
 impl<F> MapOkFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -31,3 +31,19 @@ where
         }
     }
 }
+
+impl<T, F> FnOnce<(T,)> for MapOkFn<F>
+where
+    T: Try,
+    T::Residual: Residual<F::Output>,
+    F: FnOnce<(T::Output,)>,
+{
+    type Output = <T::Residual as Residual<F::Output>>::TryType;
+
+    fn call_once(self, args: (T,)) -> Self::Output {
+        match args.0.branch() {
+            ControlFlow::Continue(output) => Self::Output::from_output(self.f.call_once((output,))),
+            ControlFlow::Break(residual) => Self::Output::from_residual(residual),
+        }
+    }
+}
@@ -9,7 +9,7 @@ where
 }
 
 impl<D, F> MapOkOrElseFn<D, F> {
-    pub fn new(default: D, f: F) -> Self {
+    pub const fn new(default: D, f: F) -> Self {
         Self { default, f }
     }
 }
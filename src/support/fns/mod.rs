@@ -1,6 +1,5 @@
 pub use self::and_then_fn::AndThenFn;
-pub use self::control_flow_is_break_fn::ControlFlowIsBreakFn;
-pub use self::control_flow_is_continue_fn::ControlFlowIsContinueFn;
+pub use self::compose_fn::ComposeFn;
 pub use self::either_left_fn::EitherLeftFn;
 pub use self::either_right_fn::EitherRightFn;
 pub use self::for_each_fn::ForEachFn;
@@ -13,12 +12,13 @@ pub use self::map_ok_async_fn::MapOkAsyncFn;
 pub use self::map_ok_fn::MapOkFn;
 pub use self::map_ok_or_else_fn::MapOkOrElseFn;
 pub use self::or_else_fn::OrElseFn;
+pub use self::result_err_fn::ResultErrFn;
+pub use self::result_ok_fn::ResultOkFn;
 pub use self::try_from_output_fn::TryFromOutputFn;
-pub use self::unwrap_continue_value_fn::UnwrapContinueValueFn;
+pub use self::unwrap_or_else_fn::UnwrapOrElseFn;
 
 mod and_then_fn;
-mod control_flow_is_break_fn;
-mod control_flow_is_continue_fn;
+mod compose_fn;
 mod either_left_fn;
 mod either_right_fn;
 mod for_each_fn;
@@ -31,5 +31,7 @@ mod map_ok_async_fn;
 mod map_ok_fn;
 mod map_ok_or_else_fn;
 mod or_else_fn;
+mod result_err_fn;
+mod result_ok_fn;
 mod try_from_output_fn;
-mod unwrap_continue_value_fn;
+mod unwrap_or_else_fn;
@@ -1,7 +1,7 @@
-use crate::support::try_::Try;
-use fn_traits::FnMut;
+use crate::ops::Try;
+use fn_traits::{FnMut, FnOnce};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct OrElseFn<F>
 where
     F: ?Sized,
@@ -10,7 +10,7 @@ where
 }
 
 impl<F> OrElseFn<F> {
-    pub fn new(f: F) -> Self {
+    pub const fn new(f: F) -> Self {
         Self { f }
     }
 }
@@ -27,3 +27,16 @@ where
             .map_or_else(|error| self.f.call_mut((error,)), Self::Output::from_output)
     }
 }
+
+impl<T, E, F> FnOnce<(Result<T, E>,)> for OrElseFn<F>
+where
+    F: FnOnce<(E,)>,
+    F::Output: Try<Output = T>,
+{
+    type Output = F::Output;
+
+    fn call_once(self, args: (Result<T, E>,)) -> Self::Output {
+        args.0
+            .map_or_else(|error| self.f.call_once((error,)), Self::Output::from_output)
+    }
+}
@@ -0,0 +1,32 @@
+use core::marker::PhantomData;
+use fn_traits::FnMut;
+
+pub struct ResultErrFn<T> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> ResultErrFn<T> {
+    pub const fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+impl<T> Default for ResultErrFn<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ResultErrFn<T> {
+    fn clone(&self) -> Self {
+        Self { phantom: self.phantom }
+    }
+}
+
+impl<T, E> FnMut<(E,)> for ResultErrFn<T> {
+    type Output = Result<T, E>;
+
+    fn call_mut(&mut self, args: (E,)) -> Self::Output {
+        Err(args.0)
+    }
+}
@@ -0,0 +1,32 @@
+use core::marker::PhantomData;
+use fn_traits::FnMut;
+
+pub struct ResultOkFn<E> {
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<E> ResultOkFn<E> {
+    pub const fn new() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
+impl<E> Default for ResultOkFn<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for ResultOkFn<E> {
+    fn clone(&self) -> Self {
+        Self { phantom: self.phantom }
+    }
+}
+
+impl<T, E> FnMut<(T,)> for ResultOkFn<E> {
+    type Output = Result<T, E>;
+
+    fn call_mut(&mut self, args: (T,)) -> Self::Output {
+        Ok(args.0)
+    }
+}
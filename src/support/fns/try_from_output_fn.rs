@@ -1,4 +1,4 @@
-use crate::support::try_::Try;
+use crate::ops::Try;
 use core::marker::PhantomData;
 use fn_traits::FnMut;
 
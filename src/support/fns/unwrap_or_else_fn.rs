@@ -0,0 +1,37 @@
+use fn_traits::{FnMut, FnOnce};
+
+#[derive(Clone, Debug)]
+pub struct UnwrapOrElseFn<F>
+where
+    F: ?Sized,
+{
+    f: F,
+}
+
+impl<F> UnwrapOrElseFn<F> {
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<T, E, F> FnMut<(Result<T, E>,)> for UnwrapOrElseFn<F>
+where
+    F: FnMut<(E,), Output = T> + ?Sized,
+{
+    type Output = T;
+
+    fn call_mut(&mut self, args: (Result<T, E>,)) -> Self::Output {
+        args.0.unwrap_or_else(|error| self.f.call_mut((error,)))
+    }
+}
+
+impl<T, E, F> FnOnce<(Result<T, E>,)> for UnwrapOrElseFn<F>
+where
+    F: FnOnce<(E,), Output = T>,
+{
+    type Output = T;
+
+    fn call_once(self, args: (Result<T, E>,)) -> Self::Output {
+        args.0.unwrap_or_else(|error| self.f.call_once((error,)))
+    }
+}
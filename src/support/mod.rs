@@ -1,36 +1,62 @@
-pub use self::into_async_iterator::IntoAsyncIterator;
 pub use self::into_result_future::IntoResultFuture;
 pub use self::option_future::OptionFuture;
 pub use self::predicate_fn::PredicateFn;
 pub use self::raw_residual::RawResidual;
-pub use self::result_async_iterator::ResultAsyncIterator;
-pub use self::result_future::ResultFuture;
-pub use self::try_::{FromResidual, Residual, Try};
+pub use crate::ops::{
+    ExactSizeAsyncIterator, FromResidual, IntoAsyncIterator, Residual, ResultAsyncIterator, ResultFuture, Try,
+};
 pub use core::convert::Infallible as Never;
 use futures_core::Future;
 pub use futures_core::{FusedStream as FusedAsyncIterator, Stream as AsyncIterator};
 
 pub mod fns;
-mod into_async_iterator;
 mod into_result_future;
 mod option_future;
+pub mod pin_array;
 mod predicate_fn;
 mod raw_residual;
-mod result_async_iterator;
-mod result_future;
 pub mod states;
-mod try_;
 
-pub fn assert_future<Fut, T>(fut: Fut) -> Fut
+/// Maximum number of items a timing combinator takes from its inner async iterator in one poll before yielding to the
+/// executor, so an always-ready source cannot keep it from returning.
+pub const DRAIN_LIMIT: usize = 32;
+
+pub const fn assert_future<Fut, T>(fut: Fut) -> Fut
 where
     Fut: Future<Output = T>,
 {
     fut
 }
 
-pub fn assert_async_iter<I, T>(iter: I) -> I
+pub const fn assert_async_iter<I, T>(iter: I) -> I
 where
     I: AsyncIterator<Item = T>,
 {
     iter
 }
+
+/// Implements `core::async_iter::AsyncIterator` for a type by forwarding to its `futures_core::Stream` implementation,
+/// using the same generic parameters and bounds.
+macro_rules! impl_core_async_iterator {
+    (impl<$($lifetime:lifetime,)* $($param:ident),*> for $ty:ty $(where $($bound:tt)*)?) => {
+        #[cfg(feature = "nightly")]
+        impl<$($lifetime,)* $($param),*> core::async_iter::AsyncIterator for $ty
+        $(where $($bound)*)?
+        {
+            type Item = <Self as crate::support::AsyncIterator>::Item;
+
+            fn poll_next(
+                self: core::pin::Pin<&mut Self>,
+                cx: &mut core::task::Context,
+            ) -> core::task::Poll<Option<<Self as crate::support::AsyncIterator>::Item>> {
+                crate::support::AsyncIterator::poll_next(self, cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                crate::support::AsyncIterator::size_hint(self)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_core_async_iterator;
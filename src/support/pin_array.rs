@@ -0,0 +1,13 @@
+#![allow(unsafe_code, reason = "Structural pinning of array elements.")]
+
+use core::pin::Pin;
+
+pub fn iter_pin_mut<T, const N: usize>(array: Pin<&mut [T; N]>) -> impl Iterator<Item = Pin<&mut T>> {
+    // SAFETY: Elements are never moved out of the array, so they stay pinned as long as the array is pinned.
+    let array = unsafe { array.get_unchecked_mut() };
+
+    array.iter_mut().map(|item| {
+        // SAFETY: See above.
+        unsafe { Pin::new_unchecked(item) }
+    })
+}
@@ -1,4 +1,4 @@
-use crate::support::try_::{FromResidual, Try};
+use crate::ops::{FromResidual, Try};
 use core::ops::ControlFlow;
 
 pub struct RawResidual<B, C> {
@@ -0,0 +1,77 @@
+#![allow(unsafe_code, reason = "The pending future borrows the closure stored next to it.")]
+
+use crate::fns::AsyncFnMutCall;
+use core::future::Future;
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::ptr;
+use core::task::{self, Context, Poll};
+
+/// An async closure together with the future of its pending call, which may borrow from the closure.
+pub struct AsyncFnState<'a, F, A, C>
+where
+    C: AsyncFnMutCall<'a, F, A>,
+{
+    // Declared before `f` so that it is dropped first.
+    fut: Option<C::Future>,
+    f: F,
+    call: C,
+    _pinned: PhantomPinned,
+}
+
+impl<'a, F, A, C> AsyncFnState<'a, F, A, C>
+where
+    F: 'a,
+    C: AsyncFnMutCall<'a, F, A>,
+{
+    pub const fn new(f: F, call: C) -> Self {
+        Self {
+            fut: None,
+            f,
+            call,
+            _pinned: PhantomPinned,
+        }
+    }
+
+    pub const fn is_pending(&self) -> bool {
+        self.fut.is_some()
+    }
+
+    /// Drops the pending call, if there is one.
+    pub fn clear(self: Pin<&mut Self>) {
+        // SAFETY: `fut` is dropped in place.
+        unsafe { self.get_unchecked_mut() }.fut = None;
+    }
+
+    /// Calls the closure, dropping the pending call first if there is one.
+    pub fn start(self: Pin<&mut Self>, args: A) {
+        // SAFETY: `fut` is only accessed through pinned references, and `f` is never moved.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        this.fut = None;
+
+        // SAFETY: `f` is pinned along with `self`, so it outlives `fut`, which is dropped first. No other reference to
+        // `f` is created while `fut` exists.
+        let f = unsafe { &mut *ptr::addr_of_mut!(this.f) };
+
+        this.fut = Some(this.call.call(f, args));
+    }
+
+    /// Polls the pending call, and drops it once it completes. Returns `Ready(None)` if there is no pending call.
+    pub fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<<C::Future as Future>::Output>> {
+        // SAFETY: `fut` is only accessed through pinned references.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        Poll::Ready(match &mut this.fut {
+            None => None,
+            Some(fut) => {
+                // SAFETY: `fut` is pinned along with `self`.
+                let output = task::ready!(unsafe { Pin::new_unchecked(fut) }.poll(cx));
+
+                this.fut = None;
+
+                Some(output)
+            }
+        })
+    }
+}
@@ -0,0 +1,67 @@
+#![allow(unsafe_code, reason = "The pending future borrows the item stored next to it.")]
+
+use super::async_fn_state::AsyncFnState;
+use crate::fns::AsyncFnMutCall;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+/// An async predicate together with the item it is testing and the future of the pending call, which may borrow from
+/// both of them.
+pub struct AsyncPredicateState<'a, T, P, C>
+where
+    C: AsyncFnMutCall<'a, P, &'a T>,
+{
+    // Declared before `item` so that the pending future is dropped first.
+    state: AsyncFnState<'a, P, &'a T, C>,
+    item: Option<T>,
+}
+
+impl<'a, T, P, C> AsyncPredicateState<'a, T, P, C>
+where
+    T: 'a,
+    P: 'a,
+    C: AsyncFnMutCall<'a, P, &'a T>,
+{
+    pub const fn new(predicate: P, call: C) -> Self {
+        Self {
+            state: AsyncFnState::new(predicate, call),
+            item: None,
+        }
+    }
+
+    /// Returns the item being tested, if there is a pending call.
+    pub const fn get_item(&self) -> Option<&T> {
+        self.item.as_ref()
+    }
+
+    /// Calls the predicate on `item`, dropping the pending call and its item first if there is one.
+    pub fn start(self: Pin<&mut Self>, item: T) {
+        // SAFETY: `state` is only accessed through pinned references, and `item` is not moved while the pending future
+        // exists.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: `state` is pinned along with `self`.
+        let mut state = unsafe { Pin::new_unchecked(&mut this.state) };
+
+        state.as_mut().clear();
+
+        let item: *const T = this.item.insert(item);
+
+        // SAFETY: `item` is pinned along with `self`, so it outlives the pending future, which is dropped first. It is
+        // not accessed mutably until the pending future has been dropped.
+        state.start(unsafe { &*item });
+    }
+
+    /// Polls the pending call, and returns its output along with the tested item once it completes. Returns
+    /// `Ready(None)` if there is no pending call.
+    pub fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<(<C::Future as Future>::Output, T)>> {
+        // SAFETY: `state` is only accessed through pinned references.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: `state` is pinned along with `self`.
+        let output = task::ready!(unsafe { Pin::new_unchecked(&mut this.state) }.poll(cx));
+
+        Poll::Ready(output.zip(this.item.take()))
+    }
+}
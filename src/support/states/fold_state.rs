@@ -1,4 +1,4 @@
-use crate::support::Never;
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use three_states::{StateAPinProject, StateBPinProject, ThreeStates, ThreeStatesPinProject};
 
@@ -6,12 +6,12 @@ pin_project_lite::pin_project! {
     #[derive(Clone)]
     pub struct FoldState<T, Fut> {
         #[pin]
-        inner: ThreeStates<(), T, Fut, (), Never, Never>,
+        inner: ThreeStates<(), T, Fut, (), (), ()>,
     }
 }
 
 impl<T, Fut> FoldState<T, Fut> {
-    pub fn new(acc: T) -> Self {
+    pub const fn new(acc: T) -> Self {
         Self {
             inner: ThreeStates::A {
                 pinned: (),
@@ -20,24 +20,35 @@ impl<T, Fut> FoldState<T, Fut> {
         }
     }
 
-    pub fn get_future(&self) -> Option<&Fut> {
-        match &self.inner {
-            ThreeStates::B { pinned, .. } => Some(pinned),
-            _ => None,
-        }
-    }
-
-    pub fn pin_project(self: Pin<&mut Self>) -> FoldStateProject<T, Fut> {
+    pub fn pin_project(self: Pin<&mut Self>) -> FoldStateProject<'_, T, Fut> {
         match self.project().inner.pin_project() {
             ThreeStatesPinProject::A(project) => FoldStateProject::Accumulate(FoldAccumulateState { inner: project }),
             ThreeStatesPinProject::B(project) => FoldStateProject::Future(FoldFutureState { inner: project }),
-            ThreeStatesPinProject::C(project) => match *project.into_project().unpinned {},
+            ThreeStatesPinProject::C(_) => FoldStateProject::Done,
+        }
+    }
+
+    pub const fn is_done(&self) -> bool {
+        matches!(self.inner, ThreeStates::C { .. })
+    }
+}
+
+impl<T, Fut> Debug for FoldState<T, Fut>
+where
+    T: Debug,
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { unpinned, .. } => f.debug_tuple("Accumulate").field(unpinned).finish(),
+            ThreeStates::B { pinned, .. } => f.debug_tuple("Future").field(pinned).finish(),
+            ThreeStates::C { .. } => f.write_str("Done"),
         }
     }
 }
 
 pub struct FoldAccumulateState<'a, T, Fut> {
-    inner: StateAPinProject<'a, (), T, Fut, (), Never, Never>,
+    inner: StateAPinProject<'a, (), T, Fut, (), (), ()>,
 }
 
 impl<'a, T, Fut> FoldAccumulateState<'a, T, Fut> {
@@ -50,10 +61,14 @@ impl<'a, T, Fut> FoldAccumulateState<'a, T, Fut> {
             inner: self.inner.replace_state_b(fut, ()).0,
         }
     }
+
+    pub fn set_done(self) -> T {
+        self.inner.replace_state_c((), ()).1
+    }
 }
 
 pub struct FoldFutureState<'a, T, Fut> {
-    inner: StateBPinProject<'a, (), T, Fut, (), Never, Never>,
+    inner: StateBPinProject<'a, (), T, Fut, (), (), ()>,
 }
 
 impl<'a, T, Fut> FoldFutureState<'a, T, Fut> {
@@ -66,10 +81,15 @@ impl<'a, T, Fut> FoldFutureState<'a, T, Fut> {
             inner: self.inner.replace_state_a((), acc).0,
         }
     }
+
+    pub fn set_done(self) {
+        self.inner.replace_state_c((), ());
+    }
 }
 
-#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::module_name_repetitions, reason = "Consistent with other state types.")]
 pub enum FoldStateProject<'a, T, Fut> {
     Accumulate(FoldAccumulateState<'a, T, Fut>),
     Future(FoldFutureState<'a, T, Fut>),
+    Done,
 }
@@ -0,0 +1,80 @@
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    pub struct MaybeDone<Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        inner: ThreeStates<Fut, (), (), Fut::Output, (), ()>,
+    }
+}
+
+impl<Fut> MaybeDone<Fut>
+where
+    Fut: Future,
+{
+    pub const fn new(fut: Fut) -> Self {
+        Self {
+            inner: ThreeStates::A {
+                pinned: fut,
+                unpinned: (),
+            },
+        }
+    }
+
+    pub fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(mut project) => {
+                let output = task::ready!(project.get_project().pinned.poll(cx));
+
+                project.replace_state_b((), output);
+            }
+            ThreeStatesPinProject::B(_) => {}
+            ThreeStatesPinProject::C(_) => return Poll::Pending,
+        }
+
+        Poll::Ready(())
+    }
+
+    pub fn take_output(self: Pin<&mut Self>) -> Option<Fut::Output> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::B(project) => Some(project.replace_state_c((), ()).1),
+            _ => None,
+        }
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        matches!(self.inner, ThreeStates::C { .. })
+    }
+}
+
+impl<Fut> Clone for MaybeDone<Fut>
+where
+    Fut: Future + Clone,
+    Fut::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut> Debug for MaybeDone<Fut>
+where
+    Fut: Future + Debug,
+    Fut::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { pinned, .. } => f.debug_tuple("Future").field(pinned).finish(),
+            ThreeStates::B { unpinned, .. } => f.debug_tuple("Done").field(unpinned).finish(),
+            ThreeStates::C { .. } => f.write_str("Gone"),
+        }
+    }
+}
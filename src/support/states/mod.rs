@@ -1,7 +1,19 @@
-pub use self::fold_state::{FoldAccumulateState, FoldFutureState, FoldState, FoldStateProject};
-pub use self::predicate_state::{PredicateEmptyState, PredicateFutureState, PredicateState, PredicateStateProject};
+pub use self::async_fn_state::AsyncFnState;
+pub use self::async_predicate_state::AsyncPredicateState;
+pub use self::fold_state::{FoldState, FoldStateProject};
+pub use self::maybe_done::MaybeDone;
+pub use self::predicate_state::{PredicateState, PredicateStateProject};
+pub use self::raw_maybe_done::RawMaybeDone;
+pub use self::reduce_state::{ReduceState, ReduceStateProject};
+pub use self::try_maybe_done::TryMaybeDone;
 pub use self::two_phases::TwoPhases;
 
+mod async_fn_state;
+mod async_predicate_state;
 mod fold_state;
+mod maybe_done;
 mod predicate_state;
+mod raw_maybe_done;
+mod reduce_state;
+mod try_maybe_done;
 mod two_phases;
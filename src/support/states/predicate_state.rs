@@ -1,4 +1,4 @@
-use crate::support::Never;
+use core::fmt::{self, Debug, Formatter};
 use core::pin::Pin;
 use three_states::{StateAPinProject, StateBPinProject, ThreeStates, ThreeStatesPinProject};
 
@@ -6,7 +6,7 @@ pin_project_lite::pin_project! {
     #[derive(Clone)]
     pub struct PredicateState<T, Fut> {
         #[pin]
-        inner: ThreeStates<(), (), Fut, T, Never, Never>,
+        inner: ThreeStates<(), (), Fut, T, (), ()>,
     }
 }
 
@@ -18,13 +18,17 @@ impl<T, Fut> PredicateState<T, Fut> {
         }
     }
 
-    pub fn pin_project(self: Pin<&mut Self>) -> PredicateStateProject<T, Fut> {
+    pub fn pin_project(self: Pin<&mut Self>) -> PredicateStateProject<'_, T, Fut> {
         match self.project().inner.pin_project() {
             ThreeStatesPinProject::A(project) => PredicateStateProject::Empty(PredicateEmptyState { inner: project }),
             ThreeStatesPinProject::B(project) => PredicateStateProject::Future(PredicateFutureState { inner: project }),
-            ThreeStatesPinProject::C(project) => match *project.into_project().unpinned {},
+            ThreeStatesPinProject::C(_) => PredicateStateProject::Done,
         }
     }
+
+    pub const fn is_done(&self) -> bool {
+        matches!(self.inner, ThreeStates::C { .. })
+    }
 }
 
 impl<T, Fut> Default for PredicateState<T, Fut> {
@@ -38,8 +42,26 @@ impl<T, Fut> Default for PredicateState<T, Fut> {
     }
 }
 
+impl<T, Fut> Debug for PredicateState<T, Fut>
+where
+    T: Debug,
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { .. } => f.write_str("Empty"),
+            ThreeStates::B { pinned, unpinned } => f
+                .debug_struct("Future")
+                .field("fut", pinned)
+                .field("item", unpinned)
+                .finish(),
+            ThreeStates::C { .. } => f.write_str("Done"),
+        }
+    }
+}
+
 pub struct PredicateEmptyState<'a, T, Fut> {
-    inner: StateAPinProject<'a, (), (), Fut, T, Never, Never>,
+    inner: StateAPinProject<'a, (), (), Fut, T, (), ()>,
 }
 
 impl<'a, T, Fut> PredicateEmptyState<'a, T, Fut> {
@@ -48,10 +70,14 @@ impl<'a, T, Fut> PredicateEmptyState<'a, T, Fut> {
             inner: self.inner.replace_state_b(fut, item).0,
         }
     }
+
+    pub fn set_done(self) {
+        self.inner.replace_state_c((), ());
+    }
 }
 
 pub struct PredicateFutureState<'a, T, Fut> {
-    inner: StateBPinProject<'a, (), (), Fut, T, Never, Never>,
+    inner: StateBPinProject<'a, (), (), Fut, T, (), ()>,
 }
 
 impl<'a, T, Fut> PredicateFutureState<'a, T, Fut> {
@@ -64,10 +90,15 @@ impl<'a, T, Fut> PredicateFutureState<'a, T, Fut> {
 
         (PredicateEmptyState { inner }, item)
     }
+
+    pub fn set_done(self) -> T {
+        self.inner.replace_state_c((), ()).1
+    }
 }
 
-#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::module_name_repetitions, reason = "Consistent with other state types.")]
 pub enum PredicateStateProject<'a, T, Fut> {
     Empty(PredicateEmptyState<'a, T, Fut>),
     Future(PredicateFutureState<'a, T, Fut>),
+    Done,
 }
@@ -0,0 +1,160 @@
+#![allow(unsafe_code, reason = "Untagged storage; the owner tracks which field is active.")]
+
+use core::future::Future;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+
+/// A [`MaybeDone`](super::MaybeDone) without a tag. The owner keeps track of whether the slot holds the future, the
+/// output, or nothing, so several slots can share a single state field.
+pub union RawMaybeDone<Fut>
+where
+    Fut: Future,
+{
+    fut: ManuallyDrop<Fut>,
+    output: ManuallyDrop<Fut::Output>,
+    gone: (),
+}
+
+#[derive(Debug)]
+pub enum RawMaybeDoneRef<'a, Fut>
+where
+    Fut: Future,
+{
+    Future(&'a Fut),
+    Done(&'a Fut::Output),
+    Gone,
+}
+
+impl<Fut> RawMaybeDone<Fut>
+where
+    Fut: Future,
+{
+    pub const fn new(fut: Fut) -> Self {
+        Self {
+            fut: ManuallyDrop::new(fut),
+        }
+    }
+
+    const fn done(output: Fut::Output) -> Self {
+        Self {
+            output: ManuallyDrop::new(output),
+        }
+    }
+
+    const fn gone() -> Self {
+        Self { gone: () }
+    }
+
+    /// Polls the future, replacing it with its output once ready. `mark_done` is called before the future is dropped,
+    /// and the output is stored even if dropping the future panics, so the owner never sees a dropped future.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold the future.
+    pub unsafe fn poll_fut(self: Pin<&mut Self>, cx: &mut Context, mark_done: impl FnOnce()) -> Poll<()> {
+        struct StoreOutput<'a, Fut>
+        where
+            Fut: Future,
+        {
+            slot: &'a mut RawMaybeDone<Fut>,
+            output: ManuallyDrop<Fut::Output>,
+        }
+
+        impl<Fut> Drop for StoreOutput<'_, Fut>
+        where
+            Fut: Future,
+        {
+            fn drop(&mut self) {
+                // SAFETY: `output` is only taken here, and this runs once.
+                *self.slot = RawMaybeDone::done(unsafe { ManuallyDrop::take(&mut self.output) });
+            }
+        }
+
+        // SAFETY: The slot is pinned and holds the future, which is never moved out.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: The future is the active field and it is pinned along with the slot.
+        let output = task::ready!(unsafe { Pin::new_unchecked(&mut *this.fut) }.poll(cx));
+
+        mark_done();
+
+        let store_output = StoreOutput {
+            slot: this,
+            output: ManuallyDrop::new(output),
+        };
+
+        // SAFETY: The future is the active field, and it will not be used again. If dropping it panics, `store_output`
+        // still replaces it with the output.
+        unsafe { ManuallyDrop::drop(&mut store_output.slot.fut) };
+
+        drop(store_output);
+
+        Poll::Ready(())
+    }
+
+    /// Moves the output out, leaving the slot empty.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold the output.
+    pub unsafe fn take_output(self: Pin<&mut Self>) -> Fut::Output {
+        // SAFETY: The output is not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // SAFETY: The output is the active field, and the caller marks the slot as gone.
+        let output = unsafe { ManuallyDrop::take(&mut this.output) };
+
+        *this = Self::gone();
+
+        output
+    }
+
+    /// Returns a reference to the active field.
+    ///
+    /// # Safety
+    ///
+    /// `done` and `taken` must describe the active field.
+    pub unsafe fn get(&self, done: bool, taken: bool) -> RawMaybeDoneRef<'_, Fut> {
+        if taken {
+            RawMaybeDoneRef::Gone
+        } else if done {
+            // SAFETY: The output is the active field.
+            RawMaybeDoneRef::Done(unsafe { &*self.output })
+        } else {
+            // SAFETY: The future is the active field.
+            RawMaybeDoneRef::Future(unsafe { &*self.fut })
+        }
+    }
+
+    /// Drops the active field in place.
+    ///
+    /// # Safety
+    ///
+    /// `done` and `taken` must describe the active field, and the slot must not be used afterwards.
+    pub unsafe fn drop_in_place(&mut self, done: bool, taken: bool) {
+        if !taken {
+            if done {
+                // SAFETY: The output is the active field.
+                unsafe { ManuallyDrop::drop(&mut self.output) };
+            } else {
+                // SAFETY: The future is the active field, and dropping it in place upholds the pinning guarantee.
+                unsafe { ManuallyDrop::drop(&mut self.fut) };
+            }
+        }
+    }
+}
+
+impl<Fut> RawMaybeDoneRef<'_, Fut>
+where
+    Fut: Future + Clone,
+    Fut::Output: Clone,
+{
+    pub fn to_owned(&self) -> RawMaybeDone<Fut> {
+        match *self {
+            Self::Future(fut) => RawMaybeDone::new(fut.clone()),
+            Self::Done(output) => RawMaybeDone::done(output.clone()),
+            Self::Gone => RawMaybeDone::gone(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use core::fmt::{self, Debug, Formatter};
+use core::pin::Pin;
+use three_states::{StateAPinProject, StateBPinProject, StateCPinProject, ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    #[derive(Clone)]
+    pub struct ReduceState<T, Fut> {
+        #[pin]
+        inner: ThreeStates<(), (), (), T, Fut, ()>,
+    }
+}
+
+impl<T, Fut> Default for ReduceState<T, Fut> {
+    fn default() -> Self {
+        Self {
+            inner: ThreeStates::A {
+                pinned: (),
+                unpinned: (),
+            },
+        }
+    }
+}
+
+impl<T, Fut> ReduceState<T, Fut> {
+    pub fn get_future(&self) -> Option<&Fut> {
+        match &self.inner {
+            ThreeStates::C { pinned, .. } => Some(pinned),
+            _ => None,
+        }
+    }
+
+    pub fn pin_project(self: Pin<&mut Self>) -> ReduceStateProject<'_, T, Fut> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(project) => ReduceStateProject::Empty(ReduceEmptyState { inner: project }),
+            ThreeStatesPinProject::B(project) => {
+                ReduceStateProject::Accumulate(ReduceAccumulateState { inner: project })
+            }
+            ThreeStatesPinProject::C(project) => ReduceStateProject::Future(ReduceFutureState { inner: project }),
+        }
+    }
+}
+
+impl<T, Fut> Debug for ReduceState<T, Fut>
+where
+    T: Debug,
+    Fut: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { .. } => f.write_str("Empty"),
+            ThreeStates::B { unpinned, .. } => f.debug_tuple("Accumulate").field(unpinned).finish(),
+            ThreeStates::C { pinned, .. } => f.debug_tuple("Future").field(pinned).finish(),
+        }
+    }
+}
+
+pub struct ReduceEmptyState<'a, T, Fut> {
+    inner: StateAPinProject<'a, (), (), (), T, Fut, ()>,
+}
+
+impl<'a, T, Fut> ReduceEmptyState<'a, T, Fut> {
+    pub fn set_accumulate(self, acc: T) -> ReduceAccumulateState<'a, T, Fut> {
+        ReduceAccumulateState {
+            inner: self.inner.replace_state_b((), acc).0,
+        }
+    }
+
+    pub fn set_future(self, fut: Fut) -> ReduceFutureState<'a, T, Fut> {
+        ReduceFutureState {
+            inner: self.inner.replace_state_c(fut, ()).0,
+        }
+    }
+}
+
+pub struct ReduceAccumulateState<'a, T, Fut> {
+    inner: StateBPinProject<'a, (), (), (), T, Fut, ()>,
+}
+
+impl<'a, T, Fut> ReduceAccumulateState<'a, T, Fut> {
+    pub fn set_empty(self) -> (ReduceEmptyState<'a, T, Fut>, T) {
+        let (inner, item) = self.inner.replace_state_a((), ());
+
+        (ReduceEmptyState { inner }, item)
+    }
+}
+
+pub struct ReduceFutureState<'a, T, Fut> {
+    inner: StateCPinProject<'a, (), (), (), T, Fut, ()>,
+}
+
+impl<'a, T, Fut> ReduceFutureState<'a, T, Fut> {
+    pub fn get_pin_mut(&mut self) -> Pin<&mut Fut> {
+        self.inner.get_project().pinned
+    }
+
+    pub fn set_accumulate(self, acc: T) -> ReduceAccumulateState<'a, T, Fut> {
+        ReduceAccumulateState {
+            inner: self.inner.replace_state_b((), acc).0,
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions, reason = "Consistent with other state types.")]
+pub enum ReduceStateProject<'a, T, Fut> {
+    Empty(ReduceEmptyState<'a, T, Fut>),
+    Accumulate(ReduceAccumulateState<'a, T, Fut>),
+    Future(ReduceFutureState<'a, T, Fut>),
+}
@@ -0,0 +1,100 @@
+use crate::support::Try;
+use core::fmt::{self, Debug, Formatter};
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{self, Context, Poll};
+use three_states::{ThreeStates, ThreeStatesPinProject};
+
+pin_project_lite::pin_project! {
+    pub struct TryMaybeDone<Fut>
+    where
+        Fut: Future,
+        Fut::Output: Try,
+    {
+        #[pin]
+        inner: ThreeStates<Fut, (), (), <Fut::Output as Try>::Output, (), ()>,
+    }
+}
+
+impl<Fut> TryMaybeDone<Fut>
+where
+    Fut: Future,
+    Fut::Output: Try,
+{
+    pub const fn new(fut: Fut) -> Self {
+        Self {
+            inner: ThreeStates::A {
+                pinned: fut,
+                unpinned: (),
+            },
+        }
+    }
+
+    pub fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<ControlFlow<<Fut::Output as Try>::Residual>> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(mut project) => match task::ready!(project.get_project().pinned.poll(cx)).branch()
+            {
+                ControlFlow::Continue(output) => {
+                    project.replace_state_b((), output);
+                }
+                ControlFlow::Break(residual) => {
+                    project.replace_state_c((), ());
+
+                    return Poll::Ready(ControlFlow::Break(residual));
+                }
+            },
+            ThreeStatesPinProject::B(_) => {}
+            ThreeStatesPinProject::C(_) => return Poll::Pending,
+        }
+
+        Poll::Ready(ControlFlow::Continue(()))
+    }
+
+    pub fn take_output(self: Pin<&mut Self>) -> Option<<Fut::Output as Try>::Output> {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::B(project) => Some(project.replace_state_c((), ()).1),
+            _ => None,
+        }
+    }
+
+    pub fn clear(self: Pin<&mut Self>) {
+        match self.project().inner.pin_project() {
+            ThreeStatesPinProject::A(project) => drop(project.replace_state_c((), ())),
+            ThreeStatesPinProject::B(project) => drop(project.replace_state_c((), ())),
+            ThreeStatesPinProject::C(_) => {}
+        }
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        matches!(self.inner, ThreeStates::C { .. })
+    }
+}
+
+impl<Fut> Clone for TryMaybeDone<Fut>
+where
+    Fut: Future + Clone,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Fut> Debug for TryMaybeDone<Fut>
+where
+    Fut: Future + Debug,
+    Fut::Output: Try,
+    <Fut::Output as Try>::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { pinned, .. } => f.debug_tuple("Future").field(pinned).finish(),
+            ThreeStates::B { unpinned, .. } => f.debug_tuple("Done").field(unpinned).finish(),
+            ThreeStates::C { .. } => f.write_str("Gone"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::support::{FusedAsyncIterator, Never};
+use core::fmt::{self, Debug, Formatter};
 use core::future::Future;
 use core::ops::ControlFlow;
 use core::pin::Pin;
@@ -16,7 +17,7 @@ pin_project_lite::pin_project! {
 }
 
 impl<A, B> TwoPhases<A, B> {
-    pub fn new(state: A) -> Self {
+    pub const fn new(state: A) -> Self {
         Self {
             inner: ThreeStates::A {
                 pinned: state,
@@ -77,3 +78,17 @@ impl<A, B> TwoPhases<A, B> {
         }
     }
 }
+
+impl<A, B> Debug for TwoPhases<A, B>
+where
+    A: Debug,
+    B: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.inner {
+            ThreeStates::A { pinned, .. } => f.debug_tuple("First").field(pinned).finish(),
+            ThreeStates::B { pinned, .. } => f.debug_tuple("Second").field(pinned).finish(),
+            ThreeStates::C { unpinned, .. } => match *unpinned {},
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use core::fmt::{self, Display, Formatter};
+use core::ops::Deref;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Context;
+use futures_util::task::AtomicWaker;
+
+/// Shared state between an [`AbortHandle`] and the abortable future or async iterator it controls. It can live in a
+/// `static`, so no allocation is needed. Each registration should only back one abortable value at a time.
+#[derive(Debug)]
+pub struct AbortRegistration {
+    aborted: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl AbortRegistration {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            aborted: AtomicBool::new(false),
+            waker: AtomicWaker::new(),
+        }
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::Acquire)
+    }
+
+    pub fn reset(&self) {
+        self.aborted.store(false, Ordering::Release);
+    }
+
+    fn abort(&self) {
+        self.aborted.store(true, Ordering::Release);
+        self.waker.wake();
+    }
+
+    pub(crate) fn register(&self, cx: &Context) -> bool {
+        self.waker.register(cx.waker());
+
+        self.is_aborted()
+    }
+}
+
+impl Default for AbortRegistration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AbortHandle<R> {
+    registration: R,
+}
+
+impl<R> AbortHandle<R>
+where
+    R: Deref<Target = AbortRegistration>,
+{
+    pub(crate) const fn new(registration: R) -> Self {
+        Self { registration }
+    }
+
+    pub fn abort(&self) {
+        self.registration.abort();
+    }
+
+    pub fn is_aborted(&self) -> bool {
+        self.registration.is_aborted()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Aborted;
+
+impl Display for Aborted {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("`Abortable` future has been aborted")
+    }
+}
+
+impl core::error::Error for Aborted {}
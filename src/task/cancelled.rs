@@ -0,0 +1,12 @@
+use core::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("future has been cancelled by its signal")
+    }
+}
+
+impl core::error::Error for Cancelled {}
@@ -0,0 +1,13 @@
+pub use self::abort::{AbortHandle, AbortRegistration, Aborted};
+pub use self::cancelled::Cancelled;
+#[cfg(feature = "alloc")]
+pub use self::per_branch_wakers::PerBranchWakers;
+pub use self::shared_waker::SharedWaker;
+pub use self::wake_mode::WakeMode;
+
+mod abort;
+mod cancelled;
+#[cfg(feature = "alloc")]
+mod per_branch_wakers;
+mod shared_waker;
+mod wake_mode;
@@ -0,0 +1,93 @@
+use crate::task::WakeMode;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::task::{Context, Poll, Waker};
+use futures_util::task::AtomicWaker;
+
+const BRANCHES: usize = 2;
+
+#[derive(Debug)]
+struct Shared {
+    ready: AtomicUsize,
+    parent: AtomicWaker,
+}
+
+struct BranchWaker {
+    shared: Arc<Shared>,
+    mask: usize,
+}
+
+impl Wake for BranchWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.shared.ready.fetch_or(self.mask, Ordering::AcqRel);
+        self.shared.parent.wake();
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    shared: Arc<Shared>,
+    wakers: [Waker; BRANCHES],
+}
+
+impl Inner {
+    fn new() -> Self {
+        let shared = Arc::new(Shared {
+            ready: AtomicUsize::new(usize::MAX),
+            parent: AtomicWaker::new(),
+        });
+
+        let wakers = core::array::from_fn(|index| {
+            Waker::from(Arc::new(BranchWaker {
+                shared: Arc::clone(&shared),
+                mask: 1 << index,
+            }))
+        });
+
+        Self { shared, wakers }
+    }
+}
+
+/// Gives each branch its own waker, and only polls the branches that have been woken since the last poll. Branches
+/// that returned `Poll::Ready` are always polled again. The wakers are allocated on the first poll, so this mode
+/// requires the `alloc` feature.
+#[derive(Debug, Default)]
+pub struct PerBranchWakers {
+    inner: Option<Inner>,
+    ready: usize,
+}
+
+impl WakeMode for PerBranchWakers {
+    const INIT: Self = Self { inner: None, ready: 0 };
+
+    fn begin(&mut self, cx: &mut Context) {
+        let inner = self.inner.get_or_insert_with(Inner::new);
+
+        inner.shared.parent.register(cx.waker());
+
+        self.ready = inner.shared.ready.swap(0, Ordering::AcqRel);
+    }
+
+    fn poll_branch<T>(&mut self, cx: &mut Context, index: usize, f: impl FnOnce(&mut Context) -> Poll<T>) -> Poll<T> {
+        let mask = 1 << index;
+
+        match &self.inner {
+            None => f(cx),
+            Some(_) if self.ready & mask == 0 => Poll::Pending,
+            Some(inner) => {
+                let result = f(&mut Context::from_waker(&inner.wakers[index]));
+
+                if result.is_ready() {
+                    inner.shared.ready.fetch_or(mask, Ordering::AcqRel);
+                }
+
+                result
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::task::WakeMode;
+use core::task::{Context, Poll};
+
+/// Polls every branch with the parent waker on every wakeup. This is the default mode, and it adds no state.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SharedWaker;
+
+impl WakeMode for SharedWaker {
+    const INIT: Self = Self;
+
+    fn begin(&mut self, cx: &mut Context) {
+        let _ = cx;
+    }
+
+    fn poll_branch<T>(&mut self, cx: &mut Context, index: usize, f: impl FnOnce(&mut Context) -> Poll<T>) -> Poll<T> {
+        let _ = index;
+
+        f(cx)
+    }
+}
@@ -0,0 +1,11 @@
+use core::task::{Context, Poll};
+
+/// Decides which waker each branch of a multi-branch combinator is polled with, and whether a branch needs to be
+/// polled at all.
+pub trait WakeMode {
+    const INIT: Self;
+
+    fn begin(&mut self, cx: &mut Context);
+
+    fn poll_branch<T>(&mut self, cx: &mut Context, index: usize, f: impl FnOnce(&mut Context) -> Poll<T>) -> Poll<T>;
+}
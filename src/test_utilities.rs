@@ -1,16 +1,20 @@
-use core::future::Future;
-use core::pin::Pin;
+use crate::support::{AsyncIterator, ExactSizeAsyncIterator, FusedAsyncIterator};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::future::{self, Future};
+use core::pin::{self, Pin};
 use core::task::{Context, Poll};
 use futures_core::FusedFuture;
-use futures_util::FutureExt;
+use futures_util::{FutureExt, StreamExt};
+use std::string::{String, ToString};
+use std::vec::Vec;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Yield {
     count: Option<usize>,
 }
 
 impl Yield {
-    pub fn new(count: usize) -> Self {
+    pub const fn new(count: usize) -> Self {
         Self { count: Some(count) }
     }
 }
@@ -50,6 +54,128 @@ where
     Yield::new(1).then(|()| fut)
 }
 
+pub async fn assert_fused_future<Fut>(fut: Fut) -> Fut::Output
+where
+    Fut: FusedFuture,
+{
+    let mut fut = pin::pin!(fut);
+
+    assert!(!fut.is_terminated());
+
+    let output = fut.as_mut().await;
+
+    assert!(fut.is_terminated());
+
+    drop(futures_util::poll!(fut.as_mut()));
+
+    assert!(fut.is_terminated());
+
+    output
+}
+
+pub async fn assert_fused_async_iter<I>(iter: I) -> Vec<I::Item>
+where
+    I: FusedAsyncIterator,
+{
+    let mut iter = pin::pin!(iter);
+
+    assert!(!iter.is_terminated());
+
+    let mut items = Vec::new();
+
+    while let Some(item) = iter.next().await {
+        items.push(item);
+    }
+
+    assert!(iter.is_terminated());
+    assert!(matches!(futures_util::poll!(iter.next()), Poll::Ready(None)));
+    assert!(iter.is_terminated());
+
+    items
+}
+
+async fn collect_with_size_hints<I>(iter: I) -> (Vec<I::Item>, Vec<(usize, Option<usize>, usize)>)
+where
+    I: AsyncIterator,
+{
+    let mut iter = pin::pin!(iter);
+    let mut items = Vec::new();
+    let mut size_hints = Vec::new();
+
+    while let Some(item) = future::poll_fn(|cx| {
+        let (lower, upper) = iter.size_hint();
+
+        size_hints.push((lower, upper, items.len()));
+
+        iter.as_mut().poll_next(cx)
+    })
+    .await
+    {
+        items.push(item);
+    }
+
+    let (lower, upper) = iter.size_hint();
+
+    size_hints.push((lower, upper, items.len()));
+
+    let count = items.len();
+
+    (
+        items,
+        size_hints
+            .into_iter()
+            .map(|(lower, upper, produced)| (lower, upper, count - produced))
+            .collect(),
+    )
+}
+
+pub async fn assert_size_hint<I>(iter: I) -> Vec<I::Item>
+where
+    I: AsyncIterator,
+{
+    let (items, size_hints) = collect_with_size_hints(iter).await;
+
+    for (lower, upper, remaining) in size_hints {
+        assert!(lower <= remaining);
+        assert!(upper.is_none_or(|upper| remaining <= upper));
+    }
+
+    items
+}
+
+pub async fn assert_exact_size<I>(iter: I) -> Vec<I::Item>
+where
+    I: ExactSizeAsyncIterator,
+{
+    let (items, size_hints) = collect_with_size_hints(iter).await;
+
+    for (lower, upper, remaining) in size_hints {
+        assert_eq!((lower, upper), (remaining, Some(remaining)));
+    }
+
+    items
+}
+
+struct DebugAsDisplay<'a, T>(&'a T)
+where
+    T: ?Sized;
+
+impl<T> Display for DebugAsDisplay<'_, T>
+where
+    T: Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+pub fn debug_string<T>(value: &T) -> String
+where
+    T: Debug + ?Sized,
+{
+    DebugAsDisplay(value).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::Yield;
@@ -0,0 +1,162 @@
+use crate::time::Timer;
+use core::cell::Cell;
+use core::future::Future;
+use core::ops::Add;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use futures_util::task::AtomicWaker;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ManualInstant {
+    since_start: Duration,
+}
+
+impl ManualInstant {
+    #[must_use]
+    pub const fn since_start(self) -> Duration {
+        self.since_start
+    }
+}
+
+impl Add<Duration> for ManualInstant {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        Self {
+            since_start: self.since_start + rhs,
+        }
+    }
+}
+
+/// A clock that only moves when [`ManualClock::advance`] is called, so timing behavior can be tested
+/// deterministically. Advancing the clock wakes the task that most recently polled a pending sleep.
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Cell<ManualInstant>,
+    waker: AtomicWaker,
+}
+
+impl ManualClock {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            now: Cell::new(ManualInstant {
+                since_start: Duration::ZERO,
+            }),
+            waker: AtomicWaker::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn now(&self) -> ManualInstant {
+        self.now.get()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        self.waker.wake();
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+pub struct ManualSleep<'a> {
+    clock: &'a ManualClock,
+    deadline: ManualInstant,
+}
+
+impl ManualSleep<'_> {
+    #[must_use]
+    pub fn deadline(&self) -> ManualInstant {
+        self.deadline
+    }
+}
+
+impl Clone for ManualSleep<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            clock: self.clock,
+            deadline: self.deadline,
+        }
+    }
+}
+
+impl Future for ManualSleep<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        if self.clock.now() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            self.clock.waker.register(cx.waker());
+
+            Poll::Pending
+        }
+    }
+}
+
+impl<'a> Timer for &'a ManualClock {
+    type Instant = ManualInstant;
+    type Sleep = ManualSleep<'a>;
+
+    fn now(&self) -> Self::Instant {
+        ManualClock::now(self)
+    }
+
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep {
+        ManualSleep { clock: self, deadline }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ManualClock;
+    use crate::time::Timer;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use futures_util::task;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new();
+        let timer = &clock;
+        let mut sleep = pin!(timer.sleep(Duration::from_secs(2)));
+        let mut cx = Context::from_waker(task::noop_waker_ref());
+
+        assert_eq!(sleep.deadline().since_start(), Duration::from_secs(2));
+        assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(timer.now().since_start(), Duration::from_secs(1));
+        assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Pending);
+
+        clock.advance(Duration::from_secs(1));
+
+        assert_eq!(sleep.as_mut().poll(&mut cx), Poll::Ready(()));
+    }
+
+    #[tokio::test]
+    async fn test_manual_clock_wakes_task() {
+        let clock = ManualClock::new();
+        let sleep = (&clock).sleep(Duration::from_secs(2));
+
+        let advance = async {
+            crate::test_utilities::Yield::new(1).await;
+
+            clock.advance(Duration::from_secs(3));
+        };
+
+        futures_util::future::join(sleep, advance).await;
+
+        assert_eq!(clock.now().since_start(), Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,7 @@
+pub use self::manual_clock::{ManualClock, ManualInstant, ManualSleep};
+pub use self::timed_out::TimedOut;
+pub use self::timer::Timer;
+
+mod manual_clock;
+mod timed_out;
+mod timer;
@@ -0,0 +1,17 @@
+use core::future::Future;
+use core::ops::Add;
+use core::time::Duration;
+
+/// A source of time that combinators use to create sleep futures. Implement it for a cheap handle type, such as a
+/// reference or a unit struct, since combinators store the timer by value.
+pub trait Timer {
+    type Instant: Copy + Ord + Add<Duration, Output = Self::Instant>;
+    type Sleep: Future<Output = ()>;
+
+    fn now(&self) -> Self::Instant;
+    fn sleep_until(&self, deadline: Self::Instant) -> Self::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self.sleep_until(self.now() + duration)
+    }
+}